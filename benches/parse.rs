@@ -19,6 +19,23 @@ fn bench_parse(c: &mut criterion::Criterion) {
             let _ = wkt::Wkt::<f64>::from_str(s).unwrap();
         });
     });
+
+    c.bench_function("parse big from bytes", |bencher| {
+        let s = include_bytes!("./big.wkt");
+        bencher.iter(|| {
+            let _ = wkt::Wkt::<f64>::from_bytes(s).unwrap();
+        });
+    });
+}
+
+fn bench_parse_random(c: &mut criterion::Criterion) {
+    c.bench_function("parse random multi polygon", |bencher| {
+        let polygons = wkt::generate::RandomGeometry::multi_polygon(0, 100, 2, 100);
+        let s = wkt::Wkt::from(polygons).to_string();
+        bencher.iter(|| {
+            let _ = wkt::Wkt::<f64>::from_str(&s).unwrap();
+        });
+    });
 }
 
 fn bench_parse_to_geo(c: &mut criterion::Criterion) {
@@ -37,5 +54,5 @@ fn bench_parse_to_geo(c: &mut criterion::Criterion) {
     });
 }
 
-criterion_group!(benches, bench_parse, bench_parse_to_geo);
+criterion_group!(benches, bench_parse, bench_parse_random, bench_parse_to_geo);
 criterion_main!(benches);
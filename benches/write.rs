@@ -24,6 +24,27 @@ fn wkt_to_string(c: &mut criterion::Criterion) {
     });
 }
 
+// Compare with `to_string big wkt`. This only measures time: that the `String` is allocated once
+// and never grown is checked by `big_geometry_needs_no_reallocation` in `src/to_wkt/len.rs`.
+fn wkt_wkt_string(c: &mut criterion::Criterion) {
+    c.bench_function("wkt_string big wkt", |bencher| {
+        let s = include_str!("./big.wkt");
+        let w = wkt::Wkt::<f64>::from_str(s).unwrap();
+        bencher.iter(|| {
+            let _ = w.wkt_string();
+        });
+    });
+}
+
+fn random_wkt_string(c: &mut criterion::Criterion) {
+    c.bench_function("wkt_string random polygon", |bencher| {
+        let w = wkt::Wkt::from(wkt::generate::RandomGeometry::polygon(0, 10, 1000));
+        bencher.iter(|| {
+            let _ = w.wkt_string();
+        });
+    });
+}
+
 fn geo_to_wkt_string(c: &mut criterion::Criterion) {
     c.bench_function("geo: serialize small wkt string", |bencher| {
         let s = include_str!("./small.wkt");
@@ -87,6 +108,8 @@ fn geo_write_wkt_as_trait(c: &mut criterion::Criterion) {
 criterion_group!(
     benches,
     wkt_to_string,
+    wkt_wkt_string,
+    random_wkt_string,
     geo_to_wkt_string,
     geo_write_wkt,
     geo_write_wkt_as_trait
@@ -0,0 +1,65 @@
+/* C declarations for the `capi` feature of the wkt crate. This file is written by hand, and the
+ * comments follow the documentation in src/capi.rs, which has the details. */
+
+#ifndef WKT_H
+#define WKT_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The coordinate dimension of a geometry. */
+typedef enum WktDimension {
+  WktDimension_XY = 0,
+  WktDimension_XYZ = 1,
+  WktDimension_XYM = 2,
+  WktDimension_XYZM = 3,
+} WktDimension;
+
+/* A parsed geometry. */
+typedef struct WktGeometry WktGeometry;
+
+/* Parses NUL-terminated WKT. On error, returns NULL and copies the message into `error`,
+ * unless it's NULL.
+ *
+ * Safety: `input` must be a valid NUL-terminated string, and `error` must be NULL or point to
+ * `error_len` writable bytes. */
+WktGeometry *wkt_parse(const char *input, char *error, size_t error_len);
+
+/* Releases a geometry returned by wkt_parse. NULL is ignored.
+ *
+ * Safety: `geometry` must be NULL or a geometry from wkt_parse that hasn't been freed. */
+void wkt_free(WktGeometry *geometry);
+
+/* The geometry's type keyword, e.g. "POLYGON", as a static NUL-terminated string.
+ *
+ * Safety: `geometry` must be a live geometry from wkt_parse. */
+const char *wkt_geometry_type(const WktGeometry *geometry);
+
+/* The geometry's coordinate dimension.
+ *
+ * Safety: `geometry` must be a live geometry from wkt_parse. */
+WktDimension wkt_dimension(const WktGeometry *geometry);
+
+/* Copies the geometry's coordinates into `out`, in order, with as many values per coordinate
+ * as its dimension has. Returns the number of values, of which at most `capacity` are copied.
+ *
+ * Safety: `geometry` must be a live geometry from wkt_parse, and `out` must be NULL or point to
+ * `capacity` writable doubles. */
+size_t wkt_coords(const WktGeometry *geometry, double *out, size_t capacity);
+
+/* Writes the geometry as WKT into `buffer`, truncated to fit and NUL-terminated if `buffer_len`
+ * isn't zero. Returns the length of the WKT, not counting the NUL, or 0 after writing an empty
+ * string if the geometry can't be written.
+ *
+ * Safety: `geometry` must be a live geometry from wkt_parse, and `buffer` must point to
+ * `buffer_len` writable bytes. */
+size_t wkt_write(const WktGeometry *geometry, char *buffer, size_t buffer_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* WKT_H */
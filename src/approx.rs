@@ -0,0 +1,228 @@
+//! Comparing geometries with a tolerance, for [`approx_eq`] and
+//! [`assert_wkt_eq!`](crate::assert_wkt_eq).
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+use crate::types::{Coord, LineString};
+use crate::Wkt;
+
+/// Parses two WKT strings and returns whether they're the same geometry, with coordinates that
+/// differ by at most `epsilon`.
+///
+/// The geometry types, dimensions, and the number of members, rings and coordinates must match.
+/// NaN values are equal to each other.
+///
+/// ```
+/// assert!(wkt::approx_eq("POINT (1 2)", "point(1.0000001 2)", 1e-6).unwrap());
+/// assert!(!wkt::approx_eq("POINT (1 2)", "POINT Z (1 2 0)", 1e-6).unwrap());
+/// assert!(wkt::approx_eq("POINT (1 2)", "POINT (1", 1e-6).is_err());
+/// ```
+pub fn approx_eq(a: &str, b: &str, epsilon: f64) -> Result<bool, Error> {
+    let a = Wkt::<f64>::from_str(a).map_err(Error::InvalidWkt)?;
+    let b = Wkt::<f64>::from_str(b).map_err(Error::InvalidWkt)?;
+    Ok(Path(vec![]).wkt(&a, &b, epsilon).is_ok())
+}
+
+/// The first difference between two geometries.
+#[cfg(feature = "test-util")]
+pub(crate) struct Difference {
+    /// What differs, like ``coordinates differ: `1 2` != `1 3` ``.
+    pub problem: String,
+    /// Where, like `polygons[1].rings[0]`, or empty for the geometries themselves.
+    pub path: String,
+}
+
+/// Returns the first difference between two geometries, or `None` if they're equal within
+/// `epsilon`.
+#[cfg(feature = "test-util")]
+pub(crate) fn difference(left: &Wkt<f64>, right: &Wkt<f64>, epsilon: f64) -> Option<Difference> {
+    let mut path = Path(vec![]);
+    let problem = path.wkt(left, right, epsilon).err()?;
+    Some(Difference {
+        problem,
+        path: path.to_string(),
+    })
+}
+
+/// Where the comparison is, like `polygons[1].rings[0]`. On a difference, it's left where the
+/// difference was found.
+struct Path(Vec<(&'static str, Option<usize>)>);
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, index)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            f.write_str(name)?;
+            if let Some(index) = index {
+                write!(f, "[{index}]")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Path {
+    fn wkt(&mut self, left: &Wkt<f64>, right: &Wkt<f64>, epsilon: f64) -> Result<(), String> {
+        match (left, right) {
+            (Wkt::Point(l), Wkt::Point(r)) => self.point(&l.0, &r.0, epsilon),
+            (Wkt::LineString(l), Wkt::LineString(r)) => self.coords(&l.0, &r.0, epsilon),
+            (Wkt::LinearRing(l), Wkt::LinearRing(r)) => self.coords(&l.0 .0, &r.0 .0, epsilon),
+            (Wkt::Polygon(l), Wkt::Polygon(r)) => self.polygon(&l.0, &r.0, epsilon),
+            (Wkt::MultiPoint(l), Wkt::MultiPoint(r)) => {
+                self.list("points", &l.0, &r.0, |path, l, r| {
+                    path.point(&l.0, &r.0, epsilon)
+                })
+            }
+            (Wkt::MultiLineString(l), Wkt::MultiLineString(r)) => {
+                self.list("line_strings", &l.0, &r.0, |path, l, r| {
+                    path.coords(&l.0, &r.0, epsilon)
+                })
+            }
+            (Wkt::MultiPolygon(l), Wkt::MultiPolygon(r)) => {
+                self.list("polygons", &l.0, &r.0, |path, l, r| {
+                    path.polygon(&l.0, &r.0, epsilon)
+                })
+            }
+            (Wkt::GeometryCollection(l), Wkt::GeometryCollection(r)) => {
+                self.list("geometries", &l.0, &r.0, |path, l, r| {
+                    path.wkt(l, r, epsilon)
+                })
+            }
+            _ => Err(format!(
+                "geometry types differ: {} != {}",
+                left.geometry_type(),
+                right.geometry_type()
+            )),
+        }
+    }
+
+    fn point(
+        &mut self,
+        left: &Option<Coord<f64>>,
+        right: &Option<Coord<f64>>,
+        epsilon: f64,
+    ) -> Result<(), String> {
+        self.0.push(("coord", None));
+        match (left, right) {
+            (Some(l), Some(r)) => coord(l, r, epsilon)?,
+            (None, None) => (),
+            _ => return Err("one point is empty".to_string()),
+        }
+        self.0.pop();
+        Ok(())
+    }
+
+    fn polygon(
+        &mut self,
+        left: &[LineString<f64>],
+        right: &[LineString<f64>],
+        epsilon: f64,
+    ) -> Result<(), String> {
+        self.list("rings", left, right, |path, l, r| {
+            path.coords(&l.0, &r.0, epsilon)
+        })
+    }
+
+    fn coords(
+        &mut self,
+        left: &[Coord<f64>],
+        right: &[Coord<f64>],
+        epsilon: f64,
+    ) -> Result<(), String> {
+        self.list("coords", left, right, |_, l, r| coord(l, r, epsilon))
+    }
+
+    /// Compares the items of two lists, which must be the same length.
+    fn list<I>(
+        &mut self,
+        name: &'static str,
+        left: &[I],
+        right: &[I],
+        mut item: impl FnMut(&mut Self, &I, &I) -> Result<(), String>,
+    ) -> Result<(), String> {
+        if left.len() != right.len() {
+            self.0.push((name, None));
+            return Err(format!("lengths differ: {} != {}", left.len(), right.len()));
+        }
+        for (index, (l, r)) in left.iter().zip(right).enumerate() {
+            self.0.push((name, Some(index)));
+            item(self, l, r)?;
+            self.0.pop();
+        }
+        Ok(())
+    }
+}
+
+fn coord(left: &Coord<f64>, right: &Coord<f64>, epsilon: f64) -> Result<(), String> {
+    let close = |l: f64, r: f64| l == r || (l - r).abs() <= epsilon || (l.is_nan() && r.is_nan());
+    let values = [
+        (Some(left.x), Some(right.x)),
+        (Some(left.y), Some(right.y)),
+        (left.z, right.z),
+        (left.m, right.m),
+    ];
+    let same = values.into_iter().all(|pair| match pair {
+        (Some(l), Some(r)) => close(l, r),
+        (None, None) => true,
+        _ => false,
+    });
+    if same {
+        Ok(())
+    } else {
+        let show = |coord: &Coord<f64>| {
+            let mut text = format!("{} {}", coord.x, coord.y);
+            for value in [coord.z, coord.m].into_iter().flatten() {
+                text += &format!(" {value}");
+            }
+            text
+        };
+        Err(format!(
+            "coordinates differ: `{}` != `{}`",
+            show(left),
+            show(right)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_within_epsilon() {
+        assert!(approx_eq("POINT (1 2)", "POINT(1 2)", 0.).unwrap());
+        assert!(approx_eq(
+            "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), EMPTY)",
+            "MULTIPOLYGON (((0 0, 1 0, 1 1.0001, 0 0)), EMPTY)",
+            1e-3
+        )
+        .unwrap());
+        assert!(!approx_eq("POINT (1 2)", "POINT (1 2.1)", 1e-3).unwrap());
+    }
+
+    #[test]
+    fn compares_the_structure() {
+        for (a, b) in [
+            ("POINT (1 2)", "MULTIPOINT (1 2)"),
+            ("POINT (1 2)", "POINT M (1 2 0)"),
+            ("POINT EMPTY", "POINT (1 2)"),
+            ("LINESTRING (1 2, 3 4)", "LINESTRING (1 2, 3 4, 5 6)"),
+            ("POLYGON ((0 0, 1 0, 1 1, 0 0))", "POLYGON EMPTY"),
+        ] {
+            assert!(!approx_eq(a, b, 10.).unwrap(), "{a} == {b}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_wkt() {
+        assert!(matches!(
+            approx_eq("POINT (1 2)", "POINT (1)", 0.),
+            Err(Error::InvalidWkt(_))
+        ));
+        assert!(approx_eq("LINESTRING (1 2,)", "POINT (1 2)", 0.).is_err());
+    }
+}
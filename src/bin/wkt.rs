@@ -0,0 +1,140 @@
+//! The `wkt` command line tool, built with the `cli` feature.
+//!
+//! Each command reads WKT from a file, or from standard input, with one geometry per line.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::process::ExitCode;
+
+use clap::{Arg, ArgMatches, Command};
+use wkt::TypeSummary;
+
+fn main() -> ExitCode {
+    let input = Arg::new("FILE").help("The file to read, or standard input if it's missing or -");
+    let matches = Command::new("wkt")
+        .about("Check, format and summarize WKT, one geometry per line")
+        .version(env!("CARGO_PKG_VERSION"))
+        .after_help("Converting to WKB or GeoJSON isn't supported: this crate has no writers for them.")
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("validate")
+                .about("Report the lines that aren't valid WKT")
+                .arg(input.clone()),
+        )
+        .subcommand(
+            Command::new("fmt")
+                .about("Write each geometry with uppercase keywords, minimal spacing and shortest numbers")
+                .arg(input.clone()),
+        )
+        .subcommand(
+            Command::new("stats")
+                .about("Count the geometries, coordinates, types and dimensions")
+                .arg(input),
+        )
+        .get_matches();
+
+    let (command, args) = matches.subcommand().expect("a subcommand is required");
+    let result = open(args).and_then(|lines| match command {
+        "validate" => validate(lines),
+        "fmt" => fmt(lines),
+        "stats" => stats(lines),
+        _ => unreachable!("unknown subcommand {command}"),
+    });
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("wkt: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// The non-blank lines of the input, with their line numbers, counting from 1.
+type Lines = Box<dyn Iterator<Item = io::Result<(usize, String)>>>;
+
+fn open(args: &ArgMatches) -> io::Result<Lines> {
+    let reader: Box<dyn BufRead> = match args.get_one::<String>("FILE").map(String::as_str) {
+        None | Some("-") => Box::new(io::stdin().lock()),
+        Some(path) => {
+            let file = File::open(path)
+                .map_err(|err| io::Error::new(err.kind(), format!("{path}: {err}")))?;
+            Box::new(BufReader::new(file))
+        }
+    };
+    let lines = reader
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(Ok((index + 1, line))),
+            Err(err) => Some(Err(err)),
+        });
+    Ok(Box::new(lines))
+}
+
+/// Prints an error for every invalid line, returning whether there were none.
+fn validate(lines: Lines) -> io::Result<bool> {
+    let mut valid = true;
+    for line in lines {
+        let (number, line) = line?;
+        if let Err(err) = wkt::check_syntax(&line) {
+            eprintln!("line {number}: {err}");
+            valid = false;
+        }
+    }
+    Ok(valid)
+}
+
+/// Reformats every line, stopping at the first invalid one.
+fn fmt(lines: Lines) -> io::Result<bool> {
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    let mut formatted = String::new();
+    for line in lines {
+        let (number, line) = line?;
+        formatted.clear();
+        if let Err(err) = wkt::reformat(&line, &mut formatted) {
+            stdout.flush()?;
+            eprintln!("line {number}: {err}");
+            return Ok(false);
+        }
+        writeln!(stdout, "{formatted}")?;
+    }
+    stdout.flush()?;
+    Ok(true)
+}
+
+fn stats(lines: Lines) -> io::Result<bool> {
+    let mut summary = TypeSummary::new();
+    let mut invalid = 0;
+    let mut coords = 0;
+    for line in lines {
+        let (_, line) = line?;
+        summary.add(&line);
+        match wkt::extract::coord_count(&line) {
+            Ok(count) => coords += count,
+            Err(_) => invalid += 1,
+        }
+    }
+
+    println!("geometries: {}", summary.count);
+    println!("invalid: {invalid}");
+    println!("empty: {}", summary.empty);
+    println!("coordinates: {coords}");
+    print_counts("types", &summary.geometry_types);
+    print_counts("dimensions", &summary.dimensions);
+    Ok(true)
+}
+
+/// Prints the counts under a heading, most common first.
+fn print_counts<K: ToString>(heading: &str, counts: &std::collections::HashMap<K, usize>) {
+    let mut counts: Vec<_> = counts
+        .iter()
+        .map(|(key, &count)| (key.to_string(), count))
+        .collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    println!("{heading}:");
+    for (key, count) in counts {
+        println!("  {key}: {count}");
+    }
+}
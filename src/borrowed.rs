@@ -0,0 +1,249 @@
+//! Geometries that borrow their members from slices, so they can be built in a `const` or
+//! `static`.
+//!
+//! The types in [`types`] hold their members in `Vec`s, which can't be allocated in
+//! a constant. These mirror them with slices instead, and [`wkt!`](crate::wkt!) creates them with
+//! the `borrowed:` prefix, which makes lookup tables of fixed geometries possible without an
+//! allocator or a [`LazyLock`](std::sync::LazyLock):
+//!
+//! ```
+//! use wkt::borrowed::{LineString, Polygon};
+//! use wkt::wkt;
+//!
+//! static ROUTE: LineString<f64> = wkt!(borrowed: LINESTRING(0.0 0.0,1.0 1.0,2.0 1.5));
+//! const SQUARE: Polygon<i32> = wkt!(borrowed: POLYGON((0 0,1 0,1 1,0 1,0 0)));
+//!
+//! assert_eq!(ROUTE.to_string(), "LINESTRING(0 0,1 1,2 1.5)");
+//! assert_eq!(wkt::types::Polygon::from(SQUARE).0[0].0.len(), 5);
+//! ```
+//!
+//! Points and coordinates are already `const`, so [`Point`] and [`Coord`] are the ones from
+//! [`types`]. There's no borrowed `Wkt` or `GeometryCollection`, and `LINEARRING`
+//! creates a [`LineString`].
+//!
+//! The geometries implement the [`geo_traits`] of their type, so they can be written with the
+//! functions in [`to_wkt`](crate::to_wkt), and convert into the owned types with [`From`].
+
+use std::fmt;
+
+use geo_traits::{
+    CoordTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait,
+    PointTrait, PolygonTrait,
+};
+
+use crate::to_wkt::{
+    write_linestring, write_multi_linestring, write_multi_point, write_multi_polygon, write_polygon,
+};
+use crate::types;
+use crate::WktNum;
+
+pub use crate::types::{Coord, Point};
+
+/// A [`types::LineString`] borrowing its coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LineString<'a, T: WktNum>(pub &'a [Coord<T>]);
+
+/// A [`types::Polygon`] borrowing its rings, the exterior first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Polygon<'a, T: WktNum>(pub &'a [LineString<'a, T>]);
+
+/// A [`types::MultiPoint`] borrowing its points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MultiPoint<'a, T: WktNum>(pub &'a [Point<T>]);
+
+/// A [`types::MultiLineString`] borrowing its line strings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MultiLineString<'a, T: WktNum>(pub &'a [LineString<'a, T>]);
+
+/// A [`types::MultiPolygon`] borrowing its polygons.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MultiPolygon<'a, T: WktNum>(pub &'a [Polygon<'a, T>]);
+
+impl<T: WktNum> LineStringTrait for LineString<'_, T> {
+    type T = T;
+    type CoordType<'b>
+        = &'b Coord<T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.0
+            .first()
+            .map_or(geo_traits::Dimensions::Xy, |coord| coord.dim())
+    }
+
+    fn num_coords(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+impl<'a, T: WktNum> PolygonTrait for Polygon<'a, T> {
+    type T = T;
+    type RingType<'b>
+        = LineString<'a, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.0
+            .first()
+            .map_or(geo_traits::Dimensions::Xy, |ring| ring.dim())
+    }
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        self.0.first().copied()
+    }
+
+    fn num_interiors(&self) -> usize {
+        self.0.len().saturating_sub(1)
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+        *self.0.get_unchecked(i + 1)
+    }
+}
+
+impl<T: WktNum> MultiPointTrait for MultiPoint<'_, T> {
+    type T = T;
+    type PointType<'b>
+        = &'b Point<T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        // Empty members have no dimension of their own, so the first non-empty one decides.
+        self.0
+            .iter()
+            .find(|point| point.0.is_some())
+            .map_or(geo_traits::Dimensions::Xy, |point| point.dim())
+    }
+
+    fn num_points(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> Self::PointType<'_> {
+        self.0.get_unchecked(i)
+    }
+}
+
+impl<'a, T: WktNum> MultiLineStringTrait for MultiLineString<'a, T> {
+    type T = T;
+    type LineStringType<'b>
+        = LineString<'a, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        // Empty members have no dimension of their own, so the first non-empty one decides.
+        self.0
+            .iter()
+            .find(|line_string| !line_string.0.is_empty())
+            .map_or(geo_traits::Dimensions::Xy, |line_string| line_string.dim())
+    }
+
+    fn num_line_strings(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn line_string_unchecked(&self, i: usize) -> Self::LineStringType<'_> {
+        *self.0.get_unchecked(i)
+    }
+}
+
+impl<'a, T: WktNum> MultiPolygonTrait for MultiPolygon<'a, T> {
+    type T = T;
+    type PolygonType<'b>
+        = Polygon<'a, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        // Empty members have no dimension of their own, so the first non-empty one decides.
+        self.0
+            .iter()
+            .find(|polygon| {
+                polygon
+                    .0
+                    .first()
+                    .is_some_and(|exterior| !exterior.0.is_empty())
+            })
+            .map_or(geo_traits::Dimensions::Xy, |polygon| polygon.dim())
+    }
+
+    fn num_polygons(&self) -> usize {
+        self.0.len()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::PolygonType<'_> {
+        *self.0.get_unchecked(i)
+    }
+}
+
+macro_rules! impl_display_and_from {
+    ($($type: ident => $write: ident),+) => {
+        $(
+            impl<T> fmt::Display for $type<'_, T>
+            where
+                T: WktNum + fmt::Display,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+                    Ok($write(f, self)?)
+                }
+            }
+
+            impl<T: WktNum> From<$type<'_, T>> for types::$type<T> {
+                fn from(geometry: $type<'_, T>) -> Self {
+                    types::$type(geometry.0.iter().cloned().map(Into::into).collect())
+                }
+            }
+        )+
+    };
+}
+
+impl_display_and_from!(
+    LineString => write_linestring,
+    Polygon => write_polygon,
+    MultiPoint => write_multi_point,
+    MultiLineString => write_multi_linestring,
+    MultiPolygon => write_multi_polygon
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Dimension;
+    use crate::Wkt;
+    use std::str::FromStr;
+
+    const POLYGONS: MultiPolygon<f64> = MultiPolygon(&[
+        Polygon(&[
+            LineString(&[
+                Coord::xyz(0., 0., 1.),
+                Coord::xyz(1., 0., 1.),
+                Coord::xyz(0., 1., 1.),
+                Coord::xyz(0., 0., 1.),
+            ]),
+            LineString(&[]),
+        ]),
+        Polygon(&[]),
+    ]);
+
+    #[test]
+    fn writes_like_owned_geometries() {
+        let owned =
+            Wkt::from_str("MULTIPOLYGON Z(((0 0 1,1 0 1,0 1 1,0 0 1),EMPTY),EMPTY)").unwrap();
+        assert_eq!(POLYGONS.to_string(), owned.to_string());
+        assert_eq!(Wkt::MultiPolygon(POLYGONS.into()), owned);
+        assert_eq!(POLYGONS.dim(), Dimension::XYZ.into());
+
+        let points = MultiPoint(&[Point(None), Point::new(Coord::xym(1, 2, 3))]);
+        assert_eq!(points.to_string(), "MULTIPOINT M(EMPTY,(1 2 3))");
+        let line_strings = MultiLineString::<i32>(&[]);
+        assert_eq!(line_strings.to_string(), "MULTILINESTRING EMPTY");
+    }
+}
@@ -0,0 +1,235 @@
+use std::cmp::Ordering;
+
+use geo_traits::GeometryTrait;
+
+use crate::types::{
+    Coord, GeometryCollection, LineString, LinearRing, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use crate::{Wkt, WktNum};
+
+/// A total ordering of geometries, shared by all types so that it can recurse into collections.
+pub(crate) trait CanonicalOrd {
+    fn canonical_cmp(&self, other: &Self) -> Ordering;
+}
+
+/// `NaN` sorts after every other value and equal to itself, making the order total.
+fn num_cmp<T: WktNum>(a: T, b: T) -> Ordering {
+    #[allow(clippy::eq_op)]
+    match a.partial_cmp(&b) {
+        Some(ordering) => ordering,
+        None => match (a != a, b != b) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            _ => Ordering::Less,
+        },
+    }
+}
+
+fn option_num_cmp<T: WktNum>(a: Option<T>, b: Option<T>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => num_cmp(a, b),
+        (a, b) => a.is_some().cmp(&b.is_some()),
+    }
+}
+
+/// Lexicographic comparison, where a strict prefix sorts first.
+fn slice_cmp<G: CanonicalOrd>(a: &[G], b: &[G]) -> Ordering {
+    for (a, b) in a.iter().zip(b) {
+        match a.canonical_cmp(b) {
+            Ordering::Equal => continue,
+            ordering => return ordering,
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+impl<T: WktNum> CanonicalOrd for Coord<T> {
+    fn canonical_cmp(&self, other: &Self) -> Ordering {
+        num_cmp(self.x, other.x)
+            .then_with(|| num_cmp(self.y, other.y))
+            .then_with(|| option_num_cmp(self.z, other.z))
+            .then_with(|| option_num_cmp(self.m, other.m))
+    }
+}
+
+impl<T: WktNum> CanonicalOrd for Point<T> {
+    fn canonical_cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => a.canonical_cmp(b),
+            (a, b) => a.is_some().cmp(&b.is_some()),
+        }
+    }
+}
+
+macro_rules! impl_canonical_ord_for_vec_wrapper {
+    ($($type: ident),+) => {
+        $(
+            impl<T: WktNum> CanonicalOrd for $type<T> {
+                fn canonical_cmp(&self, other: &Self) -> Ordering {
+                    slice_cmp(&self.0, &other.0)
+                }
+            }
+        )+
+    };
+}
+
+impl_canonical_ord_for_vec_wrapper!(
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+impl<T: WktNum> CanonicalOrd for LinearRing<T> {
+    fn canonical_cmp(&self, other: &Self) -> Ordering {
+        self.0.canonical_cmp(&other.0)
+    }
+}
+
+impl<T: WktNum> CanonicalOrd for Wkt<T> {
+    fn canonical_cmp(&self, other: &Self) -> Ordering {
+        // `GeometryType` discriminants follow declaration order.
+        let ordering = (self.geometry_type() as u8)
+            .cmp(&(other.geometry_type() as u8))
+            .then_with(|| dimension_rank(self).cmp(&dimension_rank(other)));
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+
+        match (self, other) {
+            (Wkt::Point(a), Wkt::Point(b)) => a.canonical_cmp(b),
+            (Wkt::LineString(a), Wkt::LineString(b)) => a.canonical_cmp(b),
+            (Wkt::LinearRing(a), Wkt::LinearRing(b)) => a.canonical_cmp(b),
+            (Wkt::Polygon(a), Wkt::Polygon(b)) => a.canonical_cmp(b),
+            (Wkt::MultiPoint(a), Wkt::MultiPoint(b)) => a.canonical_cmp(b),
+            (Wkt::MultiLineString(a), Wkt::MultiLineString(b)) => a.canonical_cmp(b),
+            (Wkt::MultiPolygon(a), Wkt::MultiPolygon(b)) => a.canonical_cmp(b),
+            (Wkt::GeometryCollection(a), Wkt::GeometryCollection(b)) => a.canonical_cmp(b),
+            _ => unreachable!("geometry types were compared above"),
+        }
+    }
+}
+
+fn dimension_rank<T: WktNum>(wkt: &Wkt<T>) -> usize {
+    match wkt.dim() {
+        geo_traits::Dimensions::Xy => 0,
+        geo_traits::Dimensions::Xyz => 1,
+        geo_traits::Dimensions::Xym => 2,
+        geo_traits::Dimensions::Xyzm => 3,
+        geo_traits::Dimensions::Unknown(size) => size,
+    }
+}
+
+impl<T> Wkt<T>
+where
+    T: WktNum,
+{
+    /// A total order over geometries that is stable across runs and platforms.
+    ///
+    /// Geometries are ordered by:
+    ///
+    /// 1. geometry type, in the order the variants of [`GeometryType`](crate::types::GeometryType)
+    ///    are declared
+    /// 2. dimension: `XY`, then `XYZ`, then `XYM`, then `XYZM`
+    /// 3. coordinates, lexicographically. Each coordinate compares `x`, `y`, `z` then `m`, and a
+    ///    sequence that is a prefix of another sorts first. Empty geometries therefore sort before
+    ///    non-empty ones of the same type.
+    ///
+    /// Numbers compare by value, so `0.0` and `-0.0` are equal. `NaN` sorts after every other
+    /// value and is equal to itself.
+    ///
+    /// This is useful for sorting and deduplicating lists of geometries:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let mut geometries: Vec<Wkt<f64>> = ["LINESTRING(0 0,1 1)", "POINT(2 2)", "POINT(1 1)", "POINT(2 2)"]
+    ///     .into_iter()
+    ///     .map(|s| Wkt::from_str(s).unwrap())
+    ///     .collect();
+    ///
+    /// geometries.sort_by(Wkt::canonical_cmp);
+    /// geometries.dedup_by(|a, b| a.canonical_cmp(b).is_eq());
+    ///
+    /// let sorted: Vec<String> = geometries.iter().map(|g| g.to_string()).collect();
+    /// assert_eq!(sorted, ["POINT(1 1)", "POINT(2 2)", "LINESTRING(0 0,1 1)"]);
+    /// ```
+    pub fn canonical_cmp(&self, other: &Self) -> Ordering {
+        CanonicalOrd::canonical_cmp(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn parse(wkt_str: &str) -> Wkt<f64> {
+        Wkt::from_str(wkt_str).unwrap()
+    }
+
+    #[test]
+    fn orders_by_type_first() {
+        assert_eq!(
+            parse("POINT(9 9)").canonical_cmp(&parse("LINESTRING(0 0,1 1)")),
+            Ordering::Less
+        );
+        assert_eq!(
+            parse("GEOMETRYCOLLECTION(POINT(0 0))").canonical_cmp(&parse("MULTIPOLYGON EMPTY")),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn orders_by_dimension() {
+        let xy = parse("POINT(9 9)");
+        let xyz = parse("POINT Z(0 0 0)");
+        let xym = parse("POINT M(0 0 0)");
+        let xyzm = parse("POINT ZM(0 0 0 0)");
+        assert_eq!(xy.canonical_cmp(&xyz), Ordering::Less);
+        assert_eq!(xyz.canonical_cmp(&xym), Ordering::Less);
+        assert_eq!(xym.canonical_cmp(&xyzm), Ordering::Less);
+    }
+
+    #[test]
+    fn orders_coordinates_lexicographically() {
+        let a = parse("LINESTRING(0 0,1 1)");
+        let b = parse("LINESTRING(0 0,1 2)");
+        let c = parse("LINESTRING(0 0,1 1,2 2)");
+        assert_eq!(a.canonical_cmp(&b), Ordering::Less);
+        assert_eq!(a.canonical_cmp(&c), Ordering::Less);
+        assert_eq!(c.canonical_cmp(&b), Ordering::Less);
+        assert_eq!(a.canonical_cmp(&a.clone()), Ordering::Equal);
+    }
+
+    #[test]
+    fn empty_sorts_first() {
+        assert_eq!(
+            parse("POINT EMPTY").canonical_cmp(&parse("POINT(0 0)")),
+            Ordering::Less
+        );
+        assert_eq!(
+            parse("POLYGON EMPTY").canonical_cmp(&parse("POLYGON((0 0,1 0,1 1,0 0))")),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn nan_sorts_last() {
+        let nan = Wkt::Point(Point(Some(Coord {
+            x: f64::NAN,
+            y: 0.0,
+            z: None,
+            m: None,
+        })));
+        assert_eq!(
+            nan.canonical_cmp(&parse("POINT(1e300 0)")),
+            Ordering::Greater
+        );
+        assert_eq!(nan.canonical_cmp(&nan.clone()), Ordering::Equal);
+    }
+}
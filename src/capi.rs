@@ -0,0 +1,310 @@
+//! A C API, with the `capi` feature, for using the parser from C, C++ or Python's `ctypes`.
+//!
+//! The declarations are in `include/wkt.h`, which is written by hand and must be updated along
+//! with this module. To get a library to link against, build this crate as one, e.g. with
+//! `cargo rustc --release --features capi --crate-type cdylib`.
+//!
+//! Geometries are parsed into opaque [`WktGeometry`] handles with `f64` coordinates, which must
+//! be released with [`wkt_free`]. Functions that fill a caller's buffer return the size they
+//! need, like `snprintf`, so they can be called with an empty buffer first.
+
+use std::ffi::{c_char, CStr};
+use std::ptr;
+use std::str::FromStr;
+
+use geo_traits::GeometryTrait;
+
+use crate::types::{Coord, Dimension, GeometryType};
+use crate::Wkt;
+
+/// A parsed geometry.
+pub struct WktGeometry(Wkt<f64>);
+
+/// The coordinate dimension of a geometry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WktDimension {
+    XY = 0,
+    XYZ = 1,
+    XYM = 2,
+    XYZM = 3,
+}
+
+impl From<Dimension> for WktDimension {
+    fn from(value: Dimension) -> Self {
+        match value {
+            Dimension::XY => Self::XY,
+            Dimension::XYZ => Self::XYZ,
+            Dimension::XYM => Self::XYM,
+            Dimension::XYZM => Self::XYZM,
+        }
+    }
+}
+
+/// Parses NUL-terminated WKT. On error, returns null and copies the message into `error`,
+/// unless it's null.
+///
+/// # Safety
+///
+/// `input` must be a valid NUL-terminated string, and `error` must be null or point to
+/// `error_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wkt_parse(
+    input: *const c_char,
+    error: *mut c_char,
+    error_len: usize,
+) -> *mut WktGeometry {
+    let parsed = match CStr::from_ptr(input).to_str() {
+        Ok(input) => Wkt::from_str(input),
+        Err(_) => Err("Input is not valid UTF-8"),
+    };
+    match parsed {
+        Ok(wkt) => Box::into_raw(Box::new(WktGeometry(wkt))),
+        Err(message) => {
+            if !error.is_null() {
+                copy_to_buffer(message, error, error_len);
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Releases a geometry returned by [`wkt_parse`]. Null is ignored.
+///
+/// # Safety
+///
+/// `geometry` must be null or a geometry from [`wkt_parse`] that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn wkt_free(geometry: *mut WktGeometry) {
+    if !geometry.is_null() {
+        drop(Box::from_raw(geometry));
+    }
+}
+
+/// The geometry's type keyword, e.g. `POLYGON`, as a static NUL-terminated string.
+///
+/// # Safety
+///
+/// `geometry` must be a live geometry from [`wkt_parse`].
+#[no_mangle]
+pub unsafe extern "C" fn wkt_geometry_type(geometry: *const WktGeometry) -> *const c_char {
+    let keyword = match (*geometry).0.geometry_type() {
+        GeometryType::Point => c"POINT",
+        GeometryType::LineString => c"LINESTRING",
+        GeometryType::LinearRing => c"LINEARRING",
+        GeometryType::Polygon => c"POLYGON",
+        GeometryType::MultiPoint => c"MULTIPOINT",
+        GeometryType::MultiLineString => c"MULTILINESTRING",
+        GeometryType::MultiPolygon => c"MULTIPOLYGON",
+        GeometryType::GeometryCollection => c"GEOMETRYCOLLECTION",
+        _ => unreachable!("`Wkt` has no extended geometry types"),
+    };
+    keyword.as_ptr()
+}
+
+/// The geometry's coordinate dimension.
+///
+/// # Safety
+///
+/// `geometry` must be a live geometry from [`wkt_parse`].
+#[no_mangle]
+pub unsafe extern "C" fn wkt_dimension(geometry: *const WktGeometry) -> WktDimension {
+    dimension(&(*geometry).0).into()
+}
+
+/// Copies the geometry's coordinates into `out`, in order, with as many values per coordinate
+/// as its dimension has. Returns the number of values, of which at most `capacity` are copied.
+///
+/// # Safety
+///
+/// `geometry` must be a live geometry from [`wkt_parse`], and `out` must be null or point to
+/// `capacity` writable `double`s.
+#[no_mangle]
+pub unsafe extern "C" fn wkt_coords(
+    geometry: *const WktGeometry,
+    out: *mut f64,
+    capacity: usize,
+) -> usize {
+    let wkt = &(*geometry).0;
+    let dim = dimension(wkt);
+    let mut len = 0;
+    for_each_coord(wkt, &mut |coord| {
+        let z = dim.has_z().then(|| coord.z.unwrap_or(f64::NAN));
+        let m = dim.has_m().then(|| coord.m.unwrap_or(f64::NAN));
+        for value in [Some(coord.x), Some(coord.y), z, m].into_iter().flatten() {
+            if len < capacity && !out.is_null() {
+                out.add(len).write(value);
+            }
+            len += 1;
+        }
+    });
+    len
+}
+
+/// Writes the geometry as WKT into `buffer`, truncated to fit and NUL-terminated if `buffer_len`
+/// isn't zero. Returns the length of the WKT, not counting the NUL, or 0 after writing an empty
+/// string if the geometry can't be written.
+///
+/// # Safety
+///
+/// `geometry` must be a live geometry from [`wkt_parse`], and `buffer` must point to
+/// `buffer_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wkt_write(
+    geometry: *const WktGeometry,
+    buffer: *mut c_char,
+    buffer_len: usize,
+) -> usize {
+    let wkt = (*geometry).0.wkt_string().unwrap_or_default();
+    copy_to_buffer(&wkt, buffer, buffer_len);
+    wkt.len()
+}
+
+/// Copies as much of `s` as fits into `buffer`, followed by a NUL, if `buffer_len` isn't zero.
+unsafe fn copy_to_buffer(s: &str, buffer: *mut c_char, buffer_len: usize) {
+    if buffer_len == 0 {
+        return;
+    }
+    let len = s.len().min(buffer_len - 1);
+    ptr::copy_nonoverlapping(s.as_ptr().cast(), buffer, len);
+    buffer.add(len).write(0);
+}
+
+fn dimension(wkt: &Wkt<f64>) -> Dimension {
+    // Parsed geometries always have a known dimension.
+    Dimension::try_from(wkt.dim()).unwrap_or_default()
+}
+
+fn for_each_coord(wkt: &Wkt<f64>, f: &mut impl FnMut(&Coord<f64>)) {
+    match wkt {
+        Wkt::Point(point) => point.0.iter().for_each(f),
+        Wkt::LineString(line_string) => line_string.0.iter().for_each(f),
+        Wkt::LinearRing(ring) => ring.0 .0.iter().for_each(f),
+        Wkt::Polygon(polygon) => polygon.0.iter().flat_map(|ring| &ring.0).for_each(f),
+        Wkt::MultiPoint(multi_point) => multi_point.0.iter().flat_map(|p| &p.0).for_each(f),
+        Wkt::MultiLineString(multi_line_string) => multi_line_string
+            .0
+            .iter()
+            .flat_map(|line_string| &line_string.0)
+            .for_each(f),
+        Wkt::MultiPolygon(multi_polygon) => multi_polygon
+            .0
+            .iter()
+            .flat_map(|polygon| &polygon.0)
+            .flat_map(|ring| &ring.0)
+            .for_each(f),
+        Wkt::GeometryCollection(collection) => {
+            for geometry in &collection.0 {
+                for_each_coord(geometry, f);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    fn parse(input: &str) -> Result<*mut WktGeometry, String> {
+        let input = CString::new(input).unwrap();
+        let mut error = [0 as c_char; 64];
+        let geometry = unsafe { wkt_parse(input.as_ptr(), error.as_mut_ptr(), error.len()) };
+        if geometry.is_null() {
+            let error = unsafe { CStr::from_ptr(error.as_ptr()) };
+            Err(error.to_str().unwrap().to_string())
+        } else {
+            Ok(geometry)
+        }
+    }
+
+    #[test]
+    fn inspects_geometry() {
+        let geometry =
+            parse("GEOMETRYCOLLECTION Z (POINT Z (1 2 3), LINESTRING Z (4 5 6, 7 8 9))").unwrap();
+        unsafe {
+            let keyword = CStr::from_ptr(wkt_geometry_type(geometry));
+            assert_eq!(keyword.to_str(), Ok("GEOMETRYCOLLECTION"));
+            assert_eq!(wkt_dimension(geometry), WktDimension::XYZ);
+
+            assert_eq!(wkt_coords(geometry, ptr::null_mut(), 0), 9);
+            let mut coords = [0.; 9];
+            assert_eq!(wkt_coords(geometry, coords.as_mut_ptr(), 4), 9);
+            assert_eq!(coords, [1., 2., 3., 4., 0., 0., 0., 0., 0.]);
+            wkt_coords(geometry, coords.as_mut_ptr(), coords.len());
+            assert_eq!(coords, [1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+
+            wkt_free(geometry);
+        }
+    }
+
+    #[test]
+    fn writes_into_buffer() {
+        let geometry = parse("POINT (10 -20)").unwrap();
+        unsafe {
+            let mut buffer = [1 as c_char; 8];
+            assert_eq!(wkt_write(geometry, buffer.as_mut_ptr(), buffer.len()), 13);
+            assert_eq!(CStr::from_ptr(buffer.as_ptr()).to_str(), Ok("POINT(1"));
+            assert_eq!(wkt_write(geometry, ptr::null_mut(), 0), 13);
+            wkt_free(geometry);
+            wkt_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn write_error_is_an_empty_string() {
+        let xyz = Coord {
+            x: 1.,
+            y: 2.,
+            z: Some(3.),
+            m: None,
+        };
+        let xy = Coord { z: None, ..xyz };
+        let geometry = WktGeometry(Wkt::LineString(crate::types::LineString(vec![xyz, xy])));
+        let mut buffer = [1 as c_char; 8];
+        assert_eq!(
+            unsafe { wkt_write(&geometry, buffer.as_mut_ptr(), buffer.len()) },
+            0
+        );
+        assert_eq!(buffer[0], 0);
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(
+            parse("POINT (1)").unwrap_err(),
+            "Expected a number for the Y coordinate"
+        );
+        let input = CString::new("POINT").unwrap();
+        let geometry = unsafe { wkt_parse(input.as_ptr(), ptr::null_mut(), 0) };
+        assert!(geometry.is_null());
+
+        let input = CString::new(b"POINT (1 2)\xff".to_vec()).unwrap();
+        let mut error = [0 as c_char; 64];
+        let geometry = unsafe { wkt_parse(input.as_ptr(), error.as_mut_ptr(), error.len()) };
+        assert!(geometry.is_null());
+        let error = unsafe { CStr::from_ptr(error.as_ptr()) };
+        assert_eq!(error.to_str(), Ok("Input is not valid UTF-8"));
+    }
+
+    #[test]
+    fn header_declares_every_function() {
+        let header = include_str!("../include/wkt.h");
+        let source = include_str!("capi.rs");
+        let exported: Vec<_> = source
+            .split("pub unsafe extern \"C\" fn ")
+            .skip(1)
+            .map(|rest| &rest[..rest.find('(').unwrap()])
+            .collect();
+        assert_eq!(exported.len(), 6);
+        for name in exported {
+            assert!(
+                header.contains(&format!("{name}(")),
+                "{name} isn't in wkt.h"
+            );
+        }
+        for name in ["WktGeometry", "WktDimension_XYZM = 3"] {
+            assert!(header.contains(name), "{name} isn't in wkt.h");
+        }
+    }
+}
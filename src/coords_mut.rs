@@ -0,0 +1,200 @@
+use crate::types::{
+    Coord, GeometryCollection, LineString, LinearRing, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use crate::{Wkt, WktNum};
+
+/// Visits every coordinate of a geometry mutably, shared by all types so that it can recurse into
+/// collections.
+pub(crate) trait CoordsMut<T: WktNum> {
+    fn coords_mut<F: FnMut(&mut Coord<T>)>(&mut self, f: &mut F);
+}
+
+impl<T: WktNum> CoordsMut<T> for Coord<T> {
+    fn coords_mut<F: FnMut(&mut Coord<T>)>(&mut self, f: &mut F) {
+        f(self)
+    }
+}
+
+impl<T: WktNum> CoordsMut<T> for Point<T> {
+    fn coords_mut<F: FnMut(&mut Coord<T>)>(&mut self, f: &mut F) {
+        if let Some(coord) = &mut self.0 {
+            f(coord)
+        }
+    }
+}
+
+impl<T: WktNum> CoordsMut<T> for LinearRing<T> {
+    fn coords_mut<F: FnMut(&mut Coord<T>)>(&mut self, f: &mut F) {
+        self.0.coords_mut(f)
+    }
+}
+
+macro_rules! impl_coords_mut_for_vec_wrapper {
+    ($($type: ident),+) => {
+        $(
+            impl<T: WktNum> CoordsMut<T> for $type<T> {
+                fn coords_mut<F: FnMut(&mut Coord<T>)>(&mut self, f: &mut F) {
+                    for item in &mut self.0 {
+                        item.coords_mut(f);
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_coords_mut_for_vec_wrapper!(
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+impl<T: WktNum> CoordsMut<T> for Wkt<T> {
+    fn coords_mut<F: FnMut(&mut Coord<T>)>(&mut self, f: &mut F) {
+        match self {
+            Wkt::Point(g) => g.coords_mut(f),
+            Wkt::LineString(g) => g.coords_mut(f),
+            Wkt::LinearRing(g) => g.coords_mut(f),
+            Wkt::Polygon(g) => g.coords_mut(f),
+            Wkt::MultiPoint(g) => g.coords_mut(f),
+            Wkt::MultiLineString(g) => g.coords_mut(f),
+            Wkt::MultiPolygon(g) => g.coords_mut(f),
+            Wkt::GeometryCollection(g) => g.coords_mut(f),
+        }
+    }
+}
+
+/// Visits every line string of a geometry mutably, including polygon rings and linear rings, for
+/// the helpers that change the number of coordinates.
+pub(crate) trait LineStringsMut<T: WktNum> {
+    fn line_strings_mut<F: FnMut(&mut LineString<T>)>(&mut self, f: &mut F);
+}
+
+impl<T: WktNum> LineStringsMut<T> for Point<T> {
+    fn line_strings_mut<F: FnMut(&mut LineString<T>)>(&mut self, _: &mut F) {}
+}
+
+impl<T: WktNum> LineStringsMut<T> for MultiPoint<T> {
+    fn line_strings_mut<F: FnMut(&mut LineString<T>)>(&mut self, _: &mut F) {}
+}
+
+impl<T: WktNum> LineStringsMut<T> for LineString<T> {
+    fn line_strings_mut<F: FnMut(&mut LineString<T>)>(&mut self, f: &mut F) {
+        f(self)
+    }
+}
+
+impl<T: WktNum> LineStringsMut<T> for LinearRing<T> {
+    fn line_strings_mut<F: FnMut(&mut LineString<T>)>(&mut self, f: &mut F) {
+        f(&mut self.0)
+    }
+}
+
+macro_rules! impl_line_strings_mut_for_vec_wrapper {
+    ($($type: ident),+) => {
+        $(
+            impl<T: WktNum> LineStringsMut<T> for $type<T> {
+                fn line_strings_mut<F: FnMut(&mut LineString<T>)>(&mut self, f: &mut F) {
+                    for item in &mut self.0 {
+                        item.line_strings_mut(f);
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_line_strings_mut_for_vec_wrapper!(Polygon, MultiLineString, MultiPolygon, GeometryCollection);
+
+impl<T: WktNum> LineStringsMut<T> for Wkt<T> {
+    fn line_strings_mut<F: FnMut(&mut LineString<T>)>(&mut self, f: &mut F) {
+        match self {
+            Wkt::Point(g) => g.line_strings_mut(f),
+            Wkt::LineString(g) => g.line_strings_mut(f),
+            Wkt::LinearRing(g) => g.line_strings_mut(f),
+            Wkt::Polygon(g) => g.line_strings_mut(f),
+            Wkt::MultiPoint(g) => g.line_strings_mut(f),
+            Wkt::MultiLineString(g) => g.line_strings_mut(f),
+            Wkt::MultiPolygon(g) => g.line_strings_mut(f),
+            Wkt::GeometryCollection(g) => g.line_strings_mut(f),
+        }
+    }
+}
+
+macro_rules! impl_coord_helpers {
+    ($($type: ident),+) => {
+        $(
+            impl<T: WktNum> $type<T> {
+                /// Calls `f` on every coordinate, in order, allowing it to be modified in place.
+                pub fn for_each_coord_mut(&mut self, mut f: impl FnMut(&mut Coord<T>)) {
+                    self.coords_mut(&mut f)
+                }
+
+                /// Swaps the `x` and `y` of every coordinate, in place, e.g. to switch between
+                /// latitude/longitude and longitude/latitude order.
+                pub fn swap_xy(&mut self) {
+                    self.for_each_coord_mut(|coord| std::mem::swap(&mut coord.x, &mut coord.y))
+                }
+            }
+        )+
+    };
+}
+
+impl_coord_helpers!(
+    Wkt,
+    Coord,
+    Point,
+    LineString,
+    LinearRing,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn swap_xy_recurses_into_collections() {
+        let mut wkt: Wkt<f64> = Wkt::from_str(
+            "GEOMETRYCOLLECTION(POINT EMPTY,MULTIPOLYGON(((1 2,3 4,5 6,1 2))),LINEARRING(1 2,3 4,1 2))",
+        )
+        .unwrap();
+        wkt.swap_xy();
+        assert_eq!(
+            wkt.to_string(),
+            "GEOMETRYCOLLECTION(POINT EMPTY,MULTIPOLYGON(((2 1,4 3,6 5,2 1))),LINEARRING(2 1,4 3,2 1))"
+        );
+    }
+
+    #[test]
+    fn for_each_coord_mut() {
+        let mut wkt: Wkt<f64> = Wkt::from_str("LINESTRING(1 2,3 4)").unwrap();
+        wkt.for_each_coord_mut(|coord| coord.x += 10.);
+        assert_eq!(wkt.to_string(), "LINESTRING(11 2,13 4)");
+    }
+
+    #[test]
+    fn swap_xy_on_types() {
+        let mut coord = Coord {
+            x: 1,
+            y: 2,
+            z: None,
+            m: Some(3),
+        };
+        coord.swap_xy();
+        assert_eq!((coord.x, coord.y, coord.m), (2, 1, Some(3)));
+
+        let mut line_string = LineString(vec![coord.clone(), coord]);
+        line_string.swap_xy();
+        assert_eq!(line_string.to_string(), "LINESTRING M(1 2 3,1 2 3)");
+    }
+}
@@ -0,0 +1,265 @@
+//! `Debug` for the geometry types.
+//!
+//! `{:?}` shows the structure, as a derived `Debug` would. `{:#?}` writes indented WKT instead, so
+//! snapshots of structs holding geometries don't change when the types' fields do:
+//!
+//! ```
+//! use std::str::FromStr;
+//! use wkt::Wkt;
+//!
+//! let wkt = Wkt::<f64>::from_str("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), EMPTY)").unwrap();
+//! assert_eq!(
+//!     format!("{wkt:#?}"),
+//!     "MULTIPOLYGON(
+//!     (
+//!         (0.0 0.0,1.0 0.0,1.0 1.0,0.0 0.0)
+//!     ),
+//!     EMPTY
+//! )"
+//! );
+//! ```
+//!
+//! Lists of coordinates stay on one line, and numbers are written with their own `Debug`.
+
+use std::fmt::{self, Write};
+
+use geo_traits::GeometryTrait;
+
+use crate::types::{
+    Coord, Dimension, GeometryCollection, LineString, LinearRing, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+use crate::{Wkt, WktNum};
+
+impl<T: WktNum> fmt::Debug for Wkt<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return Indented { f, depth: 0 }.wkt(self);
+        }
+        match self {
+            Wkt::Point(g) => f.debug_tuple("Point").field(g).finish(),
+            Wkt::LineString(g) => f.debug_tuple("LineString").field(g).finish(),
+            Wkt::LinearRing(g) => f.debug_tuple("LinearRing").field(g).finish(),
+            Wkt::Polygon(g) => f.debug_tuple("Polygon").field(g).finish(),
+            Wkt::MultiPoint(g) => f.debug_tuple("MultiPoint").field(g).finish(),
+            Wkt::MultiLineString(g) => f.debug_tuple("MultiLineString").field(g).finish(),
+            Wkt::MultiPolygon(g) => f.debug_tuple("MultiPolygon").field(g).finish(),
+            Wkt::GeometryCollection(g) => f.debug_tuple("GeometryCollection").field(g).finish(),
+        }
+    }
+}
+
+macro_rules! impl_debug {
+    ($($type: ident),+) => {
+        $(
+            impl<T: WktNum> fmt::Debug for $type<T> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if f.alternate() {
+                        // Cloning is simpler than a second writer for each type, and `{:#?}` is
+                        // for tests and logs.
+                        Indented { f, depth: 0 }.wkt(&Wkt::from(self.clone()))
+                    } else {
+                        f.debug_tuple(stringify!($type)).field(&self.0).finish()
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_debug!(
+    Point,
+    LineString,
+    LinearRing,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+/// Writes indented WKT: any list that holds lists or geometries puts each on its own line.
+struct Indented<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    depth: usize,
+}
+
+impl Indented<'_, '_> {
+    fn wkt<T: WktNum>(&mut self, wkt: &Wkt<T>) -> fmt::Result {
+        write!(self.f, "{}", wkt.geometry_type())?;
+        let dim = Dimension::try_from(wkt.dim()).unwrap_or_default();
+        if dim != Dimension::XY {
+            write!(self.f, " {}", dim.suffix())?;
+        }
+        let empty = match wkt {
+            Wkt::Point(g) => g.0.is_none(),
+            Wkt::LineString(g) => g.0.is_empty(),
+            Wkt::LinearRing(g) => g.0 .0.is_empty(),
+            Wkt::Polygon(g) => is_empty_polygon(g),
+            Wkt::MultiPoint(g) => g.0.is_empty(),
+            Wkt::MultiLineString(g) => g.0.is_empty(),
+            Wkt::MultiPolygon(g) => g.0.is_empty(),
+            Wkt::GeometryCollection(g) => g.0.is_empty(),
+        };
+        if empty {
+            return self.f.write_str(" EMPTY");
+        }
+        match wkt {
+            Wkt::Point(g) => self.point(g),
+            Wkt::LineString(g) => self.coords(&g.0),
+            Wkt::LinearRing(g) => self.coords(&g.0 .0),
+            Wkt::Polygon(g) => self.polygon(g),
+            Wkt::MultiPoint(g) => self.lines(&g.0, Self::point),
+            Wkt::MultiLineString(g) => self.lines(&g.0, |s, g| s.coords(&g.0)),
+            Wkt::MultiPolygon(g) => self.lines(&g.0, Self::polygon),
+            Wkt::GeometryCollection(g) => self.lines(&g.0, Self::wkt),
+        }
+    }
+
+    fn point<T: WktNum>(&mut self, point: &Point<T>) -> fmt::Result {
+        match &point.0 {
+            Some(coord) => self.coords(std::slice::from_ref(coord)),
+            None => self.f.write_str("EMPTY"),
+        }
+    }
+
+    fn polygon<T: WktNum>(&mut self, polygon: &Polygon<T>) -> fmt::Result {
+        if is_empty_polygon(polygon) {
+            self.f.write_str("EMPTY")
+        } else {
+            self.lines(&polygon.0, |s, ring| s.coords(&ring.0))
+        }
+    }
+
+    /// Writes `(`, each item on its own line, one level deeper, and `)` on a line of its own.
+    fn lines<I>(
+        &mut self,
+        items: &[I],
+        mut item: impl FnMut(&mut Self, &I) -> fmt::Result,
+    ) -> fmt::Result {
+        self.f.write_char('(')?;
+        self.depth += 1;
+        for (i, value) in items.iter().enumerate() {
+            if i > 0 {
+                self.f.write_char(',')?;
+            }
+            self.newline()?;
+            item(self, value)?;
+        }
+        self.depth -= 1;
+        self.newline()?;
+        self.f.write_char(')')
+    }
+
+    /// Writes a list of coordinates on one line, or `EMPTY`.
+    fn coords<T: WktNum>(&mut self, coords: &[Coord<T>]) -> fmt::Result {
+        if coords.is_empty() {
+            return self.f.write_str("EMPTY");
+        }
+        self.f.write_char('(')?;
+        for (i, coord) in coords.iter().enumerate() {
+            if i > 0 {
+                self.f.write_char(',')?;
+            }
+            write!(self.f, "{:?} {:?}", coord.x, coord.y)?;
+            for value in [coord.z, coord.m].into_iter().flatten() {
+                write!(self.f, " {value:?}")?;
+            }
+        }
+        self.f.write_char(')')
+    }
+
+    fn newline(&mut self) -> fmt::Result {
+        self.f.write_char('\n')?;
+        for _ in 0..self.depth {
+            self.f.write_str("    ")?;
+        }
+        Ok(())
+    }
+}
+
+/// A polygon is written as `EMPTY` when its exterior is, like `Display` does.
+fn is_empty_polygon<T: WktNum>(polygon: &Polygon<T>) -> bool {
+    polygon
+        .0
+        .first()
+        .map_or(true, |exterior| exterior.0.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn wkt(input: &str) -> Wkt<f64> {
+        Wkt::from_str(input).unwrap()
+    }
+
+    #[test]
+    fn debug_shows_the_structure() {
+        assert_eq!(
+            format!("{:?}", wkt("MULTIPOINT ((1 2), EMPTY)")),
+            "MultiPoint(MultiPoint([Point(Some(Coord { x: 1.0, y: 2.0, z: None, m: None })), \
+            Point(None)]))"
+        );
+        assert_eq!(format!("{:?}", Point::<f64>::empty()), "Point(None)");
+    }
+
+    #[test]
+    fn alternate_debug_is_indented_wkt() {
+        assert_eq!(
+            format!("{:#?}", wkt("POINT Z (1 2 3)")),
+            "POINT Z(1.0 2.0 3.0)"
+        );
+        assert_eq!(
+            format!("{:#?}", wkt("LINESTRING EMPTY")),
+            "LINESTRING EMPTY"
+        );
+        assert_eq!(
+            format!(
+                "{:#?}",
+                wkt("GEOMETRYCOLLECTION (POINT (1 2), MULTIPOINT (3 4, EMPTY))")
+            ),
+            "GEOMETRYCOLLECTION(
+    POINT(1.0 2.0),
+    MULTIPOINT(
+        (3.0 4.0),
+        EMPTY
+    )
+)"
+        );
+        let Wkt::Polygon(polygon) = wkt("POLYGON ((0 0, 1 0, 1 1, 0 0), EMPTY)") else {
+            unreachable!()
+        };
+        assert_eq!(
+            format!("{polygon:#?}"),
+            "POLYGON(
+    (0.0 0.0,1.0 0.0,1.0 1.0,0.0 0.0),
+    EMPTY
+)"
+        );
+    }
+
+    #[test]
+    fn alternate_debug_nests_in_derived_debug() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Feature {
+            id: u32,
+            geometry: Wkt<f64>,
+        }
+        let feature = Feature {
+            id: 1,
+            geometry: wkt("MULTILINESTRING ((1 2, 3 4))"),
+        };
+        assert_eq!(
+            format!("{feature:#?}"),
+            "Feature {
+    id: 1,
+    geometry: MULTILINESTRING(
+        (1.0 2.0,3.0 4.0)
+    ),
+}"
+        );
+    }
+}
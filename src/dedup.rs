@@ -0,0 +1,122 @@
+//! Removing consecutive duplicate coordinates, which most invalid line strings and rings are
+//! fixed by.
+
+use crate::coords_mut::LineStringsMut;
+use crate::types::{
+    Coord, GeometryCollection, LineString, LinearRing, MultiLineString, MultiPolygon, Polygon,
+};
+use crate::{Wkt, WktNum};
+
+/// Removes the coordinates that are the same as the one kept before them. The last coordinate is
+/// always kept, so closed rings stay closed.
+fn dedup<T: WktNum>(coords: &mut Vec<Coord<T>>, same: impl Fn(&Coord<T>, &Coord<T>) -> bool) {
+    let Some(end) = coords.last().cloned() else {
+        return;
+    };
+    coords.dedup_by(|next, kept| same(kept, next));
+    if let Some(last) = coords.last_mut() {
+        *last = end;
+    }
+}
+
+fn within<T: WktNum>(a: &Coord<T>, b: &Coord<T>, epsilon: T) -> bool {
+    let close = |a: T, b: T| {
+        if a > b {
+            a - b <= epsilon
+        } else {
+            b - a <= epsilon
+        }
+    };
+    let optional = |a: Option<T>, b: Option<T>| match (a, b) {
+        (Some(a), Some(b)) => close(a, b),
+        (None, None) => true,
+        _ => false,
+    };
+    close(a.x, b.x) && close(a.y, b.y) && optional(a.z, b.z) && optional(a.m, b.m)
+}
+
+macro_rules! impl_dedup_coords {
+    ($($type: ident),+) => {
+        $(
+            impl<T: WktNum> $type<T> {
+                /// Removes consecutive duplicate coordinates from every line string and ring, in
+                /// place. The last coordinate is kept, so closed rings stay closed.
+                ///
+                /// Run it before [`Wkt::validate`]: many libraries reject repeated points, and a
+                /// ring that collapses to fewer than 4 coordinates without them is then reported.
+                pub fn dedup_coords(&mut self) {
+                    self.line_strings_mut(&mut |line_string| dedup(&mut line_string.0, |a, b| a == b));
+                }
+
+                /// Like `dedup_coords`, but also removes a coordinate whose values each differ by
+                /// at most `epsilon` from the coordinate kept before it. Coordinates with
+                /// different dimensions are never duplicates.
+                pub fn dedup_coords_within(&mut self, epsilon: T) {
+                    self.line_strings_mut(&mut |line_string| {
+                        dedup(&mut line_string.0, |a, b| within(a, b, epsilon))
+                    });
+                }
+            }
+        )+
+    };
+}
+
+impl_dedup_coords!(
+    Wkt,
+    LineString,
+    LinearRing,
+    Polygon,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn wkt(input: &str) -> Wkt<f64> {
+        Wkt::from_str(input).unwrap()
+    }
+
+    #[test]
+    fn removes_consecutive_duplicates() {
+        let mut geometry = wkt("GEOMETRYCOLLECTION (LINESTRING (0 0, 0 0, 1 1, 0 0), \
+            MULTIPOLYGON (((0 0, 1 0, 1 0, 1 1, 0 0, 0 0))), MULTIPOINT (1 1, 1 1))");
+        geometry.dedup_coords();
+        assert_eq!(
+            geometry.to_string(),
+            "GEOMETRYCOLLECTION(LINESTRING(0 0,1 1,0 0),\
+            MULTIPOLYGON(((0 0,1 0,1 1,0 0))),MULTIPOINT((1 1),(1 1)))"
+        );
+    }
+
+    #[test]
+    fn removes_near_duplicates_and_keeps_rings_closed() {
+        let mut polygon = wkt("POLYGON ((0 0, 1 0, 1.01 0, 1 1, 0.01 0.01, 0 0))");
+        polygon.dedup_coords_within(0.02);
+        assert_eq!(polygon.to_string(), "POLYGON((0 0,1 0,1 1,0 0))");
+        assert!(polygon.validate().is_empty());
+
+        let mut line = wkt("LINESTRING Z (0 0 0, 0 0 1, 0.5 0 1)");
+        line.dedup_coords_within(0.5);
+        assert_eq!(line.to_string(), "LINESTRING Z(0 0 0,0.5 0 1)");
+    }
+
+    #[test]
+    fn exposes_collapsed_rings_to_validation() {
+        let mut polygon = wkt("POLYGON ((0 0, 1 0, 1 0, 0 0))");
+        assert!(polygon.validate().is_empty());
+        polygon.dedup_coords();
+        assert_eq!(polygon.to_string(), "POLYGON((0 0,1 0,0 0))");
+        assert_eq!(polygon.validate().len(), 1);
+    }
+
+    #[test]
+    fn dedups_types() {
+        let mut line = LineString(vec![Coord::xy(1, 2), Coord::xy(1, 2)]);
+        line.dedup_coords_within(0);
+        assert_eq!(line.0, [Coord::xy(1, 2)]);
+    }
+}
@@ -144,10 +144,13 @@ where
     }
 }
 
+// Only the tests below deserialize through this visitor.
+#[cfg(test)]
 struct GeometryVisitor<T> {
     _marker: PhantomData<T>,
 }
 
+#[cfg(test)]
 impl<T> Default for GeometryVisitor<T> {
     fn default() -> Self {
         GeometryVisitor {
@@ -156,6 +159,7 @@ impl<T> Default for GeometryVisitor<T> {
     }
 }
 
+#[cfg(test)]
 impl<T> Visitor<'_> for GeometryVisitor<T>
 where
     T: FromStr + Default + WktNum,
@@ -1,17 +1,100 @@
 use std::fmt;
 
-use thiserror::Error;
+use crate::types::Dimension;
 
 /// Generic errors for WKT writing and reading
-#[derive(Error, Debug)]
+#[derive(Debug)]
 pub enum Error {
-    #[error("Only 2D input is supported when writing Rect to WKT.")]
     RectUnsupportedDimension,
-    #[error("Only defined dimensions and undefined dimensions of 2, 3, or 4 are supported.")]
     UnknownDimension,
+    /// Returned by the checked constructors, e.g.
+    /// [`LineString::from_coords_checked`](crate::types::LineString::from_coords_checked), when an
+    /// element's dimension differs from the first non-empty element's.
+    MixedDimension {
+        /// The position of the offending element in the input.
+        index: usize,
+        expected: Dimension,
+        found: Dimension,
+    },
+    /// Returned by [`Wkt::with_m`](crate::Wkt::with_m) when the number of values doesn't match
+    /// the number of coordinates.
+    MeasureCountMismatch {
+        coords: usize,
+        values: usize,
+    },
+    /// Returned when writing a coordinate that has fewer values than its geometry's dimension,
+    /// e.g. an XY coordinate inside an XYZ line string.
+    MissingOrdinate,
+    /// Returned by [`reformat`](crate::reformat) and [`approx_eq`](crate::approx_eq) when the
+    /// input is not valid WKT.
+    InvalidWkt(&'static str),
+    /// A [`ParseError`] from [`Wkt::parse`](crate::Wkt::parse), converted with `?`.
+    Parse(ParseError),
     /// Wrapper around `[std::fmt::Error]`
-    #[error(transparent)]
-    FmtError(#[from] std::fmt::Error),
+    FmtError(std::fmt::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::RectUnsupportedDimension => {
+                f.write_str("Only 2D input is supported when writing Rect to WKT.")
+            }
+            Error::UnknownDimension => f.write_str(
+                "Only defined dimensions and undefined dimensions of 2, 3, or 4 are supported.",
+            ),
+            Error::MixedDimension {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Mixed dimensions: expected {expected}, found {found} at index {index}."
+            ),
+            Error::MeasureCountMismatch { coords, values } => write!(
+                f,
+                "Expected {coords} M values, one per coordinate, but found {values}."
+            ),
+            Error::MissingOrdinate => {
+                f.write_str("A coordinate has fewer values than its geometry's dimension.")
+            }
+            Error::InvalidWkt(message) => write!(f, "Invalid WKT: {message}"),
+            Error::Parse(err) => write!(f, "Invalid WKT: {err}"),
+            Error::FmtError(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Why WKT couldn't be parsed, and where, returned by [`Wkt::parse`](crate::Wkt::parse).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The same message [`Wkt::from_str`](crate::Wkt) returns.
+    pub message: &'static str,
+    /// The byte offset where the rejected token starts, or the input's length if the input
+    /// ended too early.
+    pub position: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}, near byte {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Error::Parse(value)
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(value: fmt::Error) -> Self {
+        Error::FmtError(value)
+    }
 }
 
 impl From<Error> for fmt::Error {
@@ -0,0 +1,63 @@
+/// Estimates the number of coordinates in a WKT string without parsing it.
+///
+/// This is a single pass over the bytes, counting each run of values that ends in a `,` or `)`
+/// and starts with a digit. It is exact for valid WKT with numeric coordinates, but doesn't
+/// validate anything, so it's meant for cost estimates before a full parse. Coordinates written
+/// only with words, like `NaN NaN`, aren't counted.
+///
+/// ```
+/// use wkt::estimate_coord_count;
+///
+/// assert_eq!(estimate_coord_count("POINT (1 2)"), 1);
+/// assert_eq!(estimate_coord_count("POLYGON Z ((0 0 0, 1 0 0, 1 1 0, 0 0 0))"), 4);
+/// assert_eq!(estimate_coord_count("MULTIPOINT EMPTY"), 0);
+/// ```
+pub fn estimate_coord_count(input: &str) -> usize {
+    let mut count = 0;
+    let mut in_coord = false;
+    for &byte in input.as_bytes() {
+        match byte {
+            b',' | b')' => {
+                count += in_coord as usize;
+                in_coord = false;
+            }
+            b'(' => in_coord = false,
+            b'0'..=b'9' => in_coord = true,
+            _ => {}
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_coordinates() {
+        assert_eq!(
+            estimate_coord_count("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))"),
+            8
+        );
+        assert_eq!(
+            estimate_coord_count("GEOMETRYCOLLECTION (POINT ZM (1 2 3 4), LINESTRING EMPTY)"),
+            1
+        );
+        assert_eq!(estimate_coord_count("MULTIPOINT ((-1e5 .5), (2 3))"), 2);
+        assert_eq!(estimate_coord_count("POINT EMPTY"), 0);
+        assert_eq!(estimate_coord_count(""), 0);
+    }
+
+    #[test]
+    fn matches_parsed_count() {
+        use crate::Wkt;
+        use std::str::FromStr;
+
+        let input = include_str!("../benches/small.wkt");
+        let mut count = 0;
+        Wkt::<f64>::from_str(input)
+            .unwrap()
+            .for_each_coord_mut(|_| count += 1);
+        assert_eq!(estimate_coord_count(input), count);
+    }
+}
@@ -0,0 +1,156 @@
+//! Extract a bounding box, coordinate count or first coordinate from WKT without building the
+//! geometry.
+//!
+//! Each function validates the whole input like [`reformat`](crate::reformat) does, but keeps
+//! only what it's asked for, so nothing is allocated.
+//!
+//! ```
+//! use wkt::extract;
+//!
+//! let input = "MULTIPOINT ((1 5), (3 -2), (-4 0))";
+//! let bbox = extract::bbox(input).unwrap().unwrap();
+//! assert_eq!((bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y), (-4., -2., 3., 5.));
+//! assert_eq!(extract::coord_count(input).unwrap(), 3);
+//! assert_eq!(extract::first_coord(input).unwrap().unwrap().x, 1.);
+//! ```
+
+use crate::error::Error;
+use crate::scan::{scan, Visitor};
+use crate::types::Coord;
+
+/// The 2D bounding box of a geometry's coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bbox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl Bbox {
+    /// Grows `bbox` to include `coord`, starting one at `coord` if there isn't one yet.
+    pub(crate) fn include(bbox: &mut Option<Self>, coord: &Coord<f64>) {
+        match bbox {
+            Some(bbox) => {
+                bbox.min_x = bbox.min_x.min(coord.x);
+                bbox.min_y = bbox.min_y.min(coord.y);
+                bbox.max_x = bbox.max_x.max(coord.x);
+                bbox.max_y = bbox.max_y.max(coord.y);
+            }
+            None => {
+                *bbox = Some(Bbox {
+                    min_x: coord.x,
+                    min_y: coord.y,
+                    max_x: coord.x,
+                    max_y: coord.y,
+                })
+            }
+        }
+    }
+
+    /// Whether `x` and `y` are inside the box or on its boundary.
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    /// Whether the two boxes overlap or touch.
+    pub fn intersects(&self, other: &Bbox) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+}
+
+/// The bounding box of the coordinates in `input`, or `None` for an empty geometry.
+///
+/// `NaN` values are ignored, as `f64::min` and `f64::max` do.
+pub fn bbox(input: &str) -> Result<Option<Bbox>, Error> {
+    let mut bbox = None;
+    scan(
+        input,
+        &mut OnCoord(|coord: Coord<f64>| Bbox::include(&mut bbox, &coord)),
+    )?;
+    Ok(bbox)
+}
+
+/// The number of coordinates in `input`.
+///
+/// Unlike [`estimate_coord_count`](crate::estimate_coord_count), this is exact and checks that
+/// the input is valid.
+pub fn coord_count(input: &str) -> Result<usize, Error> {
+    let mut count = 0;
+    scan(input, &mut OnCoord(|_| count += 1))?;
+    Ok(count)
+}
+
+/// The first coordinate in `input`, or `None` for an empty geometry.
+pub fn first_coord(input: &str) -> Result<Option<Coord<f64>>, Error> {
+    let mut first = None;
+    scan(
+        input,
+        &mut OnCoord(|coord| {
+            first.get_or_insert(coord);
+        }),
+    )?;
+    Ok(first)
+}
+
+/// A [`Visitor`] that only looks at coordinates.
+struct OnCoord<F>(F);
+
+impl<F: FnMut(Coord<f64>)> Visitor for OnCoord<F> {
+    fn coord(&mut self, coord: Coord<f64>) -> Result<(), Error> {
+        (self.0)(coord);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bbox_of_collection() {
+        let input = "GEOMETRYCOLLECTION (POINT Z (10 20 30), LINESTRING (-1 5, 3 -7), POINT EMPTY)";
+        assert_eq!(
+            bbox(input).unwrap(),
+            Some(Bbox {
+                min_x: -1.,
+                min_y: -7.,
+                max_x: 10.,
+                max_y: 20.,
+            })
+        );
+        assert_eq!(bbox("POLYGON EMPTY").unwrap(), None);
+        assert!(bbox("POINT (1)").is_err());
+    }
+
+    #[test]
+    fn bbox_predicates() {
+        let bbox = bbox("LINESTRING (0 0, 10 10)").unwrap().unwrap();
+        assert!(bbox.contains(10., 5.));
+        assert!(!bbox.contains(11., 5.));
+        let other = super::bbox("POINT (10 10)").unwrap().unwrap();
+        assert!(bbox.intersects(&other));
+        let other = super::bbox("LINESTRING (11 0, 12 1)").unwrap().unwrap();
+        assert!(!bbox.intersects(&other));
+    }
+
+    #[test]
+    fn counts_and_first_coord() {
+        let input = "MULTIPOLYGON M (((0 0 1, 1 0 2, 1 1 3, 0 0 4)), EMPTY)";
+        assert_eq!(coord_count(input).unwrap(), 4);
+        assert_eq!(
+            first_coord(input).unwrap(),
+            Some(Coord {
+                x: 0.,
+                y: 0.,
+                z: None,
+                m: Some(1.),
+            })
+        );
+        assert_eq!(first_coord("POINT EMPTY").unwrap(), None);
+        assert!(coord_count("LINESTRING (0 0, 1 1").is_err());
+    }
+}
@@ -0,0 +1,1079 @@
+//! A flat, struct-of-arrays representation of many geometries.
+//!
+//! [`FlatGeometries`] stores the coordinates of all its geometries in one contiguous buffer, with
+//! offset arrays describing how they nest, similar to the
+//! [GeoArrow](https://geoarrow.org/format.html) layout. Parsing into it with
+//! [`FlatGeometries::push_str`] doesn't allocate per geometry, and the buffers are reused as more
+//! geometries are added, which makes it a good fit for loading WKT into columnar formats.
+//!
+//! Geometries are read back through [`geo_traits`] views, so they can be written with
+//! [`to_wkt`](crate::to_wkt) or passed to anything else that accepts `geo_traits` input.
+//!
+//! ```
+//! use geo_traits::{GeometryTrait, GeometryType, LineStringTrait};
+//! use wkt::flat::FlatGeometries;
+//!
+//! let mut geometries = FlatGeometries::<f64>::new();
+//! geometries.push_str("POINT(1 2)").unwrap();
+//! geometries.push_str("LINESTRING Z(0 0 0,1 1 1,2 2 2)").unwrap();
+//! assert_eq!(geometries.len(), 2);
+//!
+//! let line = geometries.get(1).unwrap();
+//! match line.as_type() {
+//!     GeometryType::LineString(line) => assert_eq!(line.num_coords(), 3),
+//!     _ => unreachable!(),
+//! }
+//! assert_eq!(line.to_string(), "LINESTRING Z(0 0 0,1 1 1,2 2 2)");
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+
+use crate::error::Error;
+use crate::to_wkt::write_geometry;
+use crate::tokenizer::{parse_number, Token, Tokens};
+use crate::types::{Dimension, GeometryType};
+use crate::{infer_geom_dimension, WktNum};
+
+#[derive(Clone, Debug)]
+struct Entry {
+    geometry_type: GeometryType,
+    dim: Dimension,
+    /// Indices into `part_offsets`, for all types but geometry collections.
+    parts: Range<usize>,
+    /// Indices into `members`, for geometry collections.
+    members: Range<usize>,
+}
+
+/// Many geometries, stored in flat buffers.
+///
+/// Each geometry is made of parts, each part of rings and each ring of coordinates:
+///
+/// | Type              | Parts                    | Rings per part |
+/// |-------------------|--------------------------|----------------|
+/// | `POINT`           | 1, or 0 if empty         | 1              |
+/// | `LINESTRING`      | 1, or 0 if empty         | 1              |
+/// | `POLYGON`         | 1, or 0 if empty         | any            |
+/// | `MULTIPOINT`      | one per point            | 1              |
+/// | `MULTILINESTRING` | one per line string      | 1              |
+/// | `MULTIPOLYGON`    | one per polygon          | any            |
+///
+/// Geometry collections instead refer to their members, which are stored as geometries of their
+/// own.
+#[derive(Clone, Debug)]
+pub struct FlatGeometries<T: WktNum> {
+    /// The values of all coordinates, `x`, `y`, then `z` and `m` if the geometry has them.
+    coords: Vec<T>,
+    /// The start of each ring in `coords`, plus the end of the last one.
+    ring_offsets: Vec<usize>,
+    /// The first ring of each part, plus the end of the last one.
+    part_offsets: Vec<usize>,
+    /// All geometries, including the members of geometry collections.
+    entries: Vec<Entry>,
+    /// Indices into `entries` of the members of each geometry collection.
+    members: Vec<usize>,
+    /// Indices into `entries` of the geometries that were pushed.
+    roots: Vec<usize>,
+}
+
+impl<T: WktNum> Default for FlatGeometries<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: WktNum> FlatGeometries<T> {
+    pub fn new() -> Self {
+        FlatGeometries {
+            coords: vec![],
+            ring_offsets: vec![0],
+            part_offsets: vec![0],
+            entries: vec![],
+            members: vec![],
+            roots: vec![],
+        }
+    }
+
+    /// The number of geometries that were pushed.
+    pub fn len(&self) -> usize {
+        self.roots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// The `i`th geometry that was pushed.
+    pub fn get(&self, i: usize) -> Option<FlatGeometry<'_, T>> {
+        self.roots.get(i).map(|&entry| self.geometry(entry))
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = FlatGeometry<'_, T>> + '_ {
+        self.roots.iter().map(|&entry| self.geometry(entry))
+    }
+
+    /// The coordinate values of all geometries, in the order they were pushed.
+    pub fn coords(&self) -> &[T] {
+        &self.coords
+    }
+
+    /// Appends a geometry from any [`geo_traits`] input, such as a [`Wkt`](crate::Wkt).
+    ///
+    /// `RECT`, `TRIANGLE` and `LINE` are stored as a polygon, polygon and line string
+    /// respectively. On error, nothing is added.
+    pub fn push_geometry(&mut self, geometry: &impl GeometryTrait<T = T>) -> Result<(), Error> {
+        let checkpoint = self.checkpoint();
+        match self.push_geometry_entry(geometry) {
+            Ok(entry) => {
+                self.roots.push(entry);
+                Ok(())
+            }
+            Err(err) => {
+                self.restore(checkpoint);
+                Err(err)
+            }
+        }
+    }
+
+    fn geometry(&self, entry: usize) -> FlatGeometry<'_, T> {
+        let Entry {
+            geometry_type,
+            dim,
+            parts,
+            members,
+        } = self.entries[entry].clone();
+        let inner = match geometry_type {
+            GeometryType::Point => FlatGeometryInner::Point(FlatPoint {
+                coord: self
+                    .first_ring(&parts)
+                    .and_then(|ring| self.coord_values(ring, dim).next()),
+                dim,
+            }),
+            GeometryType::LineString | GeometryType::LinearRing => {
+                FlatGeometryInner::LineString(FlatLineString {
+                    values: self.first_ring(&parts).map_or(&[], |ring| self.ring(ring)),
+                    dim,
+                })
+            }
+            GeometryType::Polygon => FlatGeometryInner::Polygon(FlatPolygon {
+                geometries: self,
+                rings: parts.clone().next().map_or(0..0, |part| self.rings(part)),
+                dim,
+            }),
+            GeometryType::MultiPoint => FlatGeometryInner::MultiPoint(FlatMultiPoint {
+                geometries: self,
+                parts,
+                dim,
+            }),
+            GeometryType::MultiLineString => {
+                FlatGeometryInner::MultiLineString(FlatMultiLineString {
+                    geometries: self,
+                    parts,
+                    dim,
+                })
+            }
+            GeometryType::MultiPolygon => FlatGeometryInner::MultiPolygon(FlatMultiPolygon {
+                geometries: self,
+                parts,
+                dim,
+            }),
+            GeometryType::GeometryCollection => {
+                FlatGeometryInner::GeometryCollection(FlatGeometryCollection {
+                    geometries: self,
+                    members: &self.members[members],
+                    dim,
+                })
+            }
+            extended => unreachable!("{extended} geometries are never stored"),
+        };
+        FlatGeometry { inner }
+    }
+
+    fn rings(&self, part: usize) -> Range<usize> {
+        self.part_offsets[part]..self.part_offsets[part + 1]
+    }
+
+    fn first_ring(&self, parts: &Range<usize>) -> Option<usize> {
+        parts
+            .clone()
+            .next()
+            .and_then(|part| self.rings(part).next())
+    }
+
+    fn ring(&self, ring: usize) -> &[T] {
+        &self.coords[self.ring_offsets[ring]..self.ring_offsets[ring + 1]]
+    }
+
+    fn coord_values(&self, ring: usize, dim: Dimension) -> impl Iterator<Item = &[T]> + '_ {
+        self.ring(ring).chunks_exact(dim.size())
+    }
+
+    fn checkpoint(&self) -> [usize; 5] {
+        [
+            self.coords.len(),
+            self.ring_offsets.len(),
+            self.part_offsets.len(),
+            self.entries.len(),
+            self.members.len(),
+        ]
+    }
+
+    fn restore(&mut self, [coords, ring_offsets, part_offsets, entries, members]: [usize; 5]) {
+        self.coords.truncate(coords);
+        self.ring_offsets.truncate(ring_offsets);
+        self.part_offsets.truncate(part_offsets);
+        self.entries.truncate(entries);
+        self.members.truncate(members);
+    }
+
+    fn end_ring(&mut self) {
+        self.ring_offsets.push(self.coords.len());
+    }
+
+    fn end_part(&mut self) {
+        self.part_offsets.push(self.ring_offsets.len() - 1);
+    }
+
+    fn begin_entry(&mut self, geometry_type: GeometryType, dim: Dimension) -> usize {
+        let parts = self.part_offsets.len() - 1;
+        self.entries.push(Entry {
+            geometry_type,
+            dim,
+            parts: parts..parts,
+            members: 0..0,
+        });
+        self.entries.len() - 1
+    }
+
+    fn end_entry(&mut self, entry: usize) {
+        self.entries[entry].parts.end = self.part_offsets.len() - 1;
+    }
+
+    fn end_collection(&mut self, entry: usize, members: Vec<usize>) {
+        let start = self.members.len();
+        self.members.extend(members);
+        self.entries[entry].members = start..self.members.len();
+    }
+
+    fn push_coord_trait(
+        &mut self,
+        coord: &impl CoordTrait<T = T>,
+        dim: Dimension,
+    ) -> Result<(), Error> {
+        for n in 0..dim.size() {
+            self.coords
+                .push(coord.nth(n).ok_or(Error::MissingOrdinate)?);
+        }
+        Ok(())
+    }
+
+    fn push_ring_trait(
+        &mut self,
+        ring: &impl LineStringTrait<T = T>,
+        dim: Dimension,
+    ) -> Result<(), Error> {
+        for coord in ring.coords() {
+            self.push_coord_trait(&coord, dim)?;
+        }
+        self.end_ring();
+        Ok(())
+    }
+
+    fn push_polygon_trait(
+        &mut self,
+        polygon: &impl PolygonTrait<T = T>,
+        dim: Dimension,
+    ) -> Result<(), Error> {
+        if let Some(exterior) = polygon.exterior() {
+            self.push_ring_trait(&exterior, dim)?;
+            for interior in polygon.interiors() {
+                self.push_ring_trait(&interior, dim)?;
+            }
+        }
+        self.end_part();
+        Ok(())
+    }
+
+    fn push_geometry_entry(
+        &mut self,
+        geometry: &impl GeometryTrait<T = T>,
+    ) -> Result<usize, Error> {
+        use geo_traits::GeometryType as Type;
+
+        let dim = Dimension::try_from(geometry.dim())?;
+        let entry = match geometry.as_type() {
+            Type::Point(point) => {
+                let entry = self.begin_entry(GeometryType::Point, dim);
+                if let Some(coord) = point.coord() {
+                    self.push_coord_trait(&coord, dim)?;
+                    self.end_ring();
+                    self.end_part();
+                }
+                entry
+            }
+            Type::LineString(line_string) => {
+                let entry = self.begin_entry(GeometryType::LineString, dim);
+                if line_string.num_coords() != 0 {
+                    self.push_ring_trait(line_string, dim)?;
+                    self.end_part();
+                }
+                entry
+            }
+            Type::Polygon(polygon) => {
+                let entry = self.begin_entry(GeometryType::Polygon, dim);
+                if polygon
+                    .exterior()
+                    .is_some_and(|ring| ring.num_coords() != 0)
+                {
+                    self.push_polygon_trait(polygon, dim)?;
+                }
+                entry
+            }
+            Type::MultiPoint(multi_point) => {
+                let entry = self.begin_entry(GeometryType::MultiPoint, dim);
+                for point in multi_point.points() {
+                    if let Some(coord) = point.coord() {
+                        self.push_coord_trait(&coord, dim)?;
+                    }
+                    self.end_ring();
+                    self.end_part();
+                }
+                entry
+            }
+            Type::MultiLineString(multi_line_string) => {
+                let entry = self.begin_entry(GeometryType::MultiLineString, dim);
+                for line_string in multi_line_string.line_strings() {
+                    self.push_ring_trait(&line_string, dim)?;
+                    self.end_part();
+                }
+                entry
+            }
+            Type::MultiPolygon(multi_polygon) => {
+                let entry = self.begin_entry(GeometryType::MultiPolygon, dim);
+                for polygon in multi_polygon.polygons() {
+                    self.push_polygon_trait(&polygon, dim)?;
+                }
+                entry
+            }
+            Type::GeometryCollection(collection) => {
+                let entry = self.begin_entry(GeometryType::GeometryCollection, dim);
+                let members = collection
+                    .geometries()
+                    .map(|member| self.push_geometry_entry(&member))
+                    .collect::<Result<_, _>>()?;
+                self.end_collection(entry, members);
+                return Ok(entry);
+            }
+            Type::Rect(rect) => {
+                if dim != Dimension::XY {
+                    return Err(Error::RectUnsupportedDimension);
+                }
+                let entry = self.begin_entry(GeometryType::Polygon, dim);
+                let (min, max) = (rect.min(), rect.max());
+                for (x, y) in [
+                    (max.x(), min.y()),
+                    (max.x(), max.y()),
+                    (min.x(), max.y()),
+                    (min.x(), min.y()),
+                    (max.x(), min.y()),
+                ] {
+                    self.coords.extend([x, y]);
+                }
+                self.end_ring();
+                self.end_part();
+                entry
+            }
+            Type::Triangle(triangle) => {
+                let entry = self.begin_entry(GeometryType::Polygon, dim);
+                let [a, b, c] = triangle.coords();
+                for coord in [&a, &b, &c, &a] {
+                    self.push_coord_trait(coord, dim)?;
+                }
+                self.end_ring();
+                self.end_part();
+                entry
+            }
+            Type::Line(line) => {
+                let entry = self.begin_entry(GeometryType::LineString, dim);
+                self.push_coord_trait(&line.start(), dim)?;
+                self.push_coord_trait(&line.end(), dim)?;
+                self.end_ring();
+                self.end_part();
+                entry
+            }
+        };
+        self.end_entry(entry);
+        Ok(entry)
+    }
+}
+
+impl<T> FlatGeometries<T>
+where
+    T: WktNum + FromStr + Default,
+{
+    /// Parses a WKT string and appends the geometry, writing its coordinates straight into the
+    /// flat buffers. On error, nothing is added.
+    ///
+    /// This accepts the same input as [`Wkt::from_str`](crate::Wkt::from_str) and returns the
+    /// same errors.
+    pub fn push_str(&mut self, wkt_str: &str) -> Result<(), &'static str> {
+        let checkpoint = self.checkpoint();
+        let mut tokens = Tokens::from_str(wkt_str);
+        let result = match tokens.next() {
+            Some(Token::Word(word)) if word.is_ascii() => self.parse_geometry(word, &mut tokens),
+            Some(Token::Word(_)) => Err("Encountered non-ascii word"),
+            _ => Err("Invalid WKT format"),
+        };
+        match result {
+            Ok(entry) => {
+                self.roots.push(entry);
+                Ok(())
+            }
+            Err(err) => {
+                self.restore(checkpoint);
+                Err(err)
+            }
+        }
+    }
+
+    fn parse_geometry(
+        &mut self,
+        word: &str,
+        tokens: &mut Tokens<'_>,
+    ) -> Result<usize, &'static str> {
+        let (geometry_type, dim) = split_keyword(word).ok_or("Invalid type encountered")?;
+        let dim = match dim {
+            Some(dim) => dim,
+            None => infer_geom_dimension(tokens)?,
+        };
+        let entry = self.begin_entry(geometry_type, dim);
+
+        if geometry_type == GeometryType::GeometryCollection {
+            let mut members = vec![];
+            if open_paren(tokens)? {
+                loop {
+                    let word = match tokens.next() {
+                        Some(Token::Word(w)) => w,
+                        _ => return Err("Expected a word in GEOMETRYCOLLECTION"),
+                    };
+                    members.push(self.parse_geometry(word, tokens)?);
+                    if !next_comma(tokens) {
+                        break;
+                    }
+                }
+                close_paren(tokens)?;
+            }
+            self.end_collection(entry, members);
+            return Ok(entry);
+        }
+
+        if open_paren(tokens)? {
+            match geometry_type {
+                GeometryType::Point => {
+                    self.parse_coord(tokens, dim)?;
+                    self.end_ring();
+                    self.end_part();
+                }
+                GeometryType::LineString | GeometryType::LinearRing => {
+                    self.parse_coords(tokens, dim)?;
+                    self.end_part();
+                }
+                GeometryType::Polygon => self.parse_rings(tokens, dim)?,
+                GeometryType::MultiPoint => loop {
+                    // The parentheses around each point are optional.
+                    match tokens.peek() {
+                        Some(Token::ParenOpen) => {
+                            if open_paren(tokens)? {
+                                self.parse_coord(tokens, dim)?;
+                                close_paren(tokens)?;
+                            }
+                        }
+                        Some(Token::Word(w)) if w.eq_ignore_ascii_case("EMPTY") => {
+                            tokens.next();
+                        }
+                        _ => self.parse_coord(tokens, dim)?,
+                    }
+                    self.end_ring();
+                    self.end_part();
+                    if !next_comma(tokens) {
+                        break;
+                    }
+                },
+                GeometryType::MultiLineString => loop {
+                    if open_paren(tokens)? {
+                        self.parse_coords(tokens, dim)?;
+                        close_paren(tokens)?;
+                    } else {
+                        self.end_ring();
+                    }
+                    self.end_part();
+                    if !next_comma(tokens) {
+                        break;
+                    }
+                },
+                GeometryType::MultiPolygon => loop {
+                    if open_paren(tokens)? {
+                        self.parse_rings(tokens, dim)?;
+                        close_paren(tokens)?;
+                    } else {
+                        self.end_part();
+                    }
+                    if !next_comma(tokens) {
+                        break;
+                    }
+                },
+                GeometryType::GeometryCollection => unreachable!("handled above"),
+                extended => unreachable!("{extended} is rejected by split_keyword"),
+            }
+            close_paren(tokens)?;
+        }
+        self.end_entry(entry);
+        Ok(entry)
+    }
+
+    fn parse_coord(&mut self, tokens: &mut Tokens<'_>, dim: Dimension) -> Result<(), &'static str> {
+        let x = "Expected a number for the X coordinate";
+        let y = "Expected a number for the Y coordinate";
+        let z = "Expected a number for the Z coordinate";
+        let m = "Expected a number for the M coordinate";
+        let errors: &[&'static str] = match dim {
+            Dimension::XY => &[x, y],
+            Dimension::XYZ => &[x, y, z],
+            Dimension::XYM => &[x, y, m],
+            Dimension::XYZM => &[x, y, z, m],
+        };
+        for &error in errors {
+            match tokens.next() {
+                Some(Token::Number(n)) => self.coords.push(parse_number(n)?),
+                _ => return Err(error),
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a comma separated list of coordinates into one ring.
+    fn parse_coords(
+        &mut self,
+        tokens: &mut Tokens<'_>,
+        dim: Dimension,
+    ) -> Result<(), &'static str> {
+        loop {
+            self.parse_coord(tokens, dim)?;
+            if !next_comma(tokens) {
+                break;
+            }
+        }
+        self.end_ring();
+        Ok(())
+    }
+
+    /// Parses a comma separated list of parenthesized rings into one part.
+    fn parse_rings(&mut self, tokens: &mut Tokens<'_>, dim: Dimension) -> Result<(), &'static str> {
+        loop {
+            if open_paren(tokens)? {
+                self.parse_coords(tokens, dim)?;
+                close_paren(tokens)?;
+            } else {
+                self.end_ring();
+            }
+            if !next_comma(tokens) {
+                break;
+            }
+        }
+        self.end_part();
+        Ok(())
+    }
+}
+
+/// Splits a keyword like `POINT` or `POINTZ`, rejecting the unsupported extended types.
+fn split_keyword(word: &str) -> Option<(GeometryType, Option<Dimension>)> {
+    GeometryType::from_keyword(word).filter(|(geometry_type, _)| !geometry_type.is_extended())
+}
+
+/// Consumes an opening parenthesis, returning `false` for `EMPTY` instead.
+fn open_paren(tokens: &mut Tokens<'_>) -> Result<bool, &'static str> {
+    match tokens.next() {
+        Some(Token::ParenOpen) => Ok(true),
+        Some(Token::Word(s)) if s.eq_ignore_ascii_case("EMPTY") => Ok(false),
+        _ => Err("Missing open parenthesis for type"),
+    }
+}
+
+fn close_paren(tokens: &mut Tokens<'_>) -> Result<(), &'static str> {
+    match tokens.next() {
+        Some(Token::ParenClose) => Ok(()),
+        _ => Err("Missing closing parenthesis for type"),
+    }
+}
+
+fn next_comma(tokens: &mut Tokens<'_>) -> bool {
+    if let Some(&Token::Comma) = tokens.peek() {
+        tokens.next();
+        true
+    } else {
+        false
+    }
+}
+
+/// A geometry stored in [`FlatGeometries`].
+#[derive(Clone, Debug)]
+pub struct FlatGeometry<'a, T: WktNum> {
+    inner: FlatGeometryInner<'a, T>,
+}
+
+#[derive(Clone, Debug)]
+enum FlatGeometryInner<'a, T: WktNum> {
+    Point(FlatPoint<'a, T>),
+    LineString(FlatLineString<'a, T>),
+    Polygon(FlatPolygon<'a, T>),
+    MultiPoint(FlatMultiPoint<'a, T>),
+    MultiLineString(FlatMultiLineString<'a, T>),
+    MultiPolygon(FlatMultiPolygon<'a, T>),
+    GeometryCollection(FlatGeometryCollection<'a, T>),
+}
+
+/// A coordinate stored in [`FlatGeometries`].
+#[derive(Clone, Copy, Debug)]
+pub struct FlatCoord<'a, T: WktNum> {
+    values: &'a [T],
+    dim: Dimension,
+}
+
+/// A point stored in [`FlatGeometries`].
+#[derive(Clone, Copy, Debug)]
+pub struct FlatPoint<'a, T: WktNum> {
+    coord: Option<&'a [T]>,
+    dim: Dimension,
+}
+
+/// A line string or ring stored in [`FlatGeometries`].
+#[derive(Clone, Copy, Debug)]
+pub struct FlatLineString<'a, T: WktNum> {
+    values: &'a [T],
+    dim: Dimension,
+}
+
+/// A polygon stored in [`FlatGeometries`].
+#[derive(Clone, Debug)]
+pub struct FlatPolygon<'a, T: WktNum> {
+    geometries: &'a FlatGeometries<T>,
+    rings: Range<usize>,
+    dim: Dimension,
+}
+
+/// A multi point stored in [`FlatGeometries`].
+#[derive(Clone, Debug)]
+pub struct FlatMultiPoint<'a, T: WktNum> {
+    geometries: &'a FlatGeometries<T>,
+    parts: Range<usize>,
+    dim: Dimension,
+}
+
+/// A multi line string stored in [`FlatGeometries`].
+#[derive(Clone, Debug)]
+pub struct FlatMultiLineString<'a, T: WktNum> {
+    geometries: &'a FlatGeometries<T>,
+    parts: Range<usize>,
+    dim: Dimension,
+}
+
+/// A multi polygon stored in [`FlatGeometries`].
+#[derive(Clone, Debug)]
+pub struct FlatMultiPolygon<'a, T: WktNum> {
+    geometries: &'a FlatGeometries<T>,
+    parts: Range<usize>,
+    dim: Dimension,
+}
+
+/// A geometry collection stored in [`FlatGeometries`].
+#[derive(Clone, Debug)]
+pub struct FlatGeometryCollection<'a, T: WktNum> {
+    geometries: &'a FlatGeometries<T>,
+    members: &'a [usize],
+    dim: Dimension,
+}
+
+impl<T> fmt::Display for FlatGeometry<'_, T>
+where
+    T: WktNum + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        Ok(write_geometry(f, self)?)
+    }
+}
+
+impl<T: WktNum> CoordTrait for FlatCoord<'_, T> {
+    type T = T;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dim.into()
+    }
+
+    fn x(&self) -> T {
+        self.values[0]
+    }
+
+    fn y(&self) -> T {
+        self.values[1]
+    }
+
+    fn nth_or_panic(&self, n: usize) -> T {
+        self.values[n]
+    }
+}
+
+impl<T: WktNum> PointTrait for FlatPoint<'_, T> {
+    type T = T;
+    type CoordType<'b>
+        = FlatCoord<'b, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dim.into()
+    }
+
+    fn coord(&self) -> Option<Self::CoordType<'_>> {
+        self.coord.map(|values| FlatCoord {
+            values,
+            dim: self.dim,
+        })
+    }
+}
+
+impl<T: WktNum> LineStringTrait for FlatLineString<'_, T> {
+    type T = T;
+    type CoordType<'b>
+        = FlatCoord<'b, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dim.into()
+    }
+
+    fn num_coords(&self) -> usize {
+        self.values.len() / self.dim.size()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        let size = self.dim.size();
+        FlatCoord {
+            values: &self.values[i * size..(i + 1) * size],
+            dim: self.dim,
+        }
+    }
+}
+
+impl<'a, T: WktNum> FlatPolygon<'a, T> {
+    fn ring(&self, ring: usize) -> FlatLineString<'a, T> {
+        FlatLineString {
+            values: self.geometries.ring(ring),
+            dim: self.dim,
+        }
+    }
+}
+
+impl<T: WktNum> PolygonTrait for FlatPolygon<'_, T> {
+    type T = T;
+    type RingType<'b>
+        = FlatLineString<'b, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dim.into()
+    }
+
+    fn exterior(&self) -> Option<Self::RingType<'_>> {
+        self.rings.clone().next().map(|ring| self.ring(ring))
+    }
+
+    fn num_interiors(&self) -> usize {
+        self.rings.len().saturating_sub(1)
+    }
+
+    unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
+        self.ring(self.rings.start + 1 + i)
+    }
+}
+
+impl<T: WktNum> MultiPointTrait for FlatMultiPoint<'_, T> {
+    type T = T;
+    type PointType<'b>
+        = FlatPoint<'b, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dim.into()
+    }
+
+    fn num_points(&self) -> usize {
+        self.parts.len()
+    }
+
+    unsafe fn point_unchecked(&self, i: usize) -> Self::PointType<'_> {
+        let ring = self.geometries.rings(self.parts.start + i).start;
+        FlatPoint {
+            coord: self.geometries.coord_values(ring, self.dim).next(),
+            dim: self.dim,
+        }
+    }
+}
+
+impl<T: WktNum> MultiLineStringTrait for FlatMultiLineString<'_, T> {
+    type T = T;
+    type LineStringType<'b>
+        = FlatLineString<'b, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dim.into()
+    }
+
+    fn num_line_strings(&self) -> usize {
+        self.parts.len()
+    }
+
+    unsafe fn line_string_unchecked(&self, i: usize) -> Self::LineStringType<'_> {
+        let ring = self.geometries.rings(self.parts.start + i).start;
+        FlatLineString {
+            values: self.geometries.ring(ring),
+            dim: self.dim,
+        }
+    }
+}
+
+impl<T: WktNum> MultiPolygonTrait for FlatMultiPolygon<'_, T> {
+    type T = T;
+    type PolygonType<'b>
+        = FlatPolygon<'b, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dim.into()
+    }
+
+    fn num_polygons(&self) -> usize {
+        self.parts.len()
+    }
+
+    unsafe fn polygon_unchecked(&self, i: usize) -> Self::PolygonType<'_> {
+        FlatPolygon {
+            geometries: self.geometries,
+            rings: self.geometries.rings(self.parts.start + i),
+            dim: self.dim,
+        }
+    }
+}
+
+impl<T: WktNum> GeometryCollectionTrait for FlatGeometryCollection<'_, T> {
+    type T = T;
+    type GeometryType<'b>
+        = FlatGeometry<'b, T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dim.into()
+    }
+
+    fn num_geometries(&self) -> usize {
+        self.members.len()
+    }
+
+    unsafe fn geometry_unchecked(&self, i: usize) -> Self::GeometryType<'_> {
+        self.geometries.geometry(self.members[i])
+    }
+}
+
+impl<'a, T: WktNum> GeometryTrait for FlatGeometry<'a, T> {
+    type T = T;
+    type PointType<'b>
+        = FlatPoint<'a, T>
+    where
+        Self: 'b;
+    type LineStringType<'b>
+        = FlatLineString<'a, T>
+    where
+        Self: 'b;
+    type PolygonType<'b>
+        = FlatPolygon<'a, T>
+    where
+        Self: 'b;
+    type MultiPointType<'b>
+        = FlatMultiPoint<'a, T>
+    where
+        Self: 'b;
+    type MultiLineStringType<'b>
+        = FlatMultiLineString<'a, T>
+    where
+        Self: 'b;
+    type MultiPolygonType<'b>
+        = FlatMultiPolygon<'a, T>
+    where
+        Self: 'b;
+    type GeometryCollectionType<'b>
+        = FlatGeometryCollection<'a, T>
+    where
+        Self: 'b;
+    type RectType<'b>
+        = geo_traits::UnimplementedRect<T>
+    where
+        Self: 'b;
+    type LineType<'b>
+        = geo_traits::UnimplementedLine<T>
+    where
+        Self: 'b;
+    type TriangleType<'b>
+        = geo_traits::UnimplementedTriangle<T>
+    where
+        Self: 'b;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        match &self.inner {
+            FlatGeometryInner::Point(g) => g.dim(),
+            FlatGeometryInner::LineString(g) => g.dim(),
+            FlatGeometryInner::Polygon(g) => g.dim(),
+            FlatGeometryInner::MultiPoint(g) => g.dim(),
+            FlatGeometryInner::MultiLineString(g) => g.dim(),
+            FlatGeometryInner::MultiPolygon(g) => g.dim(),
+            FlatGeometryInner::GeometryCollection(g) => g.dim(),
+        }
+    }
+
+    fn as_type(
+        &self,
+    ) -> geo_traits::GeometryType<
+        '_,
+        FlatPoint<'a, T>,
+        FlatLineString<'a, T>,
+        FlatPolygon<'a, T>,
+        FlatMultiPoint<'a, T>,
+        FlatMultiLineString<'a, T>,
+        FlatMultiPolygon<'a, T>,
+        FlatGeometryCollection<'a, T>,
+        Self::RectType<'_>,
+        Self::TriangleType<'_>,
+        Self::LineType<'_>,
+    > {
+        match &self.inner {
+            FlatGeometryInner::Point(g) => geo_traits::GeometryType::Point(g),
+            FlatGeometryInner::LineString(g) => geo_traits::GeometryType::LineString(g),
+            FlatGeometryInner::Polygon(g) => geo_traits::GeometryType::Polygon(g),
+            FlatGeometryInner::MultiPoint(g) => geo_traits::GeometryType::MultiPoint(g),
+            FlatGeometryInner::MultiLineString(g) => geo_traits::GeometryType::MultiLineString(g),
+            FlatGeometryInner::MultiPolygon(g) => geo_traits::GeometryType::MultiPolygon(g),
+            FlatGeometryInner::GeometryCollection(g) => {
+                geo_traits::GeometryType::GeometryCollection(g)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Wkt;
+
+    const SAMPLES: &[&str] = &[
+        "POINT(1 2)",
+        "POINT EMPTY",
+        "POINTZ(1 2 3)",
+        "POINT M(1 2 4)",
+        "LINESTRING(0 0,1 1,2 2)",
+        "LINESTRING EMPTY",
+        "POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,2 2,1 1))",
+        "POLYGON EMPTY",
+        "MULTIPOINT((1 2),(3 4))",
+        "MULTIPOINT ZM(1 2 3 4,5 6 7 8)",
+        "MULTILINESTRING((0 0,1 1),(2 2,3 3,4 4))",
+        "MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((5 5,6 5,6 6,5 5),(5.1 5.1,5.2 5.1,5.2 5.2,5.1 5.1)))",
+        "MULTIPOLYGON EMPTY",
+        "GEOMETRYCOLLECTION(POINT(1 2),GEOMETRYCOLLECTION(LINESTRING(0 0,1 1)),POINT(3 4))",
+        "GEOMETRYCOLLECTION EMPTY",
+    ];
+
+    #[test]
+    fn push_str_matches_wkt() {
+        let mut geometries = FlatGeometries::<f64>::new();
+        for sample in SAMPLES {
+            geometries.push_str(sample).unwrap();
+        }
+        assert_eq!(geometries.len(), SAMPLES.len());
+        for (sample, geometry) in SAMPLES.iter().zip(geometries.iter()) {
+            let expected = Wkt::<f64>::from_str(sample).unwrap().to_string();
+            assert_eq!(geometry.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn push_geometry_matches_wkt() {
+        let mut geometries = FlatGeometries::<f64>::new();
+        for sample in SAMPLES {
+            let wkt = Wkt::<f64>::from_str(sample).unwrap();
+            geometries.push_geometry(&wkt).unwrap();
+            let pushed = geometries.get(geometries.len() - 1).unwrap();
+            assert_eq!(pushed.to_string(), wkt.to_string());
+        }
+    }
+
+    #[test]
+    fn coords_are_contiguous() {
+        let mut geometries = FlatGeometries::<i32>::new();
+        geometries.push_str("POINT(1 2)").unwrap();
+        geometries.push_str("LINESTRING Z(3 4 5,6 7 8)").unwrap();
+        assert_eq!(geometries.coords(), [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn errors_roll_back() {
+        let mut geometries = FlatGeometries::<f64>::new();
+        geometries.push_str("POINT(1 2)").unwrap();
+        assert_eq!(
+            geometries.push_str("LINESTRING(3 4,5)"),
+            Err("Expected a number for the Y coordinate")
+        );
+        assert_eq!(
+            geometries.push_str("GEOMETRYCOLLECTION(POINT(1 2),CIRCLE(1))"),
+            Err("Invalid type encountered")
+        );
+        assert_eq!(geometries.len(), 1);
+        assert_eq!(geometries.coords(), [1., 2.]);
+        geometries.push_str("POINT(3 4)").unwrap();
+        assert_eq!(geometries.get(1).unwrap().to_string(), "POINT(3 4)");
+    }
+
+    #[test]
+    fn missing_ordinate_rolls_back() {
+        use crate::types::{Coord, LineString};
+
+        let xyz = Coord {
+            x: 1.,
+            y: 2.,
+            z: Some(3.),
+            m: None,
+        };
+        let xy = Coord { z: None, ..xyz };
+        let mut geometries = FlatGeometries::<f64>::new();
+        geometries.push_str("POINT(1 2)").unwrap();
+        assert!(matches!(
+            geometries.push_geometry(&Wkt::LineString(LineString(vec![xyz, xy]))),
+            Err(Error::MissingOrdinate)
+        ));
+        assert_eq!(geometries.len(), 1);
+        assert_eq!(geometries.coords(), [1., 2.]);
+        geometries.push_str("POINT(3 4)").unwrap();
+        assert_eq!(geometries.get(1).unwrap().to_string(), "POINT(3 4)");
+    }
+}
@@ -0,0 +1,486 @@
+//! Random, syntactically valid WKT, for fuzzing parsers and writers.
+//!
+//! Random bytes rarely get past the keyword, so these strings are built from the grammar instead,
+//! with varied spelling: keyword case, joined or separate dimension tags, `EMPTY` members, number
+//! notation and whitespace. The same seed and [`Config`] always give the same strings.
+//!
+//! ```
+//! use std::str::FromStr;
+//! use wkt::generate::{Config, Generator};
+//! use wkt::Wkt;
+//!
+//! for input in Generator::new(7, Config::default()).take(100) {
+//!     assert!(Wkt::<f64>::from_str(&input).is_ok(), "{input}");
+//! }
+//! ```
+//!
+//! The geometries are only well formed as text: rings are closed, but may cross themselves.
+//! [`RandomGeometry`] makes typed geometries with realistic shapes instead.
+
+use std::f64::consts::TAU;
+
+use crate::types::{Coord, Dimension, LineString, MultiPolygon, Point, Polygon};
+
+/// What [`Generator`] produces.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// How deeply geometry collections may nest. Zero means no geometry collections.
+    pub max_depth: usize,
+    /// The most members of a collection, rings of a polygon, or coordinates of a line string.
+    pub max_items: usize,
+    /// The dimension of every geometry, or `None` for a random one for each string.
+    pub dimension: Option<Dimension>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_depth: 2,
+            max_items: 4,
+            dimension: None,
+        }
+    }
+}
+
+/// An endless iterator of random WKT strings.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    rng: Rng,
+    config: Config,
+    out: String,
+}
+
+impl Generator {
+    pub fn new(seed: u64, config: Config) -> Self {
+        Self {
+            rng: Rng::new(seed),
+            config,
+            out: String::new(),
+        }
+    }
+
+    /// Generates the next string.
+    pub fn wkt(&mut self) -> String {
+        let dim = match self.config.dimension {
+            Some(dim) => dim,
+            None => self.rng.pick(&[
+                Dimension::XY,
+                Dimension::XYZ,
+                Dimension::XYM,
+                Dimension::XYZM,
+            ]),
+        };
+        self.space(false);
+        self.geometry(dim, self.config.max_depth);
+        self.space(false);
+        std::mem::take(&mut self.out)
+    }
+
+    fn geometry(&mut self, dim: Dimension, depth: usize) {
+        let kinds = if depth == 0 { 6 } else { 7 };
+        let keyword = [
+            "POINT",
+            "LINESTRING",
+            "POLYGON",
+            "MULTIPOINT",
+            "MULTILINESTRING",
+            "MULTIPOLYGON",
+            "GEOMETRYCOLLECTION",
+        ][self.rng.below(kinds)];
+        self.keyword(keyword, dim);
+        if self.rng.below(8) == 0 {
+            self.empty();
+            return;
+        }
+        match keyword {
+            "POINT" => self.list(1, |g| g.coord(dim)),
+            "LINESTRING" => self.line_string(dim),
+            "POLYGON" => self.polygon(dim),
+            "MULTIPOINT" => {
+                let len = self.items(1);
+                self.list(len, |g| match g.rng.below(8) {
+                    0 => g.empty(),
+                    // Points in a multi point may be written without parentheses.
+                    1..=3 => g.coord(dim),
+                    _ => g.list(1, |g| g.coord(dim)),
+                })
+            }
+            "MULTILINESTRING" => {
+                let len = self.items(1);
+                self.list(len, |g| g.member(|g| g.line_string(dim)))
+            }
+            "MULTIPOLYGON" => {
+                let len = self.items(1);
+                self.list(len, |g| g.member(|g| g.polygon(dim)))
+            }
+            _ => {
+                let len = self.items(1);
+                self.list(len, |g| g.geometry(dim, depth - 1))
+            }
+        }
+    }
+
+    /// Writes a member of a multi geometry, which may be `EMPTY`.
+    fn member(&mut self, write: impl FnOnce(&mut Self)) {
+        if self.rng.below(8) == 0 {
+            self.out.push_str(self.rng.pick(&["EMPTY", "empty"]));
+        } else {
+            write(self)
+        }
+    }
+
+    fn line_string(&mut self, dim: Dimension) {
+        let len = self.items(2);
+        self.list(len, |g| g.coord(dim))
+    }
+
+    fn polygon(&mut self, dim: Dimension) {
+        let rings = self.items(1);
+        self.list(rings, |g| {
+            // At least three coordinates, and the first again to close the ring.
+            let start = g.out.len();
+            g.coord(dim);
+            let first = g.out.split_off(start);
+            let len = g.items(3) + 1;
+            let mut i = 0;
+            g.list(len, |g| {
+                if i == 0 || i == len - 1 {
+                    g.out.push_str(&first);
+                } else {
+                    g.coord(dim);
+                }
+                i += 1;
+            })
+        })
+    }
+
+    /// A number of items from `min` to `max_items`, or `min` if `max_items` is smaller.
+    fn items(&mut self, min: usize) -> usize {
+        min + self
+            .rng
+            .below(self.config.max_items.saturating_sub(min) + 1)
+    }
+
+    /// Writes `(`, then `len` items separated by commas, and `)`.
+    fn list(&mut self, len: usize, mut item: impl FnMut(&mut Self)) {
+        self.out.push('(');
+        for i in 0..len {
+            if i > 0 {
+                self.space(false);
+                self.out.push(',');
+            }
+            self.space(false);
+            item(self);
+        }
+        self.space(false);
+        self.out.push(')');
+    }
+
+    fn keyword(&mut self, keyword: &str, dim: Dimension) {
+        self.word(keyword);
+        if dim != Dimension::XY {
+            // The tag may be joined to the keyword, as in `POINTZ`.
+            let joined = self.rng.below(4) == 0;
+            self.space(!joined);
+            self.word(dim.suffix());
+        }
+        self.space(false);
+    }
+
+    fn empty(&mut self) {
+        self.space(true);
+        self.word("EMPTY");
+    }
+
+    /// Writes `word` in upper, lower or mixed case.
+    fn word(&mut self, word: &str) {
+        match self.rng.below(4) {
+            0 => self.out.push_str(&word.to_ascii_lowercase()),
+            1 => {
+                for c in word.chars() {
+                    let lower = self.rng.below(2) == 0;
+                    self.out
+                        .push(if lower { c.to_ascii_lowercase() } else { c });
+                }
+            }
+            _ => self.out.push_str(word),
+        }
+    }
+
+    fn coord(&mut self, dim: Dimension) {
+        for i in 0..dim.size() {
+            if i > 0 {
+                self.space(true);
+            }
+            self.number();
+        }
+    }
+
+    /// Writes a number as an integer, a decimal or in scientific notation.
+    fn number(&mut self) {
+        let sign = self.rng.pick(&["", "", "-", "+"]);
+        let whole = self.rng.below(100_000);
+        let fraction = self.rng.below(1000);
+        let number = match self.rng.below(6) {
+            0 | 1 => format!("{sign}{whole}"),
+            2 | 3 => format!("{sign}{whole}.{fraction:03}"),
+            4 => format!("{sign}.{fraction:03}"),
+            _ => {
+                let e = self.rng.pick(&["e", "E"]);
+                let exponent = self.rng.below(40) as i64 - 20;
+                format!("{sign}{whole}.{fraction}{e}{exponent}")
+            }
+        };
+        self.out.push_str(&number);
+    }
+
+    /// Writes whitespace, which may be nothing unless it's `required`.
+    fn space(&mut self, required: bool) {
+        let space = match self.rng.below(8) {
+            0..=3 if !required => "",
+            0..=4 => " ",
+            5 => "  ",
+            6 => "\n",
+            _ => "\t",
+        };
+        self.out.push_str(space);
+    }
+}
+
+impl Iterator for Generator {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        Some(self.wkt())
+    }
+}
+
+/// Random but reproducible geometries, for synthetic datasets and benchmarks. The same seed and
+/// sizes always give the same geometry.
+///
+/// Coordinates are scattered over longitudes and latitudes, with all the digits of an `f64`.
+/// Polygons are valid: their rings are star shaped, so they don't cross themselves, the exterior
+/// is counterclockwise and the holes, which don't touch, are clockwise.
+///
+/// ```
+/// use wkt::generate::RandomGeometry;
+///
+/// let polygon = RandomGeometry::polygon(7, 3, 100);
+/// assert_eq!(polygon.0.len(), 3);
+/// assert_eq!(polygon.0[0].0.len(), 101);
+/// assert_eq!(polygon, RandomGeometry::polygon(7, 3, 100));
+/// ```
+pub struct RandomGeometry;
+
+impl RandomGeometry {
+    pub fn point(seed: u64) -> Point<f64> {
+        let mut rng = Rng::new(seed);
+        Point::new(Coord::xy(rng.between(-180., 180.), rng.between(-90., 90.)))
+    }
+
+    /// A line string of `n_coords` coordinates, wandering from a random start.
+    pub fn line_string(seed: u64, n_coords: usize) -> LineString<f64> {
+        let mut rng = Rng::new(seed);
+        let (mut x, mut y) = (rng.between(-180., 180.), rng.between(-90., 90.));
+        let coords = (0..n_coords)
+            .map(|_| {
+                x += rng.between(-0.01, 0.01);
+                y += rng.between(-0.01, 0.01);
+                Coord::xy(x, y)
+            })
+            .collect();
+        LineString(coords)
+    }
+
+    /// A polygon with an exterior and `n_rings - 1` holes, each ring of `n_coords` coordinates
+    /// and the first again to close it. `n_rings` of zero gives an empty polygon, and `n_coords`
+    /// should be at least three.
+    pub fn polygon(seed: u64, n_rings: usize, n_coords: usize) -> Polygon<f64> {
+        let mut rng = Rng::new(seed);
+        let center = (rng.between(-179., 179.), rng.between(-89., 89.));
+        Self::polygon_around(&mut rng, center, 1., n_rings, n_coords)
+    }
+
+    /// `n_polygons` polygons like [`polygon`](Self::polygon)'s, which don't overlap.
+    pub fn multi_polygon(
+        seed: u64,
+        n_polygons: usize,
+        n_rings: usize,
+        n_coords: usize,
+    ) -> MultiPolygon<f64> {
+        let mut rng = Rng::new(seed);
+        let (x, y) = (rng.between(-170., 170.), rng.between(-80., 80.));
+        // Side by side, a little more than their width apart.
+        let polygons = (0..n_polygons)
+            .map(|i| {
+                let center = (x + 0.0025 * i as f64, y);
+                Self::polygon_around(&mut rng, center, 0.001, n_rings, n_coords)
+            })
+            .collect();
+        MultiPolygon(polygons)
+    }
+
+    fn polygon_around(
+        rng: &mut Rng,
+        (x, y): (f64, f64),
+        radius: f64,
+        n_rings: usize,
+        n_coords: usize,
+    ) -> Polygon<f64> {
+        if n_rings == 0 {
+            return Polygon::empty();
+        }
+        let mut rings = vec![Self::ring(rng, (x, y), radius, n_coords, false)];
+        // The exterior reaches at least 0.8 of the radius from the center. Holes are on a circle
+        // of half the radius, each small enough to keep clear of the others and the exterior.
+        let holes = n_rings - 1;
+        let hole_radius =
+            radius * (0.45 * (std::f64::consts::PI / holes.max(2) as f64).sin()).min(0.25);
+        for i in 0..holes {
+            let angle = TAU * i as f64 / holes as f64;
+            let center = (
+                x + 0.5 * radius * angle.cos(),
+                y + 0.5 * radius * angle.sin(),
+            );
+            rings.push(Self::ring(rng, center, hole_radius, n_coords, true));
+        }
+        Polygon(rings)
+    }
+
+    /// A closed, star shaped ring, with each coordinate between 0.8 and 1 times `radius` from the
+    /// center.
+    fn ring(
+        rng: &mut Rng,
+        (x, y): (f64, f64),
+        radius: f64,
+        n_coords: usize,
+        clockwise: bool,
+    ) -> LineString<f64> {
+        let step = if clockwise { -TAU } else { TAU } / n_coords as f64;
+        let mut coords: Vec<_> = (0..n_coords)
+            .map(|i| {
+                let angle = step * i as f64;
+                let distance = radius * rng.between(0.8, 1.);
+                Coord::xy(x + distance * angle.cos(), y + distance * angle.sin())
+            })
+            .collect();
+        if let Some(first) = coords.first().cloned() {
+            coords.push(first);
+        }
+        LineString(coords)
+    }
+}
+
+/// A small, fast pseudorandom number generator (SplitMix64). It's not suitable for anything that
+/// needs to be unpredictable.
+#[derive(Clone, Debug)]
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from zero up to, but not including, `n`, which must not be zero.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number from `min` up to, but not including, `max`.
+    pub(crate) fn between(&mut self, min: f64, max: f64) -> f64 {
+        // The top 53 bits, as a fraction of one.
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        min + unit * (max - min)
+    }
+
+    pub(crate) fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Wkt;
+    use std::str::FromStr;
+
+    #[test]
+    fn generates_valid_wkt() {
+        let config = Config {
+            max_depth: 3,
+            ..Config::default()
+        };
+        for input in Generator::new(0, config).take(2000) {
+            let wkt = Wkt::<f64>::from_str(&input).unwrap_or_else(|err| panic!("{input:?}: {err}"));
+            // The parsed geometry must also survive being written.
+            assert_eq!(
+                Wkt::<f64>::from_str(&wkt.to_string()).unwrap(),
+                wkt,
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn is_deterministic() {
+        let first: Vec<_> = Generator::new(42, Config::default()).take(20).collect();
+        let second: Vec<_> = Generator::new(42, Config::default()).take(20).collect();
+        assert_eq!(first, second);
+        let other: Vec<_> = Generator::new(43, Config::default()).take(20).collect();
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn uses_the_configured_dimension() {
+        let config = Config {
+            max_depth: 0,
+            max_items: 2,
+            dimension: Some(Dimension::XYM),
+        };
+        for input in Generator::new(1, config).take(200) {
+            let written = Wkt::<f64>::from_str(&input).unwrap().to_string();
+            assert!(!written.starts_with("GEOMETRYCOLLECTION"), "{input:?}");
+            // Geometries without coordinates are written without their tag.
+            let has_coords = written.contains(|c: char| c.is_ascii_digit());
+            assert!(written.contains(" M(") || !has_coords, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn random_geometries_are_reproducible_and_well_shaped() {
+        // Twice the signed area, which is positive for counterclockwise rings.
+        fn area(ring: &LineString<f64>) -> f64 {
+            let coords = &ring.0;
+            coords
+                .iter()
+                .zip(&coords[1..])
+                .map(|(a, b)| a.x * b.y - b.x * a.y)
+                .sum()
+        }
+
+        let polygon = RandomGeometry::polygon(3, 4, 50);
+        assert_eq!(polygon, RandomGeometry::polygon(3, 4, 50));
+        assert_ne!(polygon, RandomGeometry::polygon(4, 4, 50));
+        assert_eq!(polygon.0.len(), 4);
+        for (i, ring) in polygon.0.iter().enumerate() {
+            assert_eq!(ring.0.len(), 51);
+            assert_eq!(ring.0.first(), ring.0.last());
+            assert_eq!(area(ring) > 0., i == 0);
+        }
+        assert_eq!(RandomGeometry::polygon(3, 0, 50), Polygon::empty());
+
+        let multi_polygon = RandomGeometry::multi_polygon(5, 3, 2, 10);
+        assert_eq!(multi_polygon.0.len(), 3);
+        assert_eq!(RandomGeometry::line_string(1, 10).0.len(), 10);
+        let point = RandomGeometry::point(9).0.unwrap();
+        assert!((-180. ..180.).contains(&point.x) && (-90. ..90.).contains(&point.y));
+    }
+}
@@ -24,26 +24,41 @@ use std::io::Read;
 use std::str::FromStr;
 
 use geo_types::{coord, CoordNum};
-use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Debug)]
 /// WKT to [`geo_types`] conversions errors
 pub enum Error {
-    #[error("The WKT Point was empty, but geo_type::Points cannot be empty")]
     PointConversionError,
-    #[error("Mismatched geometry (expected {expected:?}, found {found:?})")]
     MismatchedGeometry {
         expected: &'static str,
         found: &'static str,
     },
-    #[error("Wrong number of Geometries: {0}")]
     WrongNumberOfGeometries(usize),
-    #[error("Invalid WKT: {0}")]
     InvalidWKT(&'static str),
-    #[error("External error: {0}")]
     External(Box<dyn std::error::Error>),
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::PointConversionError => {
+                f.write_str("The WKT Point was empty, but geo_type::Points cannot be empty")
+            }
+            Error::MismatchedGeometry { expected, found } => write!(
+                f,
+                "Mismatched geometry (expected {expected:?}, found {found:?})"
+            ),
+            Error::WrongNumberOfGeometries(count) => {
+                write!(f, "Wrong number of Geometries: {count}")
+            }
+            Error::InvalidWKT(message) => write!(f, "Invalid WKT: {message}"),
+            Error::External(err) => write!(f, "External error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
 macro_rules! try_from_wkt_impl {
     ($($type: ident),+) => {
         $(
@@ -101,7 +116,7 @@ impl<T: CoordNum> TryFrom<Wkt<T>> for geo_types::GeometryCollection<T> {
                 expected: type_name::<Self>(),
                 found: type_name::<geo_types::Point<T>>(),
             }),
-            Wkt::LineString(_) => Err(Error::MismatchedGeometry {
+            Wkt::LineString(_) | Wkt::LinearRing(_) => Err(Error::MismatchedGeometry {
                 expected: type_name::<Self>(),
                 found: type_name::<geo_types::LineString<T>>(),
             }),
@@ -330,6 +345,8 @@ where
                 }
             }
             Wkt::LineString(g) => geo_types::Geometry::LineString(g.into()),
+            // geo-types has no separate type for rings
+            Wkt::LinearRing(g) => geo_types::Geometry::LineString(g.0.into()),
             Wkt::Polygon(g) => geo_types::Geometry::Polygon(g.into()),
             Wkt::MultiLineString(g) => geo_types::Geometry::MultiLineString(g.into()),
             Wkt::MultiPoint(g) => geo_types::Geometry::MultiPoint(g.try_into()?),
@@ -0,0 +1,299 @@
+//! Opt-in [`Eq`] and [`Hash`] for geometries with floating point coordinates.
+//!
+//! All of the [`types`](crate::types) derive `Eq` and `Hash` when their coordinate type does,
+//! which covers integer geometries. Floats are neither `Eq` nor `Hash`, so wrap float geometries
+//! in [`Hashable`] to key a `HashMap` or deduplicate them in a `HashSet`.
+
+use std::hash::{Hash, Hasher};
+
+use crate::types::{
+    Coord, GeometryCollection, LineString, LinearRing, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use crate::{Wkt, WktFloat};
+
+/// A wrapper providing [`Eq`] and [`Hash`] for geometries with floating point coordinates.
+///
+/// Coordinates are compared by value, with two exceptions that make equality total:
+///
+/// - all `NaN` values are equal to each other
+/// - `0.0` and `-0.0` are equal (as they are under `PartialEq`)
+///
+/// Hashing is consistent with this equality.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use std::str::FromStr;
+/// use wkt::{Hashable, Wkt};
+///
+/// let mut seen = HashSet::new();
+/// for wkt_str in ["POINT(1 2)", "POINT(1.0 2.0)", "POINT(3 4)"] {
+///     seen.insert(Hashable(Wkt::<f64>::from_str(wkt_str).unwrap()));
+/// }
+/// assert_eq!(seen.len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Hashable<G>(pub G);
+
+impl<G> Hashable<G> {
+    /// Unwrap the inner geometry.
+    pub fn into_inner(self) -> G {
+        self.0
+    }
+}
+
+impl<G> From<G> for Hashable<G> {
+    fn from(value: G) -> Self {
+        Hashable(value)
+    }
+}
+
+impl<G: TotalEq> PartialEq for Hashable<G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_eq(&other.0)
+    }
+}
+
+impl<G: TotalEq> Eq for Hashable<G> {}
+
+impl<G: TotalEq> Hash for Hashable<G> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.total_hash(state)
+    }
+}
+
+/// Equality and hashing with `NaN` treated as a regular value.
+///
+/// This is sealed: it is only implemented for this crate's geometry types.
+pub trait TotalEq: private::Sealed {
+    #[doc(hidden)]
+    fn total_eq(&self, other: &Self) -> bool;
+    #[doc(hidden)]
+    fn total_hash<H: Hasher>(&self, state: &mut H);
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+fn float_eq<T: WktFloat>(a: T, b: T) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+fn float_hash<T: WktFloat, H: Hasher>(value: T, state: &mut H) {
+    if value.is_nan() {
+        // All NaNs compare equal, so they must all hash the same regardless of payload.
+        T::nan().integer_decode().hash(state);
+    } else if value.is_zero() {
+        // Same for 0.0 and -0.0.
+        T::zero().integer_decode().hash(state);
+    } else {
+        value.integer_decode().hash(state);
+    }
+}
+
+fn option_float_eq<T: WktFloat>(a: Option<T>, b: Option<T>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => float_eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn option_float_hash<T: WktFloat, H: Hasher>(value: Option<T>, state: &mut H) {
+    match value {
+        Some(value) => {
+            state.write_u8(1);
+            float_hash(value, state);
+        }
+        None => state.write_u8(0),
+    }
+}
+
+fn slice_eq<G: TotalEq>(a: &[G], b: &[G]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.total_eq(b))
+}
+
+fn slice_hash<G: TotalEq, H: Hasher>(items: &[G], state: &mut H) {
+    state.write_usize(items.len());
+    for item in items {
+        item.total_hash(state);
+    }
+}
+
+impl<T: WktFloat> private::Sealed for Coord<T> {}
+
+impl<T: WktFloat> TotalEq for Coord<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        float_eq(self.x, other.x)
+            && float_eq(self.y, other.y)
+            && option_float_eq(self.z, other.z)
+            && option_float_eq(self.m, other.m)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        float_hash(self.x, state);
+        float_hash(self.y, state);
+        option_float_hash(self.z, state);
+        option_float_hash(self.m, state);
+    }
+}
+
+impl<T: WktFloat> private::Sealed for Point<T> {}
+
+impl<T: WktFloat> TotalEq for Point<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => a.total_eq(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        match &self.0 {
+            Some(coord) => {
+                state.write_u8(1);
+                coord.total_hash(state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+
+macro_rules! impl_total_eq_for_vec_wrapper {
+    ($($type: ident),+) => {
+        $(
+            impl<T: WktFloat> private::Sealed for $type<T> {}
+
+            impl<T: WktFloat> TotalEq for $type<T> {
+                fn total_eq(&self, other: &Self) -> bool {
+                    slice_eq(&self.0, &other.0)
+                }
+
+                fn total_hash<H: Hasher>(&self, state: &mut H) {
+                    slice_hash(&self.0, state)
+                }
+            }
+        )+
+    };
+}
+
+impl_total_eq_for_vec_wrapper!(
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+impl<T: WktFloat> private::Sealed for LinearRing<T> {}
+
+impl<T: WktFloat> TotalEq for LinearRing<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        self.0.total_eq(&other.0)
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.total_hash(state)
+    }
+}
+
+impl<T: WktFloat> private::Sealed for Wkt<T> {}
+
+impl<T: WktFloat> TotalEq for Wkt<T> {
+    fn total_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Wkt::Point(a), Wkt::Point(b)) => a.total_eq(b),
+            (Wkt::LineString(a), Wkt::LineString(b)) => a.total_eq(b),
+            (Wkt::LinearRing(a), Wkt::LinearRing(b)) => a.total_eq(b),
+            (Wkt::Polygon(a), Wkt::Polygon(b)) => a.total_eq(b),
+            (Wkt::MultiPoint(a), Wkt::MultiPoint(b)) => a.total_eq(b),
+            (Wkt::MultiLineString(a), Wkt::MultiLineString(b)) => a.total_eq(b),
+            (Wkt::MultiPolygon(a), Wkt::MultiPolygon(b)) => a.total_eq(b),
+            (Wkt::GeometryCollection(a), Wkt::GeometryCollection(b)) => a.total_eq(b),
+            _ => false,
+        }
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Wkt::Point(g) => g.total_hash(state),
+            Wkt::LineString(g) => g.total_hash(state),
+            Wkt::LinearRing(g) => g.total_hash(state),
+            Wkt::Polygon(g) => g.total_hash(state),
+            Wkt::MultiPoint(g) => g.total_hash(state),
+            Wkt::MultiLineString(g) => g.total_hash(state),
+            Wkt::MultiPolygon(g) => g.total_hash(state),
+            Wkt::GeometryCollection(g) => g.total_hash(state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    fn hash_of<G: TotalEq>(g: &Hashable<G>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        g.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn parse(wkt_str: &str) -> Hashable<Wkt<f64>> {
+        Hashable(Wkt::from_str(wkt_str).unwrap())
+    }
+
+    #[test]
+    fn integer_geometries_are_hashable() {
+        let mut set = HashSet::new();
+        set.insert(Wkt::<i32>::from_str("POINT(1 2)").unwrap());
+        set.insert(Wkt::<i32>::from_str("POINT (1 2)").unwrap());
+        set.insert(Wkt::<i32>::from_str("LINESTRING(1 2,3 4)").unwrap());
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn dedup_float_geometries() {
+        let mut set = HashSet::new();
+        set.insert(parse("LINESTRING(1 2,3 4)"));
+        set.insert(parse("LINESTRING(1.0 2.0, 3.0 4.0)"));
+        set.insert(parse("LINESTRING Z(1 2 0,3 4 0)"));
+        set.insert(parse("MULTIPOINT(1 2,3 4)"));
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn nan_is_equal_to_nan() {
+        let nan_point = || {
+            Hashable(Wkt::Point(Point(Some(Coord {
+                x: f64::NAN,
+                y: 1.0,
+                z: None,
+                m: None,
+            }))))
+        };
+        let a = nan_point();
+        let b = nan_point();
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn signed_zeros_are_equal() {
+        let a = parse("POINT(0 1)");
+        let b = parse("POINT(-0 1)");
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn z_and_m_are_distinguished() {
+        assert_ne!(parse("POINT Z(1 2 3)"), parse("POINT M(1 2 3)"));
+    }
+}
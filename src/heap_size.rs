@@ -0,0 +1,124 @@
+//! [`Wkt::estimated_heap_size`], for enforcing memory budgets on cached geometries.
+
+use std::mem::size_of;
+
+use crate::types::{
+    Coord, GeometryCollection, LineString, LinearRing, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use crate::{Wkt, WktNum};
+
+/// The bytes a geometry owns on the heap, not counting itself.
+trait HeapSize {
+    fn heap_size(&self) -> usize;
+}
+
+/// The capacity of `items`, and what each item owns.
+fn vec_heap_size<I: HeapSize>(items: &Vec<I>) -> usize {
+    items.capacity() * size_of::<I>() + items.iter().map(I::heap_size).sum::<usize>()
+}
+
+impl<T: WktNum> HeapSize for Coord<T> {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl<T: WktNum> HeapSize for Point<T> {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl<T: WktNum> HeapSize for LinearRing<T> {
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+}
+
+macro_rules! impl_heap_size_for_vec_wrapper {
+    ($($type: ident),+) => {
+        $(
+            impl<T: WktNum> HeapSize for $type<T> {
+                fn heap_size(&self) -> usize {
+                    vec_heap_size(&self.0)
+                }
+            }
+        )+
+    };
+}
+
+impl_heap_size_for_vec_wrapper!(
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+impl<T: WktNum> HeapSize for Wkt<T> {
+    fn heap_size(&self) -> usize {
+        match self {
+            Wkt::Point(g) => g.heap_size(),
+            Wkt::LineString(g) => g.heap_size(),
+            Wkt::LinearRing(g) => g.heap_size(),
+            Wkt::Polygon(g) => g.heap_size(),
+            Wkt::MultiPoint(g) => g.heap_size(),
+            Wkt::MultiLineString(g) => g.heap_size(),
+            Wkt::MultiPolygon(g) => g.heap_size(),
+            Wkt::GeometryCollection(g) => g.heap_size(),
+        }
+    }
+}
+
+impl<T: WktNum> Wkt<T> {
+    /// The bytes allocated on the heap by this geometry's vectors, from their capacities,
+    /// recursively. Add `size_of::<Wkt<T>>()` for the geometry itself.
+    ///
+    /// This doesn't include the allocator's own overhead, so it's an estimate of the memory used,
+    /// but it's exact for what the geometry has reserved.
+    ///
+    /// ```
+    /// use std::mem::size_of;
+    /// use wkt::types::{Coord, LineString};
+    /// use wkt::Wkt;
+    ///
+    /// let mut coords = Vec::with_capacity(8);
+    /// coords.push(Coord::xy(1.0_f64, 2.0));
+    /// let wkt = Wkt::from(LineString(coords));
+    /// assert_eq!(wkt.estimated_heap_size(), 8 * size_of::<Coord<f64>>());
+    /// ```
+    pub fn estimated_heap_size(&self) -> usize {
+        self.heap_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn sums_nested_capacities() {
+        assert_eq!(
+            Wkt::<f64>::from_str("POINT (1 2)")
+                .unwrap()
+                .estimated_heap_size(),
+            0
+        );
+
+        let mut polygon = Polygon(vec![LineString(vec![Coord::xy(0., 0.); 4])]);
+        polygon.0.reserve_exact(1);
+        let polygon_size =
+            polygon.0.capacity() * size_of::<LineString<f64>>() + 4 * size_of::<Coord<f64>>();
+        let collection = Wkt::GeometryCollection(GeometryCollection(vec![
+            Wkt::Polygon(polygon),
+            Wkt::Point(Point::empty()),
+        ]));
+        assert_eq!(
+            collection.estimated_heap_size(),
+            2 * size_of::<Wkt<f64>>() + polygon_size
+        );
+    }
+}
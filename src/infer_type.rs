@@ -1,89 +1,254 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+use crate::tokenizer::{Token, Tokens};
 use crate::types::{Dimension, GeometryType};
 
-const POINT: &str = "POINT";
-const LINESTRING: &str = "LINESTRING";
-const POLYGON: &str = "POLYGON";
-const MULTIPOINT: &str = "MULTIPOINT";
-const MULTILINESTRING: &str = "MULTILINESTRING";
-const MULTIPOLYGON: &str = "MULTIPOLYGON";
-const GEOMETRYCOLLECTION: &str = "GEOMETRYCOLLECTION";
+/// The geometry type, dimension and emptiness of a WKT string, as returned by [`infer_type`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct InferredType {
+    pub geometry_type: GeometryType,
+    pub dimension: Dimension,
+    /// Whether the geometry is `EMPTY`, like `POINT Z EMPTY`.
+    pub is_empty: bool,
+    /// The spatial reference ID of an EWKT string, like `SRID=4326;POINT (1 2)`. It's an `i32`,
+    /// like PostGIS's, so `SRID=-1` is accepted.
+    pub srid: Option<i32>,
+}
 
-/// Infer the geometry type and dimension from an input WKT string slice.
+/// Infer the geometry type, dimension and emptiness from an input WKT string slice.
 ///
-/// An `EMPTY` WKT object will return `None` in place of the dimension.
+/// Only the leading keyword, dimension tag and the `(` or `EMPTY` that follows are read, so this
+/// is much cheaper than parsing the whole geometry. Coordinates are not checked.
 ///
 /// ```
 /// use wkt::infer_type;
 /// use wkt::types::{Dimension, GeometryType};
 ///
-/// assert_eq!(
-///     infer_type("POINT (10 20.1)").unwrap(),
-///     (GeometryType::Point, Some(Dimension::XY))
-/// );
+/// let inferred = infer_type("POINT (10 20.1)").unwrap();
+/// assert_eq!(inferred.geometry_type, GeometryType::Point);
+/// assert_eq!(inferred.dimension, Dimension::XY);
+/// assert!(!inferred.is_empty);
+///
+/// let inferred = infer_type("POINT Z EMPTY").unwrap();
+/// assert_eq!(inferred.dimension, Dimension::XYZ);
+/// assert!(inferred.is_empty);
+/// ```
+///
+/// The extended SQL/MM types, like `CIRCULARSTRING`, are recognized as well, so they can be
+/// classified and skipped even though they can't be parsed into a [`Wkt`](crate::Wkt):
+///
+/// ```
+/// use wkt::infer_type;
+/// use wkt::types::GeometryType;
+///
+/// let inferred = infer_type("CIRCULARSTRING (0 0, 1 1, 2 0)").unwrap();
+/// assert_eq!(inferred.geometry_type, GeometryType::CircularString);
+/// assert!(inferred.geometry_type.is_extended());
+/// ```
+///
+/// An EWKT `SRID=...;` prefix, as written by PostGIS, is skipped and returned:
+///
+/// ```
+/// use wkt::infer_type;
+/// use wkt::types::GeometryType;
+///
+/// let inferred = infer_type("SRID=4326;POINT (1 2)").unwrap();
+/// assert_eq!(inferred.geometry_type, GeometryType::Point);
+/// assert_eq!(inferred.srid, Some(4326));
+/// ```
+pub fn infer_type(input: &str) -> Result<InferredType, String> {
+    let (srid, input) = split_srid(input.trim_start())?;
+    let mut tokens = Tokens::from_str(input);
+
+    let word = match tokens.next() {
+        Some(Token::Word(word)) => word,
+        _ => return Err("Invalid WKT; expected a geometry type".to_string()),
+    };
+    let (geometry_type, dimension) = GeometryType::from_keyword(word)
+        .ok_or_else(|| format!("Unsupported WKT prefix {}", word))?;
+
+    let mut next = tokens.next();
+    // A dimension tag may also be written as a separate word, like `POINT Z`.
+    let dimension = match (dimension, &next) {
+        (Some(dimension), _) => dimension,
+        (None, Some(Token::Word(tag))) if !tag.eq_ignore_ascii_case("EMPTY") => {
+            let dimension = Dimension::from_suffix(tag)
+                .ok_or_else(|| format!("Unexpected word {} after {}", tag, word))?;
+            next = tokens.next();
+            dimension
+        }
+        (None, _) => Dimension::XY,
+    };
+
+    let is_empty = match next {
+        Some(Token::ParenOpen) => false,
+        Some(Token::Word(w)) if w.eq_ignore_ascii_case("EMPTY") => true,
+        _ => return Err("Invalid WKT; no '(' character and not EMPTY".to_string()),
+    };
+
+    Ok(InferredType {
+        geometry_type,
+        dimension,
+        is_empty,
+        srid,
+    })
+}
+
+/// The longest header [`infer_type_from_reader`] reads before giving up, in bytes.
+const MAX_HEADER_LEN: usize = 256;
+
+/// Like [`infer_type`], but reads only the header of the WKT from `reader`, up to the first `(` or
+/// `EMPTY`, so large inputs can be classified without reading them in full.
+///
+/// The header bytes are consumed from the reader, and the rest is left unread. Errors from the
+/// reader are returned as is, and invalid headers as [`io::ErrorKind::InvalidData`].
+///
+/// ```
+/// use std::io::BufReader;
+/// use wkt::infer_type_from_reader;
+/// use wkt::types::{Dimension, GeometryType};
+///
+/// let file = "MULTIPOLYGON Z (((0 0 0, 1 0 0, 1 1 0, 0 0 0)))".as_bytes();
+/// let mut reader = BufReader::new(file);
+/// let inferred = infer_type_from_reader(&mut reader).unwrap();
+/// assert_eq!(inferred.geometry_type, GeometryType::MultiPolygon);
+/// assert_eq!(inferred.dimension, Dimension::XYZ);
+/// ```
+pub fn infer_type_from_reader(mut reader: impl BufRead) -> io::Result<InferredType> {
+    let mut header = Vec::new();
+    'read: loop {
+        let buf = reader.fill_buf()?;
+        if buf.is_empty() {
+            break;
+        }
+        let mut used = 0;
+        for &byte in buf {
+            header.push(byte);
+            used += 1;
+            if byte == b'(' || ends_with_empty(&header) {
+                reader.consume(used);
+                break 'read;
+            }
+            if header.len() == MAX_HEADER_LEN {
+                return Err(invalid_data("WKT header too long"));
+            }
+        }
+        reader.consume(used);
+    }
+    let header = std::str::from_utf8(&header).map_err(|_| invalid_data("Invalid UTF-8"))?;
+    infer_type(header).map_err(invalid_data)
+}
+
+fn ends_with_empty(header: &[u8]) -> bool {
+    header.len() >= 5 && header[header.len() - 5..].eq_ignore_ascii_case(b"EMPTY")
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// Counts of the geometry types, dimensions and SRIDs across many WKT strings, from
+/// [`infer_type`].
+///
+/// Collect an iterator of strings into it, or [`add`](TypeSummary::add) them one at a time.
+/// Strings that `infer_type` rejects are only counted in [`invalid`](TypeSummary::invalid).
+///
+/// ```
+/// use wkt::TypeSummary;
+/// use wkt::types::{Dimension, GeometryType};
 ///
-/// assert_eq!(
-///     infer_type("POINT EMPTY").unwrap(),
-///     (GeometryType::Point, None)
-/// );
+/// let inputs = ["POINT (1 2)", "SRID=4326;POINT Z EMPTY", "LINESTRING (1 2, 3 4)", "oops"];
+/// let summary: TypeSummary = inputs.into_iter().collect();
+/// assert_eq!(summary.count, 4);
+/// assert_eq!(summary.invalid, 1);
+/// assert_eq!(summary.empty, 1);
+/// assert_eq!(summary.geometry_types[&GeometryType::Point], 2);
+/// assert_eq!(summary.dimensions[&Dimension::XY], 2);
+/// assert_eq!(summary.srids[&Some(4326)], 1);
 /// ```
-pub fn infer_type(input: &str) -> Result<(GeometryType, Option<Dimension>), String> {
-    let input = input.trim_start();
-
-    if let Some((prefix, _suffix)) = input.split_once("(") {
-        let prefix = prefix.to_uppercase();
-
-        let (geom_type, dim_str) = if let Some(dim_str) = prefix.strip_prefix(POINT) {
-            (GeometryType::Point, dim_str)
-        } else if let Some(dim_str) = prefix.strip_prefix(LINESTRING) {
-            (GeometryType::LineString, dim_str)
-        } else if let Some(dim_str) = prefix.strip_prefix(POLYGON) {
-            (GeometryType::Polygon, dim_str)
-        } else if let Some(dim_str) = prefix.strip_prefix(MULTIPOINT) {
-            (GeometryType::MultiPoint, dim_str)
-        } else if let Some(dim_str) = prefix.strip_prefix(MULTILINESTRING) {
-            (GeometryType::MultiLineString, dim_str)
-        } else if let Some(dim_str) = prefix.strip_prefix(MULTIPOLYGON) {
-            (GeometryType::MultiPolygon, dim_str)
-        } else if let Some(dim_str) = prefix.strip_prefix(GEOMETRYCOLLECTION) {
-            (GeometryType::GeometryCollection, dim_str)
-        } else {
-            return Err(format!("Unsupported WKT prefix {}", prefix));
-        };
-
-        let dim = if dim_str.contains("ZM") {
-            Dimension::XYZM
-        } else if dim_str.contains("Z") {
-            Dimension::XYZ
-        } else if dim_str.contains("M") {
-            Dimension::XYM
-        } else {
-            Dimension::XY
-        };
-
-        Ok((geom_type, Some(dim)))
-    } else {
-        let input = input.to_uppercase();
-        if !input.contains("EMPTY") {
-            return Err("Invalid WKT; no '(' character and not EMPTY".to_string());
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeSummary {
+    /// The number of strings added, including invalid ones.
+    pub count: usize,
+    /// The number of strings `infer_type` rejected.
+    pub invalid: usize,
+    /// The number of `EMPTY` geometries.
+    pub empty: usize,
+    pub geometry_types: HashMap<GeometryType, usize>,
+    pub dimensions: HashMap<Dimension, usize>,
+    /// The SRIDs seen, with `None` counting the strings without one.
+    pub srids: HashMap<Option<i32>, usize>,
+}
+
+impl TypeSummary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Infers the type of `input` and adds it to the counts.
+    pub fn add(&mut self, input: &str) {
+        self.count += 1;
+        match infer_type(input) {
+            Ok(inferred) => {
+                self.empty += inferred.is_empty as usize;
+                *self
+                    .geometry_types
+                    .entry(inferred.geometry_type)
+                    .or_default() += 1;
+                *self.dimensions.entry(inferred.dimension).or_default() += 1;
+                *self.srids.entry(inferred.srid).or_default() += 1;
+            }
+            Err(_) => self.invalid += 1,
+        }
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for TypeSummary {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for input in iter {
+            self.add(input.as_ref());
         }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for TypeSummary {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut summary = TypeSummary::new();
+        summary.extend(iter);
+        summary
+    }
+}
 
-        if input.starts_with(POINT) {
-            Ok((GeometryType::Point, None))
-        } else if input.starts_with(LINESTRING) {
-            Ok((GeometryType::LineString, None))
-        } else if input.starts_with(POLYGON) {
-            Ok((GeometryType::Polygon, None))
-        } else if input.starts_with(MULTIPOINT) {
-            Ok((GeometryType::MultiPoint, None))
-        } else if input.starts_with(MULTILINESTRING) {
-            Ok((GeometryType::MultiLineString, None))
-        } else if input.starts_with(MULTIPOLYGON) {
-            Ok((GeometryType::MultiPolygon, None))
-        } else if input.starts_with(GEOMETRYCOLLECTION) {
-            Ok((GeometryType::GeometryCollection, None))
-        } else {
-            return Err(format!("Unsupported WKT prefix {}", input));
+/// Reads the SRID from an EWKT `SRID=<id>;` prefix, without looking at the geometry after it.
+///
+/// Returns `None` if there's no prefix, or its ID isn't a number. The geometry isn't validated,
+/// so this is cheap enough to route many rows by CRS before deciding whether to parse them.
+///
+/// ```
+/// assert_eq!(wkt::extract_srid("SRID=4326;POINT (1 2)"), Some(4326));
+/// assert_eq!(wkt::extract_srid(" srid=3857;not even WKT"), Some(3857));
+/// assert_eq!(wkt::extract_srid("POINT (1 2)"), None);
+/// ```
+pub fn extract_srid(input: &str) -> Option<i32> {
+    split_srid(input.trim_start()).ok()?.0
+}
+
+/// Splits an EWKT `SRID=<id>;` prefix from the rest of the input.
+fn split_srid(input: &str) -> Result<(Option<i32>, &str), String> {
+    match input.get(..5) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("SRID=") => {
+            let (srid, rest) = input[5..]
+                .split_once(';')
+                .ok_or_else(|| "Invalid EWKT; no ';' after the SRID".to_string())?;
+            let srid = srid
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid EWKT SRID {}", srid))?;
+            Ok((Some(srid), rest.trim_start()))
         }
+        _ => Ok((None, input)),
     }
 }
 
@@ -91,23 +256,32 @@ pub fn infer_type(input: &str) -> Result<(GeometryType, Option<Dimension>), Stri
 mod test {
     use super::*;
 
+    fn inferred(geometry_type: GeometryType, dimension: Dimension, is_empty: bool) -> InferredType {
+        InferredType {
+            geometry_type,
+            dimension,
+            is_empty,
+            srid: None,
+        }
+    }
+
     #[test]
     fn test_points() {
         assert_eq!(
             infer_type("POINT (10 20.1)").unwrap(),
-            (GeometryType::Point, Some(Dimension::XY))
+            inferred(GeometryType::Point, Dimension::XY, false)
         );
         assert_eq!(
             infer_type("POINT Z (10 20.1 5)").unwrap(),
-            (GeometryType::Point, Some(Dimension::XYZ))
+            inferred(GeometryType::Point, Dimension::XYZ, false)
         );
         assert_eq!(
             infer_type("POINT M (10 20.1 80)").unwrap(),
-            (GeometryType::Point, Some(Dimension::XYM))
+            inferred(GeometryType::Point, Dimension::XYM, false)
         );
         assert_eq!(
             infer_type("POINT ZM (10 20.1 5 80)").unwrap(),
-            (GeometryType::Point, Some(Dimension::XYZM))
+            inferred(GeometryType::Point, Dimension::XYZM, false)
         );
     }
 
@@ -115,12 +289,12 @@ mod test {
     fn test_with_leading_whitespace() {
         assert_eq!(
             infer_type(" POINT (10 20.1)").unwrap(),
-            (GeometryType::Point, Some(Dimension::XY))
+            inferred(GeometryType::Point, Dimension::XY, false)
         );
 
         assert_eq!(
             infer_type(" POINT EMPTY").unwrap(),
-            (GeometryType::Point, None)
+            inferred(GeometryType::Point, Dimension::XY, true)
         );
     }
 
@@ -128,7 +302,7 @@ mod test {
     fn lowercase_point() {
         assert_eq!(
             infer_type("point EMPTY").unwrap(),
-            (GeometryType::Point, None)
+            inferred(GeometryType::Point, Dimension::XY, true)
         );
     }
 
@@ -136,11 +310,181 @@ mod test {
     fn test_empty() {
         assert_eq!(
             infer_type("POINT EMPTY").unwrap(),
-            (GeometryType::Point, None)
+            inferred(GeometryType::Point, Dimension::XY, true)
         );
         assert_eq!(
             infer_type("MULTIPOLYGON EMPTY").unwrap(),
-            (GeometryType::MultiPolygon, None)
+            inferred(GeometryType::MultiPolygon, Dimension::XY, true)
+        );
+    }
+
+    #[test]
+    fn empty_with_dimension() {
+        assert_eq!(
+            infer_type("POINT Z EMPTY").unwrap(),
+            inferred(GeometryType::Point, Dimension::XYZ, true)
+        );
+        assert_eq!(
+            infer_type("linestring zm empty").unwrap(),
+            inferred(GeometryType::LineString, Dimension::XYZM, true)
+        );
+        // An empty member doesn't make the collection empty.
+        assert_eq!(
+            infer_type("GEOMETRYCOLLECTION (POINT EMPTY)").unwrap(),
+            inferred(GeometryType::GeometryCollection, Dimension::XY, false)
+        );
+    }
+
+    #[test]
+    fn ewkt_srid() {
+        assert_eq!(
+            infer_type("SRID=4326;POINT(1 2)").unwrap(),
+            InferredType {
+                srid: Some(4326),
+                ..inferred(GeometryType::Point, Dimension::XY, false)
+            }
+        );
+        assert_eq!(
+            infer_type(" srid=3857; MULTIPOLYGON Z EMPTY").unwrap(),
+            InferredType {
+                srid: Some(3857),
+                ..inferred(GeometryType::MultiPolygon, Dimension::XYZ, true)
+            }
+        );
+        assert_eq!(
+            infer_type("SRID=-1;POINT(1 2)").unwrap().srid,
+            extract_srid("SRID=-1;POINT(1 2)")
+        );
+        assert!(infer_type("SRID=4326 POINT(1 2)").is_err());
+        assert!(infer_type("SRID=abc;POINT(1 2)").is_err());
+    }
+
+    #[test]
+    fn extracts_srid() {
+        assert_eq!(extract_srid("SRID=4326;POINT(1 2)"), Some(4326));
+        assert_eq!(extract_srid("SRID= -1 ;POINT(1 2)"), Some(-1));
+        assert_eq!(extract_srid("\tSrId=0;"), Some(0));
+        assert_eq!(extract_srid("SRID=4326 POINT(1 2)"), None);
+        assert_eq!(extract_srid("SRID=abc;POINT(1 2)"), None);
+        assert_eq!(extract_srid("SRID"), None);
+        assert_eq!(extract_srid("POINT(1 2)"), None);
+    }
+
+    #[test]
+    fn extended_types() {
+        assert_eq!(
+            infer_type("COMPOUNDCURVE Z (CIRCULARSTRING Z (0 0 0, 1 1 0, 2 0 0))").unwrap(),
+            inferred(GeometryType::CompoundCurve, Dimension::XYZ, false)
+        );
+        assert_eq!(
+            infer_type("TIN (((0 0, 0 1, 1 0, 0 0)))").unwrap(),
+            inferred(GeometryType::Tin, Dimension::XY, false)
+        );
+        assert_eq!(
+            infer_type("TRIANGLE M EMPTY").unwrap(),
+            inferred(GeometryType::Triangle, Dimension::XYM, true)
+        );
+        assert_eq!(
+            infer_type("polyhedralsurface EMPTY").unwrap(),
+            inferred(GeometryType::PolyhedralSurface, Dimension::XY, true)
+        );
+    }
+
+    #[test]
+    fn joined_dimension_tags() {
+        assert_eq!(
+            infer_type("MULTIPOINTZM ((1 2 3 4))").unwrap(),
+            inferred(GeometryType::MultiPoint, Dimension::XYZM, false)
+        );
+        assert_eq!(
+            infer_type("POINTM EMPTY").unwrap(),
+            inferred(GeometryType::Point, Dimension::XYM, true)
+        );
+    }
+
+    #[test]
+    fn invalid_headers() {
+        assert!(infer_type("").is_err());
+        assert!(infer_type("POINTX (1 2)").is_err());
+        assert!(infer_type("POINT Q (1 2)").is_err());
+        assert!(infer_type("POINT Z").is_err());
+        assert!(infer_type("POINT 1 2").is_err());
+        assert!(infer_type("(1 2)").is_err());
+    }
+
+    #[test]
+    fn letters_in_coordinates() {
+        // Only the header decides the dimension, not the tags of members.
+        assert_eq!(
+            infer_type("GEOMETRYCOLLECTION (POINT ZM (1 2 3 4))").unwrap(),
+            inferred(GeometryType::GeometryCollection, Dimension::XY, false)
+        );
+    }
+
+    #[test]
+    fn from_reader() {
+        let input = "SRID=4326;POINT Z EMPTY";
+        assert_eq!(
+            infer_type_from_reader(input.as_bytes()).unwrap(),
+            InferredType {
+                srid: Some(4326),
+                ..inferred(GeometryType::Point, Dimension::XYZ, true)
+            }
+        );
+
+        // Only the header is consumed, even across small buffers.
+        let input = "LINESTRING M (1 2 3, 4 5 6)";
+        let mut reader = io::BufReader::with_capacity(4, input.as_bytes());
+        assert_eq!(
+            infer_type_from_reader(&mut reader).unwrap(),
+            inferred(GeometryType::LineString, Dimension::XYM, false)
+        );
+        let mut rest = String::new();
+        io::Read::read_to_string(&mut reader, &mut rest).unwrap();
+        assert_eq!(rest, "1 2 3, 4 5 6)");
+
+        let err = infer_type_from_reader(" ".repeat(1000).as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let err = infer_type_from_reader("CIRCLE (1 2)".as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn type_summary() {
+        let mut summary: TypeSummary = vec![
+            "POINT (1 2)".to_string(),
+            "MULTIPOLYGON Z EMPTY".to_string(),
+        ]
+        .into_iter()
+        .collect();
+        summary.extend([
+            "SRID=3857;MULTIPOLYGON Z (((0 0 0, 1 0 0, 1 1 0, 0 0 0)))",
+            "?",
+        ]);
+
+        assert_eq!(summary.count, 4);
+        assert_eq!(summary.invalid, 1);
+        assert_eq!(summary.empty, 1);
+        assert_eq!(
+            summary.geometry_types,
+            HashMap::from([(GeometryType::Point, 1), (GeometryType::MultiPolygon, 2)])
+        );
+        assert_eq!(
+            summary.dimensions,
+            HashMap::from([(Dimension::XY, 1), (Dimension::XYZ, 2)])
+        );
+        assert_eq!(summary.srids, HashMap::from([(None, 2), (Some(3857), 1)]));
+    }
+
+    #[test]
+    fn test_linear_ring() {
+        assert_eq!(
+            infer_type("LINEARRING Z (0 0 0, 1 0 0, 1 1 0, 0 0 0)").unwrap(),
+            inferred(GeometryType::LinearRing, Dimension::XYZ, false)
+        );
+        assert_eq!(
+            infer_type("LINEARRING EMPTY").unwrap(),
+            inferred(GeometryType::LinearRing, Dimension::XY, true)
         );
     }
 }
@@ -0,0 +1,52 @@
+//! Moving geometries in and out of [`serde_json::Value`] documents as WKT strings, with the
+//! `serde_json` feature.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::{Wkt, WktNum};
+
+impl<T> Wkt<T>
+where
+    T: WktNum + fmt::Display,
+{
+    /// The WKT of this geometry, as a JSON string, or [`Error::MissingOrdinate`] if a coordinate
+    /// has fewer values than its geometry's dimension.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt = Wkt::<f64>::from_str("POINT (1 2)").unwrap();
+    /// let document = serde_json::json!({ "name": "home", "geometry": wkt.to_json_value().unwrap() });
+    /// assert_eq!(document["geometry"], "POINT(1 2)");
+    /// ```
+    pub fn to_json_value(&self) -> Result<Value, Error> {
+        self.wkt_string().map(Value::String)
+    }
+}
+
+impl<T> Wkt<T>
+where
+    T: WktNum + FromStr + Default,
+{
+    /// Parses a JSON string containing WKT. Any other kind of value is an error.
+    ///
+    /// ```
+    /// use wkt::Wkt;
+    ///
+    /// let document = serde_json::json!({ "name": "home", "geometry": "POINT (1 2)" });
+    /// let wkt = Wkt::<f64>::from_json_value(&document["geometry"]).unwrap();
+    /// assert_eq!(wkt.to_string(), "POINT(1 2)");
+    /// assert!(Wkt::<f64>::from_json_value(&document["missing"]).is_err());
+    /// ```
+    pub fn from_json_value(value: &Value) -> Result<Self, &'static str> {
+        match value {
+            Value::String(wkt) => Wkt::from_str(wkt),
+            _ => Err("Expected a JSON string containing WKT"),
+        }
+    }
+}
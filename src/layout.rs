@@ -0,0 +1,181 @@
+//! `minify` and `prettify`, which change only the whitespace of WKT.
+
+use crate::error::Error;
+use crate::scan::check_syntax;
+use crate::tokenizer::{Token, Tokens};
+
+/// Validates WKT and returns it without any optional whitespace, for embedding it in URLs.
+///
+/// Unlike [`normalize_str`](crate::normalize_str), keywords and numbers are kept as they were
+/// written, apart from dropping a number's leading `+`.
+///
+/// ```
+/// assert_eq!(
+///     wkt::minify("Polygon Z ( (0 0 1, 1.50 0 1 , 1 1 1, 0 0 1) )").unwrap(),
+///     "Polygon Z((0 0 1,1.50 0 1,1 1 1,0 0 1))"
+/// );
+/// ```
+pub fn minify(input: &str) -> Result<String, Error> {
+    check_syntax(input)?;
+    let mut output = String::with_capacity(input.len());
+    let mut after_word = false;
+    for token in Tokens::from_str(input) {
+        // Only words and numbers need a space between them.
+        let word = match token {
+            Token::Word(text) | Token::Number(text) => Some(text),
+            _ => None,
+        };
+        match word {
+            Some(text) => {
+                if after_word {
+                    output.push(' ');
+                }
+                output.push_str(text);
+            }
+            None => output.push(marker(&token)),
+        }
+        after_word = word.is_some();
+    }
+    Ok(output)
+}
+
+/// Validates WKT and returns it laid out for people to read, indenting by `indent` spaces.
+///
+/// Each member of a collection, and each ring of a polygon, is on a line of its own. Lists of
+/// coordinates stay on one line. Like [`minify`], keywords and numbers are kept as they were
+/// written.
+///
+/// ```
+/// assert_eq!(
+///     wkt::prettify("GEOMETRYCOLLECTION(POINT(1 2),POLYGON((0 0,1 0,1 1,0 0)))", 2).unwrap(),
+///     "GEOMETRYCOLLECTION (
+///   POINT (1 2),
+///   POLYGON (
+///     (0 0, 1 0, 1 1, 0 0)
+///   )
+/// )"
+/// );
+/// ```
+pub fn prettify(input: &str, indent: usize) -> Result<String, Error> {
+    check_syntax(input)?;
+    let mut output = String::with_capacity(input.len() * 2);
+    let mut tokens = Tokens::from_str(input);
+    // For each open list, whether its items are on lines of their own.
+    let mut lists: Vec<bool> = vec![];
+    let newline = |output: &mut String, lists: &[bool]| {
+        output.push('\n');
+        let depth = lists.iter().filter(|&&broken| broken).count();
+        output.extend((0..depth * indent).map(|_| ' '));
+    };
+    let mut after_word = false;
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::ParenOpen => {
+                if after_word {
+                    output.push(' ');
+                }
+                output.push('(');
+                // A list of coordinates starts with a number, and any other list with a word or
+                // a parenthesis.
+                let broken = !matches!(tokens.peek(), Some(Token::Number(_)));
+                lists.push(broken);
+                if broken {
+                    newline(&mut output, &lists);
+                }
+            }
+            Token::ParenClose => {
+                if lists.pop() == Some(true) {
+                    newline(&mut output, &lists);
+                }
+                output.push(')');
+            }
+            Token::Comma => {
+                output.push(',');
+                if lists.last() == Some(&true) {
+                    newline(&mut output, &lists);
+                } else {
+                    output.push(' ');
+                }
+            }
+            Token::Word(text) | Token::Number(text) => {
+                if after_word {
+                    output.push(' ');
+                }
+                output.push_str(text);
+            }
+        }
+        after_word = matches!(token, Token::Word(_) | Token::Number(_));
+    }
+    Ok(output)
+}
+
+fn marker(token: &Token<'_>) -> char {
+    match token {
+        Token::Comma => ',',
+        Token::ParenOpen => '(',
+        Token::ParenClose => ')',
+        Token::Word(_) | Token::Number(_) => unreachable!("words and numbers aren't markers"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Wkt;
+    use std::str::FromStr;
+
+    #[test]
+    fn minifies() {
+        for (input, expected) in [
+            ("POINT ( 1  2 )", "POINT(1 2)"),
+            ("point zm\n(1 2 3 4)", "point zm(1 2 3 4)"),
+            ("POINTZ (1 2 3)", "POINTZ(1 2 3)"),
+            (
+                "MULTIPOINT ( 1 2 , ( 3 4 ) , EMPTY )",
+                "MULTIPOINT(1 2,(3 4),EMPTY)",
+            ),
+            ("LINESTRING EMPTY", "LINESTRING EMPTY"),
+            (" POINT(+1e3 .5) ", "POINT(1e3 .5)"),
+        ] {
+            assert_eq!(minify(input).unwrap(), expected);
+        }
+        assert!(minify("POINT (1 2").is_err());
+    }
+
+    #[test]
+    fn prettifies() {
+        assert_eq!(prettify("POINT(1 2)", 4).unwrap(), "POINT (1 2)");
+        assert_eq!(prettify("POINT  Z  EMPTY", 4).unwrap(), "POINT Z EMPTY");
+        assert_eq!(
+            prettify(
+                "MULTIPOLYGON(((0 0,1 0,1 1,0 0),(1 1,2 1,2 2,1 1)),EMPTY)",
+                4
+            )
+            .unwrap(),
+            "MULTIPOLYGON (
+    (
+        (0 0, 1 0, 1 1, 0 0),
+        (1 1, 2 1, 2 2, 1 1)
+    ),
+    EMPTY
+)"
+        );
+        assert_eq!(
+            prettify("MULTIPOINT(EMPTY,(1 2))", 1).unwrap(),
+            "MULTIPOINT (\n EMPTY,\n (1 2)\n)"
+        );
+        assert_eq!(
+            prettify("MULTIPOINT(1 2,(3 4))", 2).unwrap(),
+            "MULTIPOINT (1 2, (3 4))"
+        );
+        assert!(prettify("POLYGON ((0 0, 1 0)", 2).is_err());
+    }
+
+    #[test]
+    fn keeps_the_geometry() {
+        let input = include_str!("../benches/small.wkt");
+        let wkt = Wkt::<f64>::from_str(input).unwrap();
+        assert_eq!(Wkt::from_str(&minify(input).unwrap()), Ok(wkt.clone()));
+        assert_eq!(Wkt::from_str(&prettify(input, 2).unwrap()), Ok(wkt));
+    }
+}
@@ -27,7 +27,8 @@
 //! For advanced usage, see the [`types`](crate::types) module for a list of internally used types.
 //!
 //! This crate has optional `serde` integration for deserializing fields containing WKT. See
-//! [`deserialize`] for an example.
+//! [`deserialize`] for an example. The `serde_json` feature adds `Wkt::to_json_value` and
+//! `Wkt::from_json_value`, for WKT strings in `serde_json::Value` documents.
 //!
 //! # Examples
 //!
@@ -95,6 +96,17 @@
 //!
 //! Implement [`geo_traits`] on your own geometry representation and those functions will work out
 //! of the box on your data.
+//!
+//! ### Errors when writing
+//!
+//! A coordinate with fewer values than its geometry's dimension, such as an XY coordinate in a
+//! `LINESTRING Z`, can't be written. [`Wkt::wkt_string`], [`ToWkt::try_wkt_string`],
+//! [`ToWkt::write_wkt`] and the functions in [`to_wkt`] return an error for it, but two APIs
+//! panic instead:
+//!
+//! - [`ToWkt::wkt_string`]. None of the `geo-types` conversions produce such a coordinate.
+//! - `to_string()`, `format!` and the like on [`Wkt`] and the types in [`types`]. Their
+//!   [`Display`](fmt::Display) impls return [`fmt::Error`], which [`ToString`] panics on.
 use std::default::Default;
 use std::fmt;
 use std::str::FromStr;
@@ -105,13 +117,43 @@ use geo_traits::{
 };
 use num_traits::{Float, Num, NumCast};
 
-use crate::to_wkt::write_geometry;
-use crate::tokenizer::{PeekableTokens, Token, Tokens};
+use crate::error::ParseError;
+use crate::to_wkt::write_wkt;
+use crate::tokenizer::{Token, Tokens};
 use crate::types::{
-    Dimension, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
-    Polygon,
+    Dimension, GeometryCollection, GeometryType, LineString, LinearRing, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon,
 };
 
+#[macro_use]
+mod macros;
+/// Creates a geometry from a WKT file at compile time, like [`wkt!`].
+///
+/// The path is relative to the root of the crate, where its `Cargo.toml` is. Numbers are read as
+/// `f64`, and the build fails if the file can't be read or isn't valid WKT. The file is tracked, so
+/// editing it triggers a rebuild. The same prefixes as `wkt!` are accepted, e.g.
+/// `include_wkt!(GEOMETRY "boundary.wkt")` for a [`Wkt`]. As in `wkt!`, coordinates with more than
+/// two values need a `Z`, `M` or `ZM` tag.
+///
+/// ```
+/// use wkt::include_wkt;
+/// use wkt::types::Polygon;
+///
+/// let polygon: Polygon<f64> = include_wkt!("benches/small.wkt");
+/// assert_eq!(polygon.0.len(), 2);
+/// ```
+///
+/// Geometries with coordinates can only be evaluated in a `static` with the `borrowed:` prefix;
+/// otherwise wrap them in a [`LazyLock`](std::sync::LazyLock) to share one.
+#[cfg(feature = "proc-macro")]
+pub use wkt_macros::include_wkt;
+#[cfg(feature = "proc-macro")]
+#[doc = include_str!("wkt_macro.md")]
+pub use wkt_macros::wkt;
+// The procedural `wkt!` refers to this crate as `::wkt`, which needs to resolve here too.
+#[cfg(feature = "proc-macro")]
+extern crate self as wkt;
+
 pub mod to_wkt;
 mod tokenizer;
 
@@ -122,11 +164,79 @@ pub mod types;
 
 mod infer_type;
 
-pub use infer_type::infer_type;
+pub use infer_type::{extract_srid, infer_type, infer_type_from_reader, InferredType, TypeSummary};
+
+mod estimate;
+
+pub use estimate::estimate_coord_count;
+
+mod reformat;
+mod scan;
+
+pub use scan::{check_syntax, is_valid};
+
+pub use reformat::{normalize_str, reformat};
+
+mod layout;
+pub use layout::{minify, prettify};
+
+mod approx;
+pub use approx::approx_eq;
+
+pub mod extract;
+
+pub mod copy;
+
+pub mod hashable;
+pub use hashable::Hashable;
+
+mod canonical_order;
+mod coords_mut;
+mod debug;
+mod dedup;
+mod heap_size;
+mod measures;
+mod narrow;
+mod normalize;
+mod precision;
+mod snap;
+mod stats;
+
+pub use precision::{precision, Magnitude, Precision};
+pub use stats::GeometryStats;
+
+pub mod validate;
+
+pub mod flat;
+
+pub mod borrowed;
+
+pub mod generate;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub mod read;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "capi")]
+pub mod capi;
+
+#[cfg(feature = "test-util")]
+pub mod test_util;
 
 #[cfg(feature = "geo-types")]
 extern crate geo_types;
 
+/// Items used by the expansion of `wkt!`, not part of the public API.
+#[cfg(feature = "geo-types")]
+#[doc(hidden)]
+pub mod __private {
+    pub extern crate geo_types;
+}
+
 pub use crate::to_wkt::ToWkt;
 
 #[cfg(feature = "geo-types")]
@@ -143,6 +253,10 @@ extern crate serde;
 pub mod deserialize;
 #[cfg(feature = "serde")]
 pub use deserialize::deserialize_wkt;
+// `serde_json` implements `PartialEq<Value>` for the number types, which makes float literals
+// compared with coordinates ambiguous in the unit tests. `tests/json.rs` covers this module instead.
+#[cfg(all(feature = "serde_json", not(test)))]
+mod json;
 
 mod from_wkt;
 pub use from_wkt::TryFromWkt;
@@ -164,7 +278,7 @@ impl<T> WktNum for T where T: Num + NumCast + PartialOrd + PartialEq + Copy + fm
 pub trait WktFloat: WktNum + Float {}
 impl<T> WktFloat for T where T: WktNum + Float {}
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 /// All supported WKT geometry [`types`]
 pub enum Wkt<T>
 where
@@ -172,6 +286,7 @@ where
 {
     Point(Point<T>),
     LineString(LineString<T>),
+    LinearRing(LinearRing<T>),
     Polygon(Polygon<T>),
     MultiPoint(MultiPoint<T>),
     MultiLineString(MultiLineString<T>),
@@ -183,191 +298,42 @@ impl<T> Wkt<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_word_and_tokens(
-        word: &str,
-        tokens: &mut PeekableTokens<T>,
-    ) -> Result<Self, &'static str> {
+    fn from_word_and_tokens(word: &str, tokens: &mut Tokens<'_>) -> Result<Self, &'static str> {
         // Normally Z/M/ZM is separated by a space from the primary WKT word. E.g. `POINT Z`
         // instead of `POINTZ`. However we wish to support both types (in reading). When written
-        // without a space, `POINTZ` is considered a single word, which means we need to include
-        // matches here.
-        match word {
-            w if w.eq_ignore_ascii_case("POINT") => {
-                let x = <Point<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("POINTZ") => {
-                let x = <Point<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZ),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("POINTM") => {
-                let x = <Point<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYM),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("POINTZM") => {
-                let x = <Point<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZM),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("LINESTRING") || w.eq_ignore_ascii_case("LINEARRING") => {
-                let x = <LineString<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("LINESTRINGZ") => {
-                let x = <LineString<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZ),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("LINESTRINGM") => {
-                let x = <LineString<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYM),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("LINESTRINGZM") => {
-                let x = <LineString<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZM),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("POLYGON") => {
-                let x = <Polygon<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("POLYGONZ") => {
-                let x = <Polygon<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZ),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("POLYGONM") => {
-                let x = <Polygon<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYM),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("POLYGONZM") => {
-                let x = <Polygon<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZM),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("MULTIPOINT") => {
-                let x = <MultiPoint<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("MULTIPOINTZ") => {
-                let x = <MultiPoint<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZ),
-                );
-                x.map(|y| y.into())
+        // without a space, `POINTZ` is considered a single word, so `from_keyword` splits it.
+        let (geometry_type, dim) =
+            GeometryType::from_keyword(word).ok_or("Invalid type encountered")?;
+        match geometry_type {
+            GeometryType::Point => {
+                <Point<T> as FromTokens<T>>::from_tokens_with_header(tokens, dim).map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("MULTIPOINTM") => {
-                let x = <MultiPoint<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYM),
-                );
-                x.map(|y| y.into())
+            GeometryType::LineString => {
+                <LineString<T> as FromTokens<T>>::from_tokens_with_header(tokens, dim)
+                    .map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("MULTIPOINTZM") => {
-                let x = <MultiPoint<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZM),
-                );
-                x.map(|y| y.into())
+            GeometryType::LinearRing => {
+                <LinearRing<T> as FromTokens<T>>::from_tokens_with_header(tokens, dim)
+                    .map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("MULTILINESTRING") => {
-                let x =
-                    <MultiLineString<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
-                x.map(|y| y.into())
+            GeometryType::Polygon => {
+                <Polygon<T> as FromTokens<T>>::from_tokens_with_header(tokens, dim).map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("MULTILINESTRINGZ") => {
-                let x = <MultiLineString<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZ),
-                );
-                x.map(|y| y.into())
+            GeometryType::MultiPoint => {
+                <MultiPoint<T> as FromTokens<T>>::from_tokens_with_header(tokens, dim)
+                    .map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("MULTILINESTRINGM") => {
-                let x = <MultiLineString<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYM),
-                );
-                x.map(|y| y.into())
+            GeometryType::MultiLineString => {
+                <MultiLineString<T> as FromTokens<T>>::from_tokens_with_header(tokens, dim)
+                    .map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("MULTILINESTRINGZM") => {
-                let x = <MultiLineString<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZM),
-                );
-                x.map(|y| y.into())
+            GeometryType::MultiPolygon => {
+                <MultiPolygon<T> as FromTokens<T>>::from_tokens_with_header(tokens, dim)
+                    .map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("MULTIPOLYGON") => {
-                let x = <MultiPolygon<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("MULTIPOLYGONZ") => {
-                let x = <MultiPolygon<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZ),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("MULTIPOLYGONM") => {
-                let x = <MultiPolygon<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYM),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("MULTIPOLYGONZM") => {
-                let x = <MultiPolygon<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZM),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("GEOMETRYCOLLECTION") => {
-                let x =
-                    <GeometryCollection<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("GEOMETRYCOLLECTIONZ") => {
-                let x = <GeometryCollection<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZ),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("GEOMETRYCOLLECTIONM") => {
-                let x = <GeometryCollection<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYM),
-                );
-                x.map(|y| y.into())
-            }
-            w if w.eq_ignore_ascii_case("GEOMETRYCOLLECTIONZM") => {
-                let x = <GeometryCollection<T> as FromTokens<T>>::from_tokens_with_header(
-                    tokens,
-                    Some(Dimension::XYZM),
-                );
-                x.map(|y| y.into())
+            GeometryType::GeometryCollection => {
+                <GeometryCollection<T> as FromTokens<T>>::from_tokens_with_header(tokens, dim)
+                    .map(Into::into)
             }
             _ => Err("Invalid type encountered"),
         }
@@ -379,7 +345,7 @@ where
     T: WktNum + fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        Ok(write_geometry(f, self)?)
+        Ok(write_wkt(f, self)?)
     }
 }
 
@@ -387,18 +353,80 @@ impl<T> Wkt<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: Tokens<T>) -> Result<Self, &'static str> {
-        let mut tokens = tokens.peekable();
-        let word = match tokens.next().transpose()? {
+    fn from_tokens(mut tokens: Tokens<'_>) -> Result<Self, &'static str> {
+        Wkt::parse_tokens(&mut tokens).map_err(|err| err.message)
+    }
+
+    fn parse_tokens(tokens: &mut Tokens<'_>) -> Result<Self, ParseError> {
+        let error = |message, tokens: &Tokens<'_>| ParseError {
+            message,
+            position: tokens.last_position(),
+        };
+        let word = match tokens.next() {
             Some(Token::Word(word)) => {
                 if !word.is_ascii() {
-                    return Err("Encountered non-ascii word");
+                    return Err(error("Encountered non-ascii word", tokens));
                 }
                 word
             }
-            _ => return Err("Invalid WKT format"),
+            _ => return Err(error("Invalid WKT format", tokens)),
         };
-        Wkt::from_word_and_tokens(&word, &mut tokens)
+        Wkt::from_word_and_tokens(word, tokens).map_err(|message| {
+            #[cfg(feature = "log")]
+            log::debug!(
+                "Invalid WKT near byte {}: {message}",
+                tokens.last_position()
+            );
+            error(message, tokens)
+        })
+    }
+
+    /// Parses WKT like [`Wkt::from_str`], but an error also says where the input stopped making
+    /// sense. Unlike `from_str`, anything after the geometry is an error too.
+    ///
+    /// ```
+    /// use wkt::Wkt;
+    ///
+    /// let err = Wkt::<f64>::parse("LINESTRING (1 2, 3 x)").unwrap_err();
+    /// assert_eq!(err.position, 19);
+    /// assert_eq!(err.to_string(), "Expected a number for the Y coordinate, near byte 19");
+    ///
+    /// assert!(Wkt::<f64>::parse("POINT (1 2) x").is_err());
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut tokens = Tokens::from_str(input);
+        let wkt = Wkt::parse_tokens(&mut tokens)?;
+        match tokens.next() {
+            None => Ok(wkt),
+            Some(_) => Err(ParseError {
+                message: "Unexpected token after the geometry",
+                position: tokens.last_position(),
+            }),
+        }
+    }
+}
+
+impl<T> TryFrom<&str> for Wkt<T>
+where
+    T: WktNum + FromStr + Default,
+{
+    type Error = ParseError;
+
+    /// Parses WKT, see [`Wkt::parse`].
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Wkt::parse(value)
+    }
+}
+
+impl<T> TryFrom<String> for Wkt<T>
+where
+    T: WktNum + FromStr + Default,
+{
+    type Error = ParseError;
+
+    /// Parses WKT, see [`Wkt::parse`].
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Wkt::parse(&value)
     }
 }
 
@@ -413,6 +441,270 @@ where
     }
 }
 
+impl<T> Wkt<T>
+where
+    T: WktNum + FromStr + Default,
+{
+    /// Parses WKT from bytes, such as a buffer read from a file or socket, without first
+    /// converting it to a `&str`.
+    ///
+    /// Valid WKT is ASCII, so the input is checked for that once, which is faster than UTF-8
+    /// validation. Any other input is rejected, and otherwise this behaves like
+    /// [`Wkt::from_str`].
+    ///
+    /// ```
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_bytes(b"POINT (1 2)").unwrap();
+    /// assert_eq!(wkt.to_string(), "POINT(1 2)");
+    /// assert!(Wkt::<f64>::from_bytes("POINT (1 2) é".as_bytes()).is_err());
+    /// ```
+    pub fn from_bytes(wkt_bytes: &[u8]) -> Result<Self, &'static str> {
+        if !wkt_bytes.is_ascii() {
+            return Err("Encountered non-ascii input");
+        }
+        // Safety:
+        // ASCII is valid UTF-8
+        let wkt_str = unsafe { std::str::from_utf8_unchecked(wkt_bytes) };
+        Wkt::from_tokens(Tokens::from_str(wkt_str))
+    }
+}
+
+impl<T> Wkt<T>
+where
+    T: WktNum,
+{
+    /// The [`GeometryType`] of this geometry.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    /// use wkt::types::GeometryType;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("MULTIPOINT(1 2,3 4)").unwrap();
+    /// assert_eq!(wkt.geometry_type(), GeometryType::MultiPoint);
+    /// assert_eq!(wkt.geometry_type().to_string(), "MULTIPOINT");
+    /// ```
+    pub fn geometry_type(&self) -> GeometryType {
+        match self {
+            Wkt::Point(_) => GeometryType::Point,
+            Wkt::LineString(_) => GeometryType::LineString,
+            Wkt::LinearRing(_) => GeometryType::LinearRing,
+            Wkt::Polygon(_) => GeometryType::Polygon,
+            Wkt::MultiPoint(_) => GeometryType::MultiPoint,
+            Wkt::MultiLineString(_) => GeometryType::MultiLineString,
+            Wkt::MultiPolygon(_) => GeometryType::MultiPolygon,
+            Wkt::GeometryCollection(_) => GeometryType::GeometryCollection,
+        }
+    }
+
+    /// An empty geometry of the given type, e.g. for returning "no result" with the expected
+    /// type. Returns `None` for the [extended](GeometryType::is_extended) types that `Wkt` can't
+    /// represent.
+    ///
+    /// Empty geometries don't record a dimension, so they are always written without a dimension
+    /// tag, e.g. `POINT EMPTY`.
+    ///
+    /// ```
+    /// use wkt::Wkt;
+    /// use wkt::types::GeometryType;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::empty(GeometryType::MultiPolygon).unwrap();
+    /// assert_eq!(wkt.geometry_type(), GeometryType::MultiPolygon);
+    /// assert_eq!(wkt.to_string(), "MULTIPOLYGON EMPTY");
+    ///
+    /// assert!(Wkt::<f64>::empty(GeometryType::CircularString).is_none());
+    /// ```
+    pub fn empty(geometry_type: GeometryType) -> Option<Self> {
+        let wkt = match geometry_type {
+            GeometryType::Point => Wkt::Point(Point(None)),
+            GeometryType::LineString => Wkt::LineString(LineString(vec![])),
+            GeometryType::LinearRing => Wkt::LinearRing(LinearRing(LineString(vec![]))),
+            GeometryType::Polygon => Wkt::Polygon(Polygon(vec![])),
+            GeometryType::MultiPoint => Wkt::MultiPoint(MultiPoint(vec![])),
+            GeometryType::MultiLineString => Wkt::MultiLineString(MultiLineString(vec![])),
+            GeometryType::MultiPolygon => Wkt::MultiPolygon(MultiPolygon(vec![])),
+            GeometryType::GeometryCollection => Wkt::GeometryCollection(GeometryCollection(vec![])),
+            _ => return None,
+        };
+        Some(wkt)
+    }
+
+    /// Wraps a single geometry into its multi counterpart, e.g. a `POINT` into a `MULTIPOINT` with
+    /// one member. Multi geometries and geometry collections are returned unchanged.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POLYGON((0 0,1 0,1 1,0 0))").unwrap();
+    /// assert_eq!(wkt.into_multi().to_string(), "MULTIPOLYGON(((0 0,1 0,1 1,0 0)))");
+    /// ```
+    ///
+    /// An empty single geometry becomes an empty multi geometry, rather than a multi geometry
+    /// containing an empty member:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POINT EMPTY").unwrap();
+    /// assert_eq!(wkt.into_multi().to_string(), "MULTIPOINT EMPTY");
+    /// ```
+    pub fn into_multi(self) -> Self {
+        match self {
+            Wkt::Point(Point(None)) => Wkt::MultiPoint(MultiPoint(vec![])),
+            Wkt::LineString(g) if g.0.is_empty() => Wkt::MultiLineString(MultiLineString(vec![])),
+            Wkt::LinearRing(g) if (g.0).0.is_empty() => {
+                Wkt::MultiLineString(MultiLineString(vec![]))
+            }
+            Wkt::Polygon(g) if g.0.is_empty() => Wkt::MultiPolygon(MultiPolygon(vec![])),
+            Wkt::Point(g) => Wkt::MultiPoint(g.into()),
+            Wkt::LineString(g) => Wkt::MultiLineString(g.into()),
+            Wkt::LinearRing(g) => Wkt::MultiLineString(g.0.into()),
+            Wkt::Polygon(g) => Wkt::MultiPolygon(g.into()),
+            multi => multi,
+        }
+    }
+
+    /// Wraps the geometry into a `GEOMETRYCOLLECTION` with it as the only member, e.g. for a column
+    /// that only holds collections. A geometry collection is returned as it is.
+    ///
+    /// Unlike [`Wkt::into_multi`], an empty geometry is kept as the member, so nothing is lost.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POINT(1 2)").unwrap();
+    /// assert_eq!(wkt.into_collection().to_string(), "GEOMETRYCOLLECTION(POINT(1 2))");
+    /// ```
+    pub fn into_collection(self) -> GeometryCollection<T> {
+        match self {
+            Wkt::GeometryCollection(collection) => collection,
+            geometry => GeometryCollection(vec![geometry]),
+        }
+    }
+
+    /// Inlines nested geometry collections, see [`GeometryCollection::flatten`]. Other geometries
+    /// are returned unchanged.
+    pub fn flatten(self) -> Self {
+        match self {
+            Wkt::GeometryCollection(collection) => Wkt::GeometryCollection(collection.flatten()),
+            geometry => geometry,
+        }
+    }
+
+    /// How deeply geometry collections are nested: 0 for any other geometry, 1 for a collection
+    /// whose members aren't collections, and so on. Use it to enforce a limit, or to decide
+    /// whether to [`flatten`](Wkt::flatten) first.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str(
+    ///     "GEOMETRYCOLLECTION(POINT(1 2),GEOMETRYCOLLECTION(GEOMETRYCOLLECTION EMPTY))",
+    /// ).unwrap();
+    /// assert_eq!(wkt.max_depth(), 3);
+    /// assert_eq!(wkt.flatten().max_depth(), 1);
+    /// ```
+    pub fn max_depth(&self) -> usize {
+        match self {
+            Wkt::GeometryCollection(collection) => {
+                1 + collection.0.iter().map(Wkt::max_depth).max().unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
+    /// Splits the geometry into points, line strings, linear rings and polygons, recursing into
+    /// multi geometries and geometry collections. Members are moved, not cloned, and keep their
+    /// order. Empty members are kept, but an empty multi geometry or collection has no parts.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str(
+    ///     "GEOMETRYCOLLECTION(MULTIPOINT(1 2,3 4),GEOMETRYCOLLECTION(LINESTRING(5 6,7 8)))",
+    /// ).unwrap();
+    /// let parts: Vec<String> = wkt.into_primitives().iter().map(Wkt::to_string).collect();
+    /// assert_eq!(parts, ["POINT(1 2)", "POINT(3 4)", "LINESTRING(5 6,7 8)"]);
+    /// ```
+    pub fn into_primitives(self) -> Vec<Self> {
+        let mut primitives = vec![];
+        self.primitives_into(&mut primitives);
+        primitives
+    }
+
+    fn primitives_into(self, target: &mut Vec<Self>) {
+        match self {
+            Wkt::MultiPoint(g) => target.extend(g.0.into_iter().map(Wkt::Point)),
+            Wkt::MultiLineString(g) => target.extend(g.0.into_iter().map(Wkt::LineString)),
+            Wkt::MultiPolygon(g) => target.extend(g.0.into_iter().map(Wkt::Polygon)),
+            Wkt::GeometryCollection(g) => {
+                for geometry in g {
+                    geometry.primitives_into(target);
+                }
+            }
+            primitive => target.push(primitive),
+        }
+    }
+}
+
+macro_rules! impl_variant_accessors {
+    ($($variant: ident, $keyword: literal, $as_fn: ident, $into_fn: ident);+ $(;)?) => {
+        /// Accessors for the geometry held by each variant.
+        ///
+        /// ```
+        /// use std::str::FromStr;
+        /// use wkt::Wkt;
+        ///
+        /// let wkt: Wkt<f64> = Wkt::from_str("POINT(1 2)").unwrap();
+        /// assert!(wkt.as_point().is_some());
+        /// assert!(wkt.as_polygon().is_none());
+        ///
+        /// let wkt = wkt.into_polygon().unwrap_err();
+        /// let point = wkt.into_point().unwrap();
+        /// assert_eq!(point.0.unwrap().x, 1.0);
+        /// ```
+        impl<T> Wkt<T>
+        where
+            T: WktNum,
+        {
+            $(
+                #[doc = concat!("Returns the inner [`", stringify!($variant), "`] if this is a `", $keyword, "`, otherwise `None`.")]
+                pub fn $as_fn(&self) -> Option<&$variant<T>> {
+                    match self {
+                        Wkt::$variant(g) => Some(g),
+                        _ => None,
+                    }
+                }
+
+                #[doc = concat!("Converts into the inner [`", stringify!($variant), "`] if this is a `", $keyword, "`, otherwise returns `self` unchanged as the error.")]
+                pub fn $into_fn(self) -> Result<$variant<T>, Self> {
+                    match self {
+                        Wkt::$variant(g) => Ok(g),
+                        other => Err(other),
+                    }
+                }
+            )+
+        }
+    };
+}
+
+impl_variant_accessors!(
+    Point, "POINT", as_point, into_point;
+    LineString, "LINESTRING", as_line_string, into_line_string;
+    LinearRing, "LINEARRING", as_linear_ring, into_linear_ring;
+    Polygon, "POLYGON", as_polygon, into_polygon;
+    MultiPoint, "MULTIPOINT", as_multi_point, into_multi_point;
+    MultiLineString, "MULTILINESTRING", as_multi_line_string, into_multi_line_string;
+    MultiPolygon, "MULTIPOLYGON", as_multi_polygon, into_multi_polygon;
+    GeometryCollection, "GEOMETRYCOLLECTION", as_geometry_collection, into_geometry_collection;
+);
+
 impl<T: WktNum> GeometryTrait for Wkt<T> {
     type T = T;
     type PointType<'b>
@@ -460,6 +752,7 @@ impl<T: WktNum> GeometryTrait for Wkt<T> {
         match self {
             Wkt::Point(geom) => PointTrait::dim(geom),
             Wkt::LineString(geom) => LineStringTrait::dim(geom),
+            Wkt::LinearRing(geom) => LineStringTrait::dim(geom),
             Wkt::Polygon(geom) => PolygonTrait::dim(geom),
             Wkt::MultiPoint(geom) => MultiPointTrait::dim(geom),
             Wkt::MultiLineString(geom) => MultiLineStringTrait::dim(geom),
@@ -486,6 +779,7 @@ impl<T: WktNum> GeometryTrait for Wkt<T> {
         match self {
             Wkt::Point(geom) => geo_traits::GeometryType::Point(geom),
             Wkt::LineString(geom) => geo_traits::GeometryType::LineString(geom),
+            Wkt::LinearRing(geom) => geo_traits::GeometryType::LineString(&geom.0),
             Wkt::Polygon(geom) => geo_traits::GeometryType::Polygon(geom),
             Wkt::MultiPoint(geom) => geo_traits::GeometryType::MultiPoint(geom),
             Wkt::MultiLineString(geom) => geo_traits::GeometryType::MultiLineString(geom),
@@ -542,6 +836,7 @@ impl<T: WktNum> GeometryTrait for &Wkt<T> {
         match self {
             Wkt::Point(geom) => PointTrait::dim(geom),
             Wkt::LineString(geom) => LineStringTrait::dim(geom),
+            Wkt::LinearRing(geom) => LineStringTrait::dim(geom),
             Wkt::Polygon(geom) => PolygonTrait::dim(geom),
             Wkt::MultiPoint(geom) => MultiPointTrait::dim(geom),
             Wkt::MultiLineString(geom) => MultiLineStringTrait::dim(geom),
@@ -568,6 +863,7 @@ impl<T: WktNum> GeometryTrait for &Wkt<T> {
         match self {
             Wkt::Point(geom) => geo_traits::GeometryType::Point(geom),
             Wkt::LineString(geom) => geo_traits::GeometryType::LineString(geom),
+            Wkt::LinearRing(geom) => geo_traits::GeometryType::LineString(&geom.0),
             Wkt::Polygon(geom) => geo_traits::GeometryType::Polygon(geom),
             Wkt::MultiPoint(geom) => geo_traits::GeometryType::MultiPoint(geom),
             Wkt::MultiLineString(geom) => geo_traits::GeometryType::MultiLineString(geom),
@@ -723,27 +1019,16 @@ impl_specialization!(MultiLineString);
 impl_specialization!(MultiPolygon);
 impl_specialization!(GeometryCollection);
 
-fn infer_geom_dimension<T: WktNum + FromStr + Default>(
-    tokens: &mut PeekableTokens<T>,
-) -> Result<Dimension, &'static str> {
-    if let Some(Ok(c)) = tokens.peek() {
+fn infer_geom_dimension(tokens: &mut Tokens<'_>) -> Result<Dimension, &'static str> {
+    if let Some(c) = tokens.peek() {
         match c {
             // If we match a word check if it's Z/M/ZM and consume the token from the stream
-            Token::Word(w) => match w.as_str() {
-                w if w.eq_ignore_ascii_case("Z") => {
-                    tokens.next().unwrap().unwrap();
-                    Ok(Dimension::XYZ)
-                }
-                w if w.eq_ignore_ascii_case("M") => {
-                    tokens.next().unwrap().unwrap();
-
-                    Ok(Dimension::XYM)
-                }
-                w if w.eq_ignore_ascii_case("ZM") => {
-                    tokens.next().unwrap().unwrap();
-                    Ok(Dimension::XYZM)
+            Token::Word(w) if w.eq_ignore_ascii_case("EMPTY") => Ok(Dimension::XY),
+            Token::Word(w) => match Dimension::from_suffix(w) {
+                Some(dim) => {
+                    tokens.next();
+                    Ok(dim)
                 }
-                w if w.eq_ignore_ascii_case("EMPTY") => Ok(Dimension::XY),
                 _ => Err("Unexpected word before open paren"),
             },
             // Not a word, e.g. an open paren
@@ -758,12 +1043,12 @@ trait FromTokens<T>: Sized + Default
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str>;
+    fn from_tokens(tokens: &mut Tokens<'_>, dim: Dimension) -> Result<Self, &'static str>;
 
     /// The preferred top-level FromTokens API, which additionally checks for the presence of Z, M,
     /// and ZM in the token stream.
     fn from_tokens_with_header(
-        tokens: &mut PeekableTokens<T>,
+        tokens: &mut Tokens<'_>,
         dim: Option<Dimension>,
     ) -> Result<Self, &'static str> {
         let dim = if let Some(dim) = dim {
@@ -775,12 +1060,12 @@ where
     }
 
     fn from_tokens_with_parens(
-        tokens: &mut PeekableTokens<T>,
+        tokens: &mut Tokens<'_>,
         dim: Dimension,
     ) -> Result<Self, &'static str> {
-        match tokens.next().transpose()? {
+        match tokens.next() {
             Some(Token::ParenOpen) => (),
-            Some(Token::Word(ref s)) if s.eq_ignore_ascii_case("EMPTY") => {
+            Some(Token::Word(s)) if s.eq_ignore_ascii_case("EMPTY") => {
                 // TODO: expand this to support Z EMPTY
                 // Maybe create a DefaultXY, DefaultXYZ trait etc for each geometry type, and then
                 // here match on the dim to decide which default trait to use.
@@ -788,38 +1073,41 @@ where
             }
             _ => return Err("Missing open parenthesis for type"),
         };
-        let result = FromTokens::from_tokens(tokens, dim);
-        match tokens.next().transpose()? {
+        let result = FromTokens::from_tokens(tokens, dim)?;
+        match tokens.next() {
             Some(Token::ParenClose) => (),
             _ => return Err("Missing closing parenthesis for type"),
         };
-        result
+        Ok(result)
     }
 
     fn from_tokens_with_optional_parens(
-        tokens: &mut PeekableTokens<T>,
+        tokens: &mut Tokens<'_>,
         dim: Dimension,
     ) -> Result<Self, &'static str> {
         match tokens.peek() {
-            Some(Ok(Token::ParenOpen)) => Self::from_tokens_with_parens(tokens, dim),
+            Some(Token::ParenOpen) => Self::from_tokens_with_parens(tokens, dim),
+            Some(Token::Word(s)) if s.eq_ignore_ascii_case("EMPTY") => {
+                Self::from_tokens_with_parens(tokens, dim)
+            }
             _ => Self::from_tokens(tokens, dim),
         }
     }
 
     fn comma_many<F>(
         f: F,
-        tokens: &mut PeekableTokens<T>,
+        tokens: &mut Tokens<'_>,
         dim: Dimension,
     ) -> Result<Vec<Self>, &'static str>
     where
-        F: Fn(&mut PeekableTokens<T>, Dimension) -> Result<Self, &'static str>,
+        F: Fn(&mut Tokens<'_>, Dimension) -> Result<Self, &'static str>,
     {
-        let mut items = Vec::new();
+        let mut items = Vec::with_capacity(tokens.estimate_items());
 
         let item = f(tokens, dim)?;
         items.push(item);
 
-        while let Some(&Ok(Token::Comma)) = tokens.peek() {
+        while let Some(&Token::Comma) = tokens.peek() {
             tokens.next(); // throw away comma
 
             let item = f(tokens, dim)?;
@@ -832,8 +1120,10 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::types::{Coord, MultiPolygon, Point};
+    use crate::error::{Error, ParseError};
+    use crate::types::{Coord, Dimension, MultiPolygon, Point};
     use crate::Wkt;
+    use geo_traits::GeometryTrait;
     use std::str::FromStr;
 
     #[test]
@@ -842,6 +1132,69 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn max_depth() {
+        for (input, expected) in [
+            ("POINT (1 2)", 0),
+            ("MULTIPOLYGON EMPTY", 0),
+            ("GEOMETRYCOLLECTION EMPTY", 1),
+            (
+                "GEOMETRYCOLLECTION (GEOMETRYCOLLECTION (POINT (1 2)), POINT (3 4))",
+                2,
+            ),
+        ] {
+            let wkt: Wkt<f64> = Wkt::from_str(input).unwrap();
+            assert_eq!(wkt.max_depth(), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn into_collection() {
+        for (input, expected) in [
+            ("POINT EMPTY", "GEOMETRYCOLLECTION(POINT EMPTY)"),
+            ("MULTIPOINT (1 2)", "GEOMETRYCOLLECTION(MULTIPOINT((1 2)))"),
+            (
+                "GEOMETRYCOLLECTION (POINT (1 2))",
+                "GEOMETRYCOLLECTION(POINT(1 2))",
+            ),
+            ("GEOMETRYCOLLECTION EMPTY", "GEOMETRYCOLLECTION EMPTY"),
+        ] {
+            let wkt: Wkt<f64> = Wkt::from_str(input).unwrap();
+            assert_eq!(wkt.into_collection().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn parse_errors_have_positions() {
+        let err = Wkt::<f64>::parse("").unwrap_err();
+        assert_eq!((err.message, err.position), ("Invalid WKT format", 0));
+        let err = Wkt::<f64>::parse("POINT (1 x)").unwrap_err();
+        assert_eq!(
+            err.message,
+            Wkt::<f64>::from_str("POINT (1 x)").unwrap_err()
+        );
+        assert_eq!(err.position, 9);
+        let input = "POLYGON ((0 0, 1 0, 1 1, 0 0)";
+        assert_eq!(Wkt::<f64>::parse(input).unwrap_err().position, input.len());
+        let err = Wkt::<f64>::parse("POINT (1 2) x").unwrap_err();
+        assert_eq!(
+            (err.message, err.position),
+            ("Unexpected token after the geometry", 12)
+        );
+        assert!(Wkt::<f64>::parse("POINT (1 2)  ").is_ok());
+
+        let wkt: Wkt<f64> = "POINT (1 2)".try_into().unwrap();
+        assert_eq!(Wkt::try_from(String::from("POINT (1 2)")), Ok(wkt));
+        assert!(Wkt::<f64>::try_from("POINT").is_err());
+
+        let err = Error::from(Wkt::<f64>::parse("POINT (1 x)").unwrap_err());
+        assert!(matches!(err, Error::Parse(ParseError { position: 9, .. })));
+        assert_eq!(
+            err.to_string(),
+            "Invalid WKT: Expected a number for the Y coordinate, near byte 9"
+        );
+    }
+
     #[test]
     fn empty_items() {
         let wkt: Wkt<f64> = Wkt::from_str("POINT EMPTY").ok().unwrap();
@@ -928,12 +1281,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn joined_dimension_keywords() {
+        for (input, dim) in [
+            ("pointz (1 2 3)", Dimension::XYZ),
+            ("MultiPointM ((1 2 3))", Dimension::XYM),
+            ("LINEARRINGZM (0 0 0 0, 1 1 1 1, 0 0 0 0)", Dimension::XYZM),
+            ("GEOMETRYCOLLECTIONZM (POINTZM (1 2 3 4))", Dimension::XYZM),
+        ] {
+            let wkt: Wkt<f64> = Wkt::from_str(input).unwrap();
+            assert_eq!(wkt.dim(), dim.into(), "{input}");
+        }
+        assert_eq!(
+            Wkt::<f64>::from_str("POINTQ (1 2)").unwrap_err(),
+            "Invalid type encountered"
+        );
+        assert_eq!(
+            Wkt::<f64>::from_str("CIRCULARSTRING (0 0, 1 1, 2 0)").unwrap_err(),
+            "Invalid type encountered"
+        );
+    }
+
     #[test]
     fn support_jts_linearring() {
         let wkt: Wkt<f64> = Wkt::from_str("linearring (10 20, 30 40)").ok().unwrap();
         match wkt {
-            Wkt::LineString(_ls) => (),
-            _ => panic!("expected to be parsed as a LINESTRING"),
+            Wkt::LinearRing(_ring) => (),
+            _ => panic!("expected to be parsed as a LINEARRING"),
         };
     }
 
@@ -951,6 +1325,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn variant_accessors() {
+        let wkt: Wkt<f64> = Wkt::from_str("LINESTRING(10 20, 20 30)").unwrap();
+        assert_eq!(wkt.as_line_string().unwrap().0.len(), 2);
+        assert!(wkt.as_point().is_none());
+        assert!(wkt.as_multi_line_string().is_none());
+        assert!(wkt.as_geometry_collection().is_none());
+
+        let wkt = wkt.into_multi_polygon().unwrap_err();
+        assert!(matches!(wkt, Wkt::LineString(_)));
+        assert_eq!(wkt.into_line_string().unwrap().0[1].y, 30.0);
+
+        let wkt: Wkt<f64> = Wkt::from_str("GEOMETRYCOLLECTION(POINT(1 2))").unwrap();
+        let collection = wkt.into_geometry_collection().unwrap();
+        assert_eq!(
+            collection.0[0].as_point().unwrap().0.as_ref().unwrap().x,
+            1.0
+        );
+    }
+
     #[test]
     fn test_display_on_wkt() {
         let wktls: Wkt<f64> = Wkt::from_str("LINESTRING(10 20, 20 30)").unwrap();
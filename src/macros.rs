@@ -0,0 +1,661 @@
+#[doc = include_str!("wkt_macro.md")]
+#[cfg(not(feature = "proc-macro"))]
+#[macro_export]
+macro_rules! wkt {
+    ($($wkt:tt)+) => {
+        $crate::wkt_internal!($($wkt)+)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! wkt_internal {
+    // A single coordinate, from its values.
+    (@coord [] [$x:expr, $y:expr,]) => {
+        $crate::types::Coord { x: $x, y: $y, z: None, m: None }
+    };
+    (@coord [Z] [$x:expr, $y:expr, $z:expr,]) => {
+        $crate::types::Coord { x: $x, y: $y, z: Some($z), m: None }
+    };
+    (@coord [M] [$x:expr, $y:expr, $m:expr,]) => {
+        $crate::types::Coord { x: $x, y: $y, z: None, m: Some($m) }
+    };
+    (@coord [ZM] [$x:expr, $y:expr, $z:expr, $m:expr,]) => {
+        $crate::types::Coord { x: $x, y: $y, z: Some($z), m: Some($m) }
+    };
+    (@coord [geo] [$x:expr, $y:expr,]) => {
+        $crate::__private::geo_types::Coord { x: $x, y: $y }
+    };
+    (@coord $dim:tt [$($value:expr,)*]) => {
+        compile_error!("wrong number of values for the coordinate dimension")
+    };
+    // A single coordinate, from its tokens.
+    (@coord $dim:tt [$($value:expr,)*] - $next:tt $($rest:tt)*) => {
+        $crate::wkt_internal!(@coord $dim [$($value,)* -$next,] $($rest)*)
+    };
+    (@coord $dim:tt [$($value:expr,)*] $next:tt $($rest:tt)*) => {
+        $crate::wkt_internal!(@coord $dim [$($value,)* $next,] $($rest)*)
+    };
+
+    // A comma separated list of coordinates, as a `vec` or a `slice`, or as a slice of `points`.
+    // Lists without negative values skip the recursion.
+    (@list vec $($item:expr),+) => {
+        ::std::vec![$($item),+]
+    };
+    (@list slice $($item:expr),+) => {
+        &[$($item),+]
+    };
+    (@list points $($item:expr),+) => {
+        &[$($crate::types::Point(Some($item))),+]
+    };
+    (@coords $list:ident [] $($x:tt $y:tt),+ $(,)?) => {
+        $crate::wkt_internal!(@list $list $($crate::wkt_internal!(@coord [] [$x, $y,])),+)
+    };
+    (@coords $list:ident [geo] $($x:tt $y:tt),+ $(,)?) => {
+        $crate::wkt_internal!(@list $list $($crate::wkt_internal!(@coord [geo] [$x, $y,])),+)
+    };
+    (@coords $list:ident [$dim:ident] $($x:tt $y:tt $z:tt),+ $(,)?) => {
+        $crate::wkt_internal!(@list $list $($crate::wkt_internal!(@coord [$dim] [$x, $y, $z,])),+)
+    };
+    (@coords $list:ident [ZM] $($x:tt $y:tt $z:tt $m:tt),+ $(,)?) => {
+        $crate::wkt_internal!(@list $list $($crate::wkt_internal!(@coord [ZM] [$x, $y, $z, $m,])),+)
+    };
+    (@coords_from $list:ident $dim:tt [$($coord:expr,)*] [$($value:expr,)*] $(,)?) => {
+        $crate::wkt_internal!(@list $list $($coord,)* $crate::wkt_internal!(@coord $dim [$($value,)*]))
+    };
+    (@coords_from $list:ident $dim:tt [$($coord:expr,)*] [$($value:expr,)*] , $($rest:tt)+) => {
+        $crate::wkt_internal!(
+            @coords_from $list $dim
+                [$($coord,)* $crate::wkt_internal!(@coord $dim [$($value,)*]),] [] $($rest)+
+        )
+    };
+    (@coords_from $list:ident $dim:tt [$($coord:expr,)*] [$($value:expr,)*] - $next:tt $($rest:tt)*) => {
+        $crate::wkt_internal!(@coords_from $list $dim [$($coord,)*] [$($value,)* -$next,] $($rest)*)
+    };
+    (@coords_from $list:ident $dim:tt [$($coord:expr,)*] [$($value:expr,)*] $next:tt $($rest:tt)*) => {
+        $crate::wkt_internal!(@coords_from $list $dim [$($coord,)*] [$($value,)* $next,] $($rest)*)
+    };
+
+    (@coords $list:ident $dim:tt $($tokens:tt)+) => {
+        $crate::wkt_internal!(@coords_from $list $dim [] [] $($tokens)+)
+    };
+
+    // The members of a geometry collection, split on commas.
+    (@members [$($member:expr,)*] [$($current:tt)+] $(,)?) => {
+        ::std::vec![$($member,)* $crate::Wkt::from($crate::wkt_internal!($($current)+))]
+    };
+    (@members [$($member:expr,)*] [$($current:tt)+] , $($rest:tt)+) => {
+        $crate::wkt_internal!(
+            @members [$($member,)* $crate::Wkt::from($crate::wkt_internal!($($current)+)),] [] $($rest)+
+        )
+    };
+    (@members [$($member:expr,)*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::wkt_internal!(@members [$($member,)*] [$($current)* $next] $($rest)*)
+    };
+
+    (@empty POINT) => {
+        $crate::types::Point(None)
+    };
+    (@empty LINESTRING) => {
+        $crate::types::LineString(::std::vec::Vec::new())
+    };
+    (@empty LINEARRING) => {
+        $crate::types::LinearRing($crate::types::LineString(::std::vec::Vec::new()))
+    };
+    (@empty POLYGON) => {
+        $crate::types::Polygon(::std::vec::Vec::new())
+    };
+    (@empty MULTIPOINT) => {
+        $crate::types::MultiPoint(::std::vec::Vec::new())
+    };
+    (@empty MULTILINESTRING) => {
+        $crate::types::MultiLineString(::std::vec::Vec::new())
+    };
+    (@empty MULTIPOLYGON) => {
+        $crate::types::MultiPolygon(::std::vec::Vec::new())
+    };
+    (@empty GEOMETRYCOLLECTION) => {
+        $crate::types::GeometryCollection(::std::vec::Vec::new())
+    };
+
+    // The `Wkt` variant wrapping a geometry, spelled out instead of using `From` so that it can be
+    // evaluated in a constant.
+    (@wkt POINT $geometry:expr) => {
+        $crate::Wkt::Point($geometry)
+    };
+    (@wkt LINESTRING $geometry:expr) => {
+        $crate::Wkt::LineString($geometry)
+    };
+    (@wkt LINEARRING $geometry:expr) => {
+        $crate::Wkt::LinearRing($geometry)
+    };
+    (@wkt POLYGON $geometry:expr) => {
+        $crate::Wkt::Polygon($geometry)
+    };
+    (@wkt MULTIPOINT $geometry:expr) => {
+        $crate::Wkt::MultiPoint($geometry)
+    };
+    (@wkt MULTILINESTRING $geometry:expr) => {
+        $crate::Wkt::MultiLineString($geometry)
+    };
+    (@wkt MULTIPOLYGON $geometry:expr) => {
+        $crate::Wkt::MultiPolygon($geometry)
+    };
+    (@wkt GEOMETRYCOLLECTION $geometry:expr) => {
+        $crate::Wkt::GeometryCollection($geometry)
+    };
+
+    (@geom $kind:ident $dim:tt EMPTY) => {
+        $crate::wkt_internal!(@empty $kind)
+    };
+    (@geom POINT $dim:tt ($($tokens:tt)+)) => {
+        $crate::types::Point(Some($crate::wkt_internal!(@coord $dim [] $($tokens)+)))
+    };
+    (@geom LINESTRING $dim:tt ($($tokens:tt)+)) => {
+        $crate::types::LineString($crate::wkt_internal!(@coords vec $dim $($tokens)+))
+    };
+    (@geom LINEARRING $dim:tt $body:tt) => {
+        $crate::types::LinearRing($crate::wkt_internal!(@geom LINESTRING $dim $body))
+    };
+    (@geom POLYGON $dim:tt ($($ring:tt),+ $(,)?)) => {
+        $crate::types::Polygon(::std::vec![$($crate::wkt_internal!(@geom LINESTRING $dim $ring)),+])
+    };
+    (@geom MULTIPOINT $dim:tt ($($point:tt),+ $(,)?)) => {
+        $crate::types::MultiPoint(::std::vec![$($crate::wkt_internal!(@geom POINT $dim $point)),+])
+    };
+    // Points without their own parentheses, e.g. `MULTIPOINT(1 2,3 4)`.
+    (@geom MULTIPOINT $dim:tt ($($tokens:tt)+)) => {
+        $crate::types::MultiPoint(
+            $crate::wkt_internal!(@coords vec $dim $($tokens)+)
+                .into_iter()
+                .map(|coord| $crate::types::Point(Some(coord)))
+                .collect(),
+        )
+    };
+    (@geom MULTILINESTRING $dim:tt ($($line_string:tt),+ $(,)?)) => {
+        $crate::types::MultiLineString(::std::vec![
+            $($crate::wkt_internal!(@geom LINESTRING $dim $line_string)),+
+        ])
+    };
+    (@geom MULTIPOLYGON $dim:tt ($($polygon:tt),+ $(,)?)) => {
+        $crate::types::MultiPolygon(::std::vec![
+            $($crate::wkt_internal!(@geom POLYGON $dim $polygon)),+
+        ])
+    };
+    (@geom GEOMETRYCOLLECTION $dim:tt ($($tokens:tt)+)) => {
+        $crate::types::GeometryCollection($crate::wkt_internal!(@members [] [] $($tokens)+))
+    };
+
+    (geo: $($wkt:tt)+) => {
+        $crate::wkt_geo_internal!($($wkt)+)
+    };
+    (borrowed: $($wkt:tt)+) => {
+        $crate::wkt_borrowed_internal!($($wkt)+)
+    };
+    (GEOMETRY $kind:ident $($wkt:tt)+) => {
+        $crate::wkt_internal!(@wkt $kind $crate::wkt_internal!($kind $($wkt)+))
+    };
+    ($kind:ident $body:tt) => {
+        $crate::wkt_internal!(@geom $kind [] $body)
+    };
+    ($kind:ident $dim:ident $body:tt) => {
+        $crate::wkt_internal!(@geom $kind [$dim] $body)
+    };
+}
+
+/// The `borrowed:` mode of `wkt!`, producing the slice-backed geometries of `crate::borrowed`.
+/// Points and coordinates are shared with `wkt_internal!`.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! wkt_borrowed_internal {
+    (GEOMETRY $($wkt:tt)+) => {
+        compile_error!("borrowed geometries have no `Wkt` enum")
+    };
+    (GEOMETRYCOLLECTION $($wkt:tt)+) => {
+        compile_error!("borrowed geometries have no GEOMETRYCOLLECTION")
+    };
+    ($kind:ident $body:tt) => {
+        $crate::wkt_borrowed_internal!(@geom $kind [] $body)
+    };
+    ($kind:ident $dim:ident $body:tt) => {
+        $crate::wkt_borrowed_internal!(@geom $kind [$dim] $body)
+    };
+
+    (@empty LINESTRING) => {
+        $crate::borrowed::LineString(&[])
+    };
+    (@empty LINEARRING) => {
+        $crate::borrowed::LineString(&[])
+    };
+    (@empty POLYGON) => {
+        $crate::borrowed::Polygon(&[])
+    };
+    (@empty MULTIPOINT) => {
+        $crate::borrowed::MultiPoint(&[])
+    };
+    (@empty MULTILINESTRING) => {
+        $crate::borrowed::MultiLineString(&[])
+    };
+    (@empty MULTIPOLYGON) => {
+        $crate::borrowed::MultiPolygon(&[])
+    };
+
+    (@geom POINT $dim:tt $body:tt) => {
+        $crate::wkt_internal!(@geom POINT $dim $body)
+    };
+    (@geom $kind:ident $dim:tt EMPTY) => {
+        $crate::wkt_borrowed_internal!(@empty $kind)
+    };
+    (@geom LINESTRING $dim:tt ($($tokens:tt)+)) => {
+        $crate::borrowed::LineString($crate::wkt_internal!(@coords slice $dim $($tokens)+))
+    };
+    (@geom LINEARRING $dim:tt $body:tt) => {
+        $crate::wkt_borrowed_internal!(@geom LINESTRING $dim $body)
+    };
+    (@geom POLYGON $dim:tt ($($ring:tt),+ $(,)?)) => {
+        $crate::borrowed::Polygon(&[$($crate::wkt_borrowed_internal!(@geom LINESTRING $dim $ring)),+])
+    };
+    (@geom MULTIPOINT $dim:tt ($($point:tt),+ $(,)?)) => {
+        $crate::borrowed::MultiPoint(&[$($crate::wkt_internal!(@geom POINT $dim $point)),+])
+    };
+    // Points without their own parentheses, e.g. `MULTIPOINT(1 2,3 4)`.
+    (@geom MULTIPOINT $dim:tt ($($tokens:tt)+)) => {
+        $crate::borrowed::MultiPoint($crate::wkt_internal!(@coords points $dim $($tokens)+))
+    };
+    (@geom MULTILINESTRING $dim:tt ($($line_string:tt),+ $(,)?)) => {
+        $crate::borrowed::MultiLineString(&[
+            $($crate::wkt_borrowed_internal!(@geom LINESTRING $dim $line_string)),+
+        ])
+    };
+    (@geom MULTIPOLYGON $dim:tt ($($polygon:tt),+ $(,)?)) => {
+        $crate::borrowed::MultiPolygon(&[
+            $($crate::wkt_borrowed_internal!(@geom POLYGON $dim $polygon)),+
+        ])
+    };
+}
+
+/// The `geo:` mode of `wkt!`, producing `geo_types` values. Coordinates are shared with
+/// `wkt_internal!`, using `[geo]` as their dimension.
+#[cfg(feature = "geo-types")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! wkt_geo_internal {
+    (@geometry POINT $geometry:expr) => {
+        $crate::__private::geo_types::Geometry::Point($geometry)
+    };
+    (@geometry LINESTRING $geometry:expr) => {
+        $crate::__private::geo_types::Geometry::LineString($geometry)
+    };
+    (@geometry LINEARRING $geometry:expr) => {
+        $crate::__private::geo_types::Geometry::LineString($geometry)
+    };
+    (@geometry POLYGON $geometry:expr) => {
+        $crate::__private::geo_types::Geometry::Polygon($geometry)
+    };
+    (@geometry MULTIPOINT $geometry:expr) => {
+        $crate::__private::geo_types::Geometry::MultiPoint($geometry)
+    };
+    (@geometry MULTILINESTRING $geometry:expr) => {
+        $crate::__private::geo_types::Geometry::MultiLineString($geometry)
+    };
+    (@geometry MULTIPOLYGON $geometry:expr) => {
+        $crate::__private::geo_types::Geometry::MultiPolygon($geometry)
+    };
+    (@geometry GEOMETRYCOLLECTION $geometry:expr) => {
+        $crate::__private::geo_types::Geometry::GeometryCollection($geometry)
+    };
+
+    (@members [$($member:expr,)*] [$($current:tt)+] $(,)?) => {
+        ::std::vec![
+            $($member,)*
+            $crate::__private::geo_types::Geometry::from($crate::wkt_geo_internal!($($current)+))
+        ]
+    };
+    (@members [$($member:expr,)*] [$($current:tt)+] , $($rest:tt)+) => {
+        $crate::wkt_geo_internal!(
+            @members [
+                $($member,)*
+                $crate::__private::geo_types::Geometry::from($crate::wkt_geo_internal!($($current)+)),
+            ] [] $($rest)+
+        )
+    };
+    (@members [$($member:expr,)*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::wkt_geo_internal!(@members [$($member,)*] [$($current)* $next] $($rest)*)
+    };
+
+    (GEOMETRY $kind:ident $($wkt:tt)+) => {
+        $crate::wkt_geo_internal!(@geometry $kind $crate::wkt_geo_internal!($kind $($wkt)+))
+    };
+    (POINT EMPTY) => {
+        compile_error!("geo-types has no empty point")
+    };
+    (POLYGON EMPTY) => {
+        $crate::__private::geo_types::Polygon::new(
+            $crate::__private::geo_types::LineString(::std::vec::Vec::new()),
+            ::std::vec::Vec::new(),
+        )
+    };
+    (LINEARRING EMPTY) => {
+        $crate::__private::geo_types::LineString(::std::vec::Vec::new())
+    };
+    ($kind:ident EMPTY) => {
+        $crate::wkt_geo_internal!(@empty $kind)
+    };
+    (@empty LINESTRING) => {
+        $crate::__private::geo_types::LineString(::std::vec::Vec::new())
+    };
+    (@empty MULTIPOINT) => {
+        $crate::__private::geo_types::MultiPoint(::std::vec::Vec::new())
+    };
+    (@empty MULTILINESTRING) => {
+        $crate::__private::geo_types::MultiLineString(::std::vec::Vec::new())
+    };
+    (@empty MULTIPOLYGON) => {
+        $crate::__private::geo_types::MultiPolygon(::std::vec::Vec::new())
+    };
+    (@empty GEOMETRYCOLLECTION) => {
+        $crate::__private::geo_types::GeometryCollection(::std::vec::Vec::new())
+    };
+    (POINT ($($tokens:tt)+)) => {
+        $crate::__private::geo_types::Point($crate::wkt_internal!(@coord [geo] [] $($tokens)+))
+    };
+    (LINESTRING ($($tokens:tt)+)) => {
+        $crate::__private::geo_types::LineString($crate::wkt_internal!(@coords vec [geo] $($tokens)+))
+    };
+    (LINEARRING $body:tt) => {
+        $crate::wkt_geo_internal!(LINESTRING $body)
+    };
+    (POLYGON ($exterior:tt $(, $interior:tt)* $(,)?)) => {
+        $crate::__private::geo_types::Polygon::new(
+            $crate::wkt_geo_internal!(LINESTRING $exterior),
+            ::std::vec![$($crate::wkt_geo_internal!(LINESTRING $interior)),*],
+        )
+    };
+    (MULTIPOINT ($($point:tt),+ $(,)?)) => {
+        $crate::__private::geo_types::MultiPoint(::std::vec![
+            $($crate::wkt_geo_internal!(POINT $point)),+
+        ])
+    };
+    (MULTIPOINT ($($tokens:tt)+)) => {
+        $crate::__private::geo_types::MultiPoint(
+            $crate::wkt_internal!(@coords vec [geo] $($tokens)+)
+                .into_iter()
+                .map($crate::__private::geo_types::Point)
+                .collect(),
+        )
+    };
+    (MULTILINESTRING ($($line_string:tt),+ $(,)?)) => {
+        $crate::__private::geo_types::MultiLineString(::std::vec![
+            $($crate::wkt_geo_internal!(LINESTRING $line_string)),+
+        ])
+    };
+    (MULTIPOLYGON ($($polygon:tt),+ $(,)?)) => {
+        $crate::__private::geo_types::MultiPolygon(::std::vec![
+            $($crate::wkt_geo_internal!(POLYGON $polygon)),+
+        ])
+    };
+    (GEOMETRYCOLLECTION ($($tokens:tt)+)) => {
+        $crate::__private::geo_types::GeometryCollection(
+            $crate::wkt_geo_internal!(@members [] [] $($tokens)+)
+        )
+    };
+    ($kind:ident $dim:ident $body:tt) => {
+        compile_error!("geo-types coordinates only have x and y")
+    };
+}
+
+#[cfg(not(feature = "geo-types"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! wkt_geo_internal {
+    ($($wkt:tt)*) => {
+        compile_error!("`wkt!(geo: ...)` requires the `geo-types` feature of `wkt`")
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{Coord, LineString, LinearRing, MultiPoint, Point};
+    #[cfg(feature = "proc-macro")]
+    use crate::wkt;
+    use crate::Wkt;
+    use std::str::FromStr;
+
+    fn assert_parses_to<G: Into<Wkt<f64>>>(geometry: G, wkt_str: &str) {
+        let expected = Wkt::<f64>::from_str(wkt_str).unwrap();
+        assert_eq!(geometry.into(), expected);
+    }
+
+    #[test]
+    fn literals() {
+        assert_parses_to(wkt!(POINT EMPTY), "POINT EMPTY");
+        assert_parses_to(wkt!(POINT(1.0 2.0)), "POINT(1 2)");
+        assert_parses_to(wkt!(POINT Z(1.0 2.0 3.0)), "POINT Z(1 2 3)");
+        assert_parses_to(wkt!(POINT M(1.0 2.0 4.0)), "POINT M(1 2 4)");
+        assert_parses_to(wkt!(POINT ZM(1.0 2.0 3.0 4.0)), "POINT ZM(1 2 3 4)");
+        assert_parses_to(
+            wkt!(LINESTRING(1.0 -2.0,-3.5 4.0)),
+            "LINESTRING(1 -2,-3.5 4)",
+        );
+        assert_parses_to(
+            wkt!(LINEARRING(0.0 0.0,1.0 0.0,1.0 1.0,0.0 0.0)),
+            "LINEARRING(0 0,1 0,1 1,0 0)",
+        );
+        assert_parses_to(
+            wkt!(POLYGON((0.0 0.0,4.0 0.0,4.0 4.0,0.0 0.0),(1.0 1.0,2.0 1.0,2.0 2.0,1.0 1.0))),
+            "POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,2 2,1 1))",
+        );
+        assert_parses_to(
+            wkt!(MULTIPOINT((1.0 2.0),(3.0 4.0))),
+            "MULTIPOINT((1 2),(3 4))",
+        );
+        assert_parses_to(
+            wkt!(MULTIPOINT Z(1.0 2.0 3.0,4.0 5.0 6.0)),
+            "MULTIPOINT Z(1 2 3,4 5 6)",
+        );
+        assert_parses_to(
+            wkt!(MULTILINESTRING((0.0 0.0,1.0 1.0),(2.0 2.0,3.0 3.0))),
+            "MULTILINESTRING((0 0,1 1),(2 2,3 3))",
+        );
+        assert_parses_to(
+            wkt!(MULTIPOLYGON(((0.0 0.0,1.0 0.0,1.0 1.0,0.0 0.0)))),
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0)))",
+        );
+        assert_eq!(
+            wkt!(MULTIPOINT((1.0 2.0),EMPTY)),
+            MultiPoint(vec![wkt!(POINT(1.0 2.0)), Point(None)])
+        );
+        assert_parses_to(
+            wkt!(GEOMETRYCOLLECTION(POINT Z(1.0 2.0 3.0),GEOMETRYCOLLECTION EMPTY,LINESTRING EMPTY)),
+            "GEOMETRYCOLLECTION(POINT Z(1 2 3),GEOMETRYCOLLECTION EMPTY,LINESTRING EMPTY)",
+        );
+    }
+
+    #[test]
+    fn empty_members_of_dimensioned_collections() {
+        assert_parses_to(
+            wkt!(GEOMETRYCOLLECTION Z(POINT Z EMPTY,LINESTRING Z(1.0 2.0 3.0,4.0 5.0 6.0))),
+            "GEOMETRYCOLLECTION Z(POINT Z EMPTY,LINESTRING Z(1 2 3,4 5 6))",
+        );
+        assert_parses_to(
+            wkt!(GEOMETRYCOLLECTION M(POLYGON EMPTY,POINT M(1.0 2.0 3.0),MULTIPOINT M EMPTY)),
+            "GEOMETRYCOLLECTION M(POLYGON EMPTY,POINT M(1 2 3),MULTIPOINT EMPTY)",
+        );
+        assert_parses_to(
+            wkt!(GEOMETRYCOLLECTION ZM(GEOMETRYCOLLECTION ZM EMPTY,POINT ZM(1.0 2.0 3.0 4.0))),
+            "GEOMETRYCOLLECTION ZM(GEOMETRYCOLLECTION EMPTY,POINT ZM(1 2 3 4))",
+        );
+    }
+
+    #[test]
+    fn expressions() {
+        let x = 1.0;
+        let offset = |value: f64| value + 10.0;
+        assert_eq!(
+            wkt!(POINT ZM(x -x (x + 1.0) {offset(x)})),
+            Point(Some(Coord {
+                x: 1.0,
+                y: -1.0,
+                z: Some(2.0),
+                m: Some(11.0),
+            }))
+        );
+        assert_parses_to(
+            wkt!(MULTILINESTRING((x 0.0,-1.0 x),(0.0 0.0,1.0 1.0))),
+            "MULTILINESTRING((1 0,-1 1),(0 0,1 1))",
+        );
+        assert_parses_to(
+            wkt!(GEOMETRYCOLLECTION(POINT(x x),MULTIPOINT M(x x x,0.0 0.0 0.0))),
+            "GEOMETRYCOLLECTION(POINT(1 1),MULTIPOINT M(1 1 1,0 0 0))",
+        );
+    }
+
+    #[test]
+    fn geometry_prefix() {
+        let wkt: Wkt<f64> =
+            wkt!(GEOMETRY POLYGON Z((0.0 0.0 1.0,1.0 0.0 1.0,0.0 1.0 1.0,0.0 0.0 1.0)));
+        assert_parses_to(wkt, "POLYGON Z((0 0 1,1 0 1,0 1 1,0 0 1))");
+        assert_eq!(wkt!(GEOMETRY POINT EMPTY), Wkt::<f64>::Point(Point(None)));
+    }
+
+    #[test]
+    fn const_geometries() {
+        const POINT: Wkt<f64> = wkt!(GEOMETRY POINT M(1.0 2.0 -3.0));
+        const RING: LinearRing<f64> = wkt!(LINEARRING EMPTY);
+        assert_parses_to(POINT, "POINT M(1 2 -3)");
+        assert_parses_to(RING, "LINEARRING EMPTY");
+    }
+
+    #[test]
+    fn borrowed_geometries() {
+        use crate::borrowed;
+        use crate::types::{MultiLineString, MultiPolygon, Polygon};
+
+        static LINE: borrowed::LineString<f64> = wkt!(borrowed: LINESTRING(-1.5 2.0,3.0 -4.0));
+        static RING: borrowed::LineString<f64> =
+            wkt!(borrowed: LINEARRING Z(0.0 0.0 1.0,1.0 1.0 1.0));
+        const POLYGON: borrowed::Polygon<f64> =
+            wkt!(borrowed: POLYGON((0.0 0.0,1.0 0.0,0.0 0.0),EMPTY));
+        const POINTS: borrowed::MultiPoint<f64> =
+            wkt!(borrowed: MULTIPOINT M(1.0 2.0 3.0,-4.0 5.0 6.0));
+        const PARENTHESIZED: borrowed::MultiPoint<f64> =
+            wkt!(borrowed: MULTIPOINT((1.0 2.0),EMPTY));
+        const LINES: borrowed::MultiLineString<f64> =
+            wkt!(borrowed: MULTILINESTRING((0.0 0.0,1.0 1.0),EMPTY));
+        const POLYGONS: borrowed::MultiPolygon<f64> =
+            wkt!(borrowed: MULTIPOLYGON(((0.0 0.0,1.0 0.0,0.0 0.0)),EMPTY));
+        const NOTHING: borrowed::MultiPolygon<f64> = wkt!(borrowed: MULTIPOLYGON Z EMPTY);
+        const POINT: Point<f64> = wkt!(borrowed: POINT(1.0 2.0));
+
+        assert_parses_to(LineString::from(LINE), "LINESTRING(-1.5 2,3 -4)");
+        assert_parses_to(LineString::from(RING), "LINESTRING Z(0 0 1,1 1 1)");
+        assert_parses_to(Polygon::from(POLYGON), "POLYGON((0 0,1 0,0 0),EMPTY)");
+        assert_parses_to(MultiPoint::from(POINTS), "MULTIPOINT M(1 2 3,-4 5 6)");
+        assert_parses_to(MultiPoint::from(PARENTHESIZED), "MULTIPOINT((1 2),EMPTY)");
+        assert_parses_to(
+            MultiLineString::from(LINES),
+            "MULTILINESTRING((0 0,1 1),EMPTY)",
+        );
+        assert_parses_to(
+            MultiPolygon::from(POLYGONS),
+            "MULTIPOLYGON(((0 0,1 0,0 0)),EMPTY)",
+        );
+        assert_parses_to(MultiPolygon::from(NOTHING), "MULTIPOLYGON EMPTY");
+        assert_parses_to(POINT, "POINT(1 2)");
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn geo_types() {
+        use geo_types::{line_string, point, polygon, Geometry, GeometryCollection, MultiPoint};
+
+        assert_eq!(wkt!(geo: POINT(1.0 -2.0)), point!(x: 1.0, y: -2.0));
+        assert_eq!(
+            wkt!(geo: LINEARRING(0.0 0.0,1.0 1.0,0.0 0.0)),
+            line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.0, y: 0.0)]
+        );
+        assert_eq!(
+            wkt!(geo: POLYGON((0.0 0.0,4.0 0.0,4.0 4.0,0.0 0.0),(1.0 1.0,2.0 1.0,2.0 2.0,1.0 1.0))),
+            polygon!(
+                exterior: [(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0)],
+                interiors: [[(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0)]],
+            )
+        );
+        assert_eq!(
+            wkt!(geo: MULTIPOINT(1 2,3 4)),
+            MultiPoint(vec![point!(x: 1, y: 2), point!(x: 3, y: 4)])
+        );
+        let x = 5.0;
+        assert_eq!(
+            wkt!(geo: GEOMETRYCOLLECTION(POINT(x x),POLYGON EMPTY)),
+            GeometryCollection(vec![
+                Geometry::Point(point!(x: 5.0, y: 5.0)),
+                Geometry::Polygon(polygon![]),
+            ])
+        );
+        assert_eq!(
+            wkt!(geo: GEOMETRY MULTILINESTRING EMPTY),
+            Geometry::<f64>::MultiLineString(geo_types::MultiLineString(vec![]))
+        );
+    }
+
+    #[test]
+    fn trailing_commas_and_comments() {
+        let x = 1.0;
+        let polygon = wkt! {
+            POLYGON(
+                // The exterior ring.
+                (0.0 0.0, 4.0 0.0, 4.0 4.0, 0.0 0.0,),
+                /* A hole, with a value from a variable. */
+                (x 1.0, 2.0 -1.0, 2.0 2.0, x 1.0,),
+            )
+        };
+        assert_parses_to(polygon, "POLYGON((0 0,4 0,4 4,0 0),(1 1,2 -1,2 2,1 1))");
+        assert_parses_to(
+            wkt!(GEOMETRYCOLLECTION(
+                MULTIPOINT Z((1.0 2.0 3.0),),
+                MULTILINESTRING((0.0 0.0, 1.0 1.0),),
+                MULTIPOLYGON(((0.0 0.0, 1.0 0.0, 1.0 1.0, 0.0 0.0)),),
+            )),
+            "GEOMETRYCOLLECTION(MULTIPOINT Z((1 2 3)),MULTILINESTRING((0 0,1 1)),\
+             MULTIPOLYGON(((0 0,1 0,1 1,0 0))))",
+        );
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn geo_types_trailing_commas() {
+        use geo_types::{line_string, polygon};
+
+        assert_eq!(
+            wkt!(geo: POLYGON((0.0 0.0, 1.0 0.0, 1.0 1.0, 0.0 0.0,),)),
+            polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0)]
+        );
+        assert_eq!(
+            wkt!(geo: GEOMETRYCOLLECTION(LINESTRING(0.0 0.0, -1.0 1.0,),))
+                .0
+                .pop(),
+            Some(line_string![(x: 0.0, y: 0.0), (x: -1.0, y: 1.0)].into())
+        );
+    }
+
+    #[cfg(feature = "proc-macro")]
+    #[test]
+    fn include_wkt() {
+        let expected = std::fs::read_to_string("benches/small.wkt").unwrap();
+        assert_parses_to(crate::include_wkt!("benches/small.wkt"), &expected);
+        assert_parses_to(crate::include_wkt!(GEOMETRY "benches/big.wkt"), &{
+            std::fs::read_to_string("benches/big.wkt").unwrap()
+        });
+
+        static SMALL: crate::borrowed::Polygon<f64> =
+            crate::include_wkt!(borrowed: "benches/small.wkt");
+        assert_parses_to(crate::types::Polygon::from(SMALL), &expected);
+    }
+
+    #[test]
+    fn integer_coordinates() {
+        let line_string: LineString<i32> = wkt!(LINESTRING(1 2,3 4));
+        assert_eq!(line_string.to_string(), "LINESTRING(1 2,3 4)");
+    }
+}
@@ -0,0 +1,183 @@
+use crate::coords_mut::CoordsMut;
+use crate::error::Error;
+use crate::types::{
+    Coord, GeometryCollection, LineString, LinearRing, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use crate::{Wkt, WktNum};
+
+impl<T> Wkt<T>
+where
+    T: WktNum,
+{
+    /// Removes the M values from this geometry, returning it along with the removed values in
+    /// coordinate order, e.g. to pass the geometry to a library that doesn't support M.
+    ///
+    /// Coordinates without an M value contribute nothing, so the values of a geometry without M
+    /// are empty. Use [`Wkt::with_m`] to re-attach them.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING ZM(0 0 5 10,1 1 6 20)").unwrap();
+    /// let (stripped, measures) = wkt.clone().strip_m();
+    /// assert_eq!(stripped.to_string(), "LINESTRING Z(0 0 5,1 1 6)");
+    /// assert_eq!(measures, [10., 20.]);
+    ///
+    /// assert_eq!(stripped.with_m(measures).unwrap(), wkt);
+    /// ```
+    pub fn strip_m(mut self) -> (Self, Vec<T>) {
+        let mut values = vec![];
+        self.coords_mut(&mut |coord| values.extend(coord.m.take()));
+        (self, values)
+    }
+
+    /// Sets the M value of every coordinate, in coordinate order, replacing any existing values.
+    ///
+    /// No values remove the M values instead, so that `with_m` undoes [`Wkt::strip_m`] on
+    /// geometries without M too. Otherwise, returns [`Error::MeasureCountMismatch`] unless there is
+    /// exactly one value per coordinate.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING(0 0,1 1)").unwrap();
+    /// let (stripped, measures) = wkt.clone().strip_m();
+    /// assert!(measures.is_empty());
+    /// assert_eq!(stripped.with_m(measures).unwrap(), wkt);
+    /// ```
+    pub fn with_m(mut self, values: impl IntoIterator<Item = T>) -> Result<Self, Error> {
+        let values: Vec<T> = values.into_iter().collect();
+        let mut coords = 0;
+        self.coords_mut(&mut |_| coords += 1);
+        if coords != values.len() && !values.is_empty() {
+            return Err(Error::MeasureCountMismatch {
+                coords,
+                values: values.len(),
+            });
+        }
+
+        let mut values = values.into_iter();
+        self.coords_mut(&mut |coord| coord.m = values.next());
+        Ok(self)
+    }
+}
+
+macro_rules! impl_third_ordinate_converters {
+    ($($type: ident),+) => {
+        $(
+            impl<T: WktNum> $type<T> {
+                /// Reinterprets the M value of every `XYM` coordinate as a Z value, making it
+                /// `XYZ`, e.g. for elevations exported as M. Coordinates with both or neither
+                /// value are left as they are.
+                pub fn m_to_z(mut self) -> Self {
+                    self.coords_mut(&mut |coord: &mut Coord<T>| {
+                        if coord.z.is_none() {
+                            coord.z = coord.m.take();
+                        }
+                    });
+                    self
+                }
+
+                /// Reinterprets the Z value of every `XYZ` coordinate as an M value, making it
+                /// `XYM`. Coordinates with both or neither value are left as they are.
+                pub fn z_to_m(mut self) -> Self {
+                    self.coords_mut(&mut |coord: &mut Coord<T>| {
+                        if coord.m.is_none() {
+                            coord.m = coord.z.take();
+                        }
+                    });
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_third_ordinate_converters!(
+    Wkt,
+    Coord,
+    Point,
+    LineString,
+    LinearRing,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn strip_m_from_collection() {
+        let wkt: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION(POINT M(1 2 3),POINT EMPTY,MULTIPOINT M((4 5 6)))")
+                .unwrap();
+        let (stripped, measures) = wkt.strip_m();
+        assert_eq!(
+            stripped.to_string(),
+            "GEOMETRYCOLLECTION(POINT(1 2),POINT EMPTY,MULTIPOINT((4 5)))"
+        );
+        assert_eq!(measures, [3., 6.]);
+
+        let (_, measures) = Wkt::<f64>::from_str("POINT(1 2)").unwrap().strip_m();
+        assert!(measures.is_empty());
+    }
+
+    #[test]
+    fn with_m_checks_count() {
+        let wkt: Wkt<f64> = Wkt::from_str("LINESTRING(0 0,1 1)").unwrap();
+        match wkt.clone().with_m([1.]) {
+            Err(Error::MeasureCountMismatch { coords, values }) => {
+                assert_eq!((coords, values), (2, 1))
+            }
+            other => panic!("expected a count mismatch, got {other:?}"),
+        }
+        assert_eq!(
+            wkt.with_m([1., 2.]).unwrap().to_string(),
+            "LINESTRING M(0 0 1,1 1 2)"
+        );
+    }
+
+    #[test]
+    fn with_m_undoes_strip_m_without_measures() {
+        for input in [
+            "POINT EMPTY",
+            "POINT(1 2)",
+            "GEOMETRYCOLLECTION(LINESTRING Z(0 0 1,1 1 2))",
+        ] {
+            let wkt: Wkt<f64> = Wkt::from_str(input).unwrap();
+            let (stripped, measures) = wkt.clone().strip_m();
+            assert_eq!(stripped.with_m(measures).unwrap(), wkt);
+        }
+
+        let wkt: Wkt<f64> = Wkt::from_str("POINT M(1 2 3)").unwrap();
+        assert_eq!(wkt.with_m([]).unwrap().to_string(), "POINT(1 2)");
+    }
+
+    #[test]
+    fn moves_the_third_ordinate() {
+        let wkt: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION(POINT M(1 2 3),LINESTRING ZM(0 0 1 2,1 1 3 4))")
+                .unwrap();
+        let wkt = wkt.m_to_z();
+        assert_eq!(
+            wkt.to_string(),
+            "GEOMETRYCOLLECTION Z(POINT Z(1 2 3),LINESTRING ZM(0 0 1 2,1 1 3 4))"
+        );
+        assert_eq!(
+            wkt.z_to_m().to_string(),
+            "GEOMETRYCOLLECTION M(POINT M(1 2 3),LINESTRING ZM(0 0 1 2,1 1 3 4))"
+        );
+
+        let point = Point::new(Coord::xyz(1, 2, 3)).z_to_m();
+        assert_eq!(point, Point::new(Coord::xym(1, 2, 3)));
+        assert_eq!(Coord::xy(1, 2).m_to_z(), Coord::xy(1, 2));
+    }
+}
@@ -0,0 +1,174 @@
+//! Storing coordinates parsed as `f64` in `f32`, to halve the memory they take.
+
+use std::str::FromStr;
+
+use crate::types::{
+    Coord, GeometryCollection, LineString, LinearRing, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use crate::Wkt;
+
+const ERROR: &str = "Unable to store a coordinate as f32 within the maximum error";
+
+/// Converts a geometry with `f64` coordinates into the same geometry with `f32` coordinates.
+trait IntoF32 {
+    type Output;
+
+    fn into_f32(self, max_error: f64) -> Result<Self::Output, &'static str>;
+}
+
+impl IntoF32 for f64 {
+    type Output = f32;
+
+    fn into_f32(self, max_error: f64) -> Result<f32, &'static str> {
+        let narrowed = self as f32;
+        // `NaN` stays `NaN` and passes, but a value too big for `f32` becomes infinite and fails.
+        let overflowed = narrowed.is_infinite() && self.is_finite();
+        if overflowed || (f64::from(narrowed) - self).abs() > max_error {
+            return Err(ERROR);
+        }
+        Ok(narrowed)
+    }
+}
+
+impl IntoF32 for Coord<f64> {
+    type Output = Coord<f32>;
+
+    fn into_f32(self, max_error: f64) -> Result<Coord<f32>, &'static str> {
+        Ok(Coord {
+            x: self.x.into_f32(max_error)?,
+            y: self.y.into_f32(max_error)?,
+            z: self.z.map(|z| z.into_f32(max_error)).transpose()?,
+            m: self.m.map(|m| m.into_f32(max_error)).transpose()?,
+        })
+    }
+}
+
+impl IntoF32 for Point<f64> {
+    type Output = Point<f32>;
+
+    fn into_f32(self, max_error: f64) -> Result<Point<f32>, &'static str> {
+        Ok(Point(self.0.map(|c| c.into_f32(max_error)).transpose()?))
+    }
+}
+
+impl IntoF32 for LinearRing<f64> {
+    type Output = LinearRing<f32>;
+
+    fn into_f32(self, max_error: f64) -> Result<LinearRing<f32>, &'static str> {
+        Ok(LinearRing(self.0.into_f32(max_error)?))
+    }
+}
+
+macro_rules! impl_into_f32_for_vec_wrapper {
+    ($($type: ident),+) => {
+        $(
+            impl IntoF32 for $type<f64> {
+                type Output = $type<f32>;
+
+                fn into_f32(self, max_error: f64) -> Result<$type<f32>, &'static str> {
+                    let items = self.0.into_iter().map(|item| item.into_f32(max_error));
+                    Ok($type(items.collect::<Result<_, _>>()?))
+                }
+            }
+        )+
+    };
+}
+
+impl_into_f32_for_vec_wrapper!(
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+impl IntoF32 for Wkt<f64> {
+    type Output = Wkt<f32>;
+
+    fn into_f32(self, max_error: f64) -> Result<Wkt<f32>, &'static str> {
+        Ok(match self {
+            Wkt::Point(g) => Wkt::Point(g.into_f32(max_error)?),
+            Wkt::LineString(g) => Wkt::LineString(g.into_f32(max_error)?),
+            Wkt::LinearRing(g) => Wkt::LinearRing(g.into_f32(max_error)?),
+            Wkt::Polygon(g) => Wkt::Polygon(g.into_f32(max_error)?),
+            Wkt::MultiPoint(g) => Wkt::MultiPoint(g.into_f32(max_error)?),
+            Wkt::MultiLineString(g) => Wkt::MultiLineString(g.into_f32(max_error)?),
+            Wkt::MultiPolygon(g) => Wkt::MultiPolygon(g.into_f32(max_error)?),
+            Wkt::GeometryCollection(g) => Wkt::GeometryCollection(g.into_f32(max_error)?),
+        })
+    }
+}
+
+impl Wkt<f64> {
+    /// Converts the coordinates to `f32`, failing if any of them would change by more than
+    /// `max_error`.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt = Wkt::<f64>::from_str("POINT (16777217 0.1)").unwrap();
+    /// assert!(wkt.clone().into_f32(0.5).is_err());
+    /// assert_eq!(wkt.into_f32(1.).unwrap().to_string(), "POINT(16777216 0.1)");
+    /// ```
+    pub fn into_f32(self, max_error: f64) -> Result<Wkt<f32>, &'static str> {
+        IntoF32::into_f32(self, max_error)
+    }
+}
+
+impl Wkt<f32> {
+    /// Parses WKT with `f64` precision and stores the coordinates as `f32`, failing if any of
+    /// them would change by more than `max_error`.
+    ///
+    /// This suits data that's only displayed, where `f32`'s precision is enough and halving the
+    /// memory of the coordinates matters. A `max_error` of `f64::INFINITY` skips the check, except
+    /// for coordinates too large for `f32`.
+    ///
+    /// ```
+    /// use wkt::Wkt;
+    ///
+    /// // About a metre, in degrees.
+    /// let wkt = Wkt::from_str_f32("POINT (-122.4194 37.7749)", 1e-5).unwrap();
+    /// assert_eq!(wkt.to_string(), "POINT(-122.4194 37.7749)");
+    /// assert!(Wkt::from_str_f32("POINT (-122.4194 37.7749)", 1e-7).is_err());
+    /// ```
+    pub fn from_str_f32(input: &str, max_error: f64) -> Result<Self, &'static str> {
+        Wkt::<f64>::from_str(input)?.into_f32(max_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_every_geometry_type() {
+        let input = "GEOMETRYCOLLECTION ZM(POINT ZM(1 2 3 4),LINESTRING ZM(1 2 3 4,5 6 7 8),\
+            LINEARRING ZM(0 0 0 0,1 0 0 0,1 1 0 0,0 0 0 0),POLYGON ZM EMPTY,\
+            MULTIPOINT ZM((1 2 3 4)),MULTILINESTRING ZM((1 2 3 4,5 6 7 8)),\
+            MULTIPOLYGON ZM(((0 0 0 0,1 0 0 0,1 1 0 0,0 0 0 0))),POINT ZM EMPTY)";
+        let wkt = Wkt::from_str_f32(input, 0.).unwrap();
+        assert_eq!(wkt, Wkt::<f32>::from_str(input).unwrap());
+    }
+
+    #[test]
+    fn checks_every_ordinate() {
+        assert!(Wkt::from_str_f32("POINT ZM (1 2 3 4)", 0.).is_ok());
+        assert!(Wkt::from_str_f32("POINT ZM (1 2 3 4.1)", 0.).is_err());
+        assert!(Wkt::from_str_f32("POINT ZM (1 2 3.1 4)", 0.).is_err());
+        assert!(Wkt::from_str_f32("POINT (1 1e39)", f64::INFINITY).is_err());
+        let nan = Coord {
+            x: f64::NAN,
+            y: 1.,
+            z: None,
+            m: None,
+        };
+        assert!(Wkt::Point(Point(Some(nan))).into_f32(0.).is_ok());
+        assert_eq!(
+            Wkt::from_str_f32("POINT (1)", 0.),
+            Err("Expected a number for the Y coordinate")
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::canonical_order::CanonicalOrd;
+use crate::types::{LineString, Polygon, Winding};
+use crate::{Wkt, WktNum};
+
+fn dedup_consecutive<T: WktNum>(line_string: &mut LineString<T>) {
+    line_string.0.dedup();
+}
+
+fn normalize_ring<T: WktNum>(ring: &mut LineString<T>, exterior: bool) {
+    dedup_consecutive(ring);
+    let coords = &mut ring.0;
+    if coords.is_empty() {
+        return;
+    }
+
+    // Work on the open ring, so the closing coordinate doesn't take part in the rotation.
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    let start = coords
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.canonical_cmp(b))
+        .map(|(i, _)| i)
+        .unwrap_or_default();
+    coords.rotate_left(start);
+
+    let expected = if exterior {
+        Winding::CounterClockwise
+    } else {
+        Winding::Clockwise
+    };
+    if ring.winding() == Some(expected.reversed()) {
+        // Keep the minimum coordinate first.
+        ring.0[1..].reverse();
+    }
+
+    ring.0.push(ring.0[0].clone());
+}
+
+fn normalize_polygon<T: WktNum>(polygon: &mut Polygon<T>) {
+    for (i, ring) in polygon.0.iter_mut().enumerate() {
+        normalize_ring(ring, i == 0);
+    }
+}
+
+impl<T> Wkt<T>
+where
+    T: WktNum,
+{
+    /// Rewrites this geometry into a canonical form, in place.
+    ///
+    /// - consecutive duplicate coordinates are removed from line strings and rings
+    /// - polygon rings and linear rings are closed
+    /// - rings are rotated to start at their smallest coordinate, comparing `x`, `y`, `z` then
+    ///   `m`
+    /// - exterior rings and linear rings are oriented counter-clockwise, and interior rings
+    ///   clockwise
+    ///
+    /// Two geometries describing the same shape in these different ways are equal after
+    /// normalization, so they also compare equal under [`Wkt::canonical_cmp`] and hash the same.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let mut a: Wkt<f64> = Wkt::from_str("POLYGON((1 1,1 0,0 0,0 1,1 1))").unwrap();
+    /// let mut b: Wkt<f64> = Wkt::from_str("POLYGON((0 0,1 0,1 0,1 1,0 1))").unwrap();
+    /// a.normalize();
+    /// b.normalize();
+    /// assert_eq!(a, b);
+    /// assert_eq!(a.to_string(), "POLYGON((0 0,1 0,1 1,0 1,0 0))");
+    /// ```
+    pub fn normalize(&mut self) {
+        match self {
+            Wkt::Point(_) | Wkt::MultiPoint(_) => (),
+            Wkt::LineString(line_string) => dedup_consecutive(line_string),
+            Wkt::LinearRing(ring) => normalize_ring(&mut ring.0, true),
+            Wkt::Polygon(polygon) => normalize_polygon(polygon),
+            Wkt::MultiLineString(multi_line_string) => {
+                multi_line_string.0.iter_mut().for_each(dedup_consecutive)
+            }
+            Wkt::MultiPolygon(multi_polygon) => {
+                multi_polygon.0.iter_mut().for_each(normalize_polygon)
+            }
+            Wkt::GeometryCollection(collection) => collection.0.iter_mut().for_each(Wkt::normalize),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn normalized(wkt_str: &str) -> String {
+        let mut wkt: Wkt<f64> = Wkt::from_str(wkt_str).unwrap();
+        wkt.normalize();
+        wkt.to_string()
+    }
+
+    #[test]
+    fn orients_rings() {
+        assert_eq!(
+            normalized("POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,4 2,4 4,2 4,2 2))"),
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,2 4,4 4,4 2,2 2))"
+        );
+    }
+
+    #[test]
+    fn closes_and_rotates_rings() {
+        assert_eq!(
+            normalized("MULTIPOLYGON(((5 5,6 5,5 6)),((1 2,0 2,0 1)))"),
+            "MULTIPOLYGON(((5 5,6 5,5 6,5 5)),((0 1,1 2,0 2,0 1)))"
+        );
+    }
+
+    #[test]
+    fn dedups_line_strings() {
+        assert_eq!(
+            normalized("GEOMETRYCOLLECTION(LINESTRING(0 0,0 0,1 1,1 1,0 0),POINT(1 1))"),
+            "GEOMETRYCOLLECTION(LINESTRING(0 0,1 1,0 0),POINT(1 1))"
+        );
+    }
+
+    #[test]
+    fn keeps_empty_rings() {
+        assert_eq!(normalized("POLYGON EMPTY"), "POLYGON EMPTY");
+    }
+}
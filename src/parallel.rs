@@ -0,0 +1,182 @@
+//! Parsing the members of large collections in parallel, with the `rayon` feature.
+
+use std::str::FromStr;
+
+use rayon::prelude::*;
+
+use crate::tokenizer::{Token, Tokens};
+use crate::types::{Dimension, GeometryCollection, GeometryType, MultiPolygon, Polygon};
+use crate::{FromTokens, Wkt, WktNum};
+
+/// Inputs shorter than this, in bytes, are parsed on the current thread.
+const MIN_PARALLEL_LEN: usize = 1 << 16;
+
+impl<T> Wkt<T>
+where
+    T: WktNum + FromStr + Default + Send,
+{
+    /// Like [`Wkt::from_str`], but parses the members of a large `MULTIPOLYGON` or
+    /// `GEOMETRYCOLLECTION` on multiple threads.
+    ///
+    /// The input is first split at the commas between top-level members, which only needs a scan
+    /// for balanced parentheses, and each member is then parsed on the rayon thread pool. Other
+    /// geometry types, and inputs under 64 KiB, are parsed on the current thread. The result and
+    /// any error are the same as from [`Wkt::from_str`].
+    ///
+    /// ```
+    /// use wkt::Wkt;
+    ///
+    /// let polygons = vec!["((0 0, 1 0, 1 1, 0 0))"; 10_000].join(",");
+    /// let input = format!("MULTIPOLYGON ({polygons})");
+    /// let wkt: Wkt<f64> = Wkt::from_str_parallel(&input).unwrap();
+    /// assert_eq!(wkt.into_multi_polygon().unwrap().0.len(), 10_000);
+    /// ```
+    pub fn from_str_parallel(input: &str) -> Result<Self, &'static str> {
+        parse(input, MIN_PARALLEL_LEN)
+    }
+}
+
+fn parse<T>(input: &str, min_len: usize) -> Result<Wkt<T>, &'static str>
+where
+    T: WktNum + FromStr + Default + Send,
+{
+    if input.len() >= min_len {
+        // Errors are rare, so rather than reproducing the serial parser's error for each way a
+        // member can be invalid, its error is found by parsing again.
+        if let Some(Ok(wkt)) = parse_members(input) {
+            return Ok(wkt);
+        }
+    }
+    Wkt::from_str(input)
+}
+
+/// Parses the members of a collection in parallel, or returns `None` if the input isn't a
+/// non-empty `MULTIPOLYGON` or `GEOMETRYCOLLECTION` with balanced parentheses.
+fn parse_members<T>(input: &str) -> Option<Result<Wkt<T>, &'static str>>
+where
+    T: WktNum + FromStr + Default + Send,
+{
+    let open = input.find('(')?;
+    let (geometry_type, dim) = parse_header(&input[..open])?;
+    let members = split_members(&input[open + 1..])?;
+
+    let result = match geometry_type {
+        GeometryType::MultiPolygon => members
+            .into_par_iter()
+            .map(|member| {
+                parse_member(member, |tokens| {
+                    <Polygon<T> as FromTokens<T>>::from_tokens_with_parens(tokens, dim)
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(|polygons| Wkt::MultiPolygon(MultiPolygon(polygons))),
+        GeometryType::GeometryCollection => members
+            .into_par_iter()
+            .map(|member| {
+                parse_member(member, |tokens| match tokens.next() {
+                    Some(Token::Word(word)) => Wkt::from_word_and_tokens(word, tokens),
+                    _ => Err("Expected a word in GEOMETRYCOLLECTION"),
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(|geometries| Wkt::GeometryCollection(GeometryCollection(geometries))),
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// Reads a header like `MULTIPOLYGON Z`, everything before the first `(`.
+fn parse_header(header: &str) -> Option<(GeometryType, Dimension)> {
+    let mut words = header.split_whitespace();
+    let (geometry_type, dim) = GeometryType::from_keyword(words.next()?)?;
+    let dim = match (dim, words.next()) {
+        (Some(dim), None) => dim,
+        (None, Some(tag)) => Dimension::from_suffix(tag)?,
+        (None, None) => Dimension::XY,
+        (Some(_), Some(_)) => return None,
+    };
+    match words.next() {
+        Some(_) => None,
+        None => Some((geometry_type, dim)),
+    }
+}
+
+/// Splits the body of a collection, after its opening `(`, at the commas between members, up to
+/// the matching `)`.
+fn split_members(body: &str) -> Option<Vec<&str>> {
+    let mut members = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, byte) in body.bytes().enumerate() {
+        match byte {
+            b'(' => depth += 1,
+            b')' if depth == 0 => {
+                members.push(&body[start..i]);
+                return Some(members);
+            }
+            b')' => depth -= 1,
+            b',' if depth == 0 => {
+                members.push(&body[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parses one member, which must use up all of its tokens.
+fn parse_member<G>(
+    member: &str,
+    parse: impl FnOnce(&mut Tokens<'_>) -> Result<G, &'static str>,
+) -> Result<G, &'static str> {
+    let mut tokens = Tokens::from_str(member);
+    let geometry = parse(&mut tokens)?;
+    match tokens.next() {
+        None => Ok(geometry),
+        Some(_) => Err("Unexpected token after member"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_same(input: &str) {
+        assert_eq!(parse::<f64>(input, 0), Wkt::from_str(input), "{input}");
+    }
+
+    #[test]
+    fn matches_serial_parser() {
+        assert_same(include_str!("../benches/small.wkt"));
+        assert_same(
+            "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5), (1 1, 2 2, 1 1)))",
+        );
+        assert_same("MULTIPOLYGON Z (((0 0 0, 1 0 0, 1 1 0, 0 0 0)))");
+        assert_same("MULTIPOLYGONM (((0 0 0, 1 0 0, 1 1 0, 0 0 0)), EMPTY)");
+        assert_same("GEOMETRYCOLLECTION (POINT (1 2), MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0))))");
+        assert_same(
+            "GEOMETRYCOLLECTION (GEOMETRYCOLLECTION (POINT (1 2), POINT (3 4)), POINT EMPTY)",
+        );
+        assert_same("MULTIPOLYGON EMPTY");
+        assert_same("POLYGON ((0 0, 1 0, 1 1, 0 0))");
+    }
+
+    #[test]
+    fn errors_match_serial_parser() {
+        assert_same("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)) 5, ((5 5, 6 5, 6 6, 5 5)))");
+        assert_same("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 x)))");
+        assert_same("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0))");
+        assert_same("GEOMETRYCOLLECTION (POINT (1 2), (3 4))");
+        assert_same("GEOMETRYCOLLECTION (POINT (1 2),)");
+    }
+
+    #[test]
+    fn splits_top_level_members() {
+        assert_eq!(
+            split_members("((0 0, 1 1)), (( 2 2)) ) trailing"),
+            Some(vec!["((0 0, 1 1))", " (( 2 2)) "])
+        );
+        assert_eq!(split_members("((0 0)"), None);
+    }
+}
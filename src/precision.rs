@@ -0,0 +1,218 @@
+//! Inspecting the precision and magnitude of coordinates, e.g. to find values written with more
+//! decimals than the data can have, or coordinates in degrees mixed with ones in meters.
+
+use std::fmt::{self, Write};
+
+use crate::coords_mut::CoordsMut;
+use crate::error::Error;
+use crate::scan::{scan, Visitor};
+use crate::types::Coord;
+use crate::{Wkt, WktNum};
+
+/// The decimals and magnitudes of the coordinates of a geometry, from [`precision`] or
+/// [`Wkt::precision`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Precision {
+    /// The most decimal places of any value, including those implied by an exponent, so `1.25e-3`
+    /// has 5.
+    pub max_decimals: usize,
+    pub x: Option<Magnitude>,
+    pub y: Option<Magnitude>,
+    pub z: Option<Magnitude>,
+    pub m: Option<Magnitude>,
+}
+
+/// The smallest and largest absolute value of one ordinate, like `x`, across coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Magnitude {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Precision {
+    fn add_text(&mut self, text: &str) {
+        self.max_decimals = self.max_decimals.max(decimals(text));
+    }
+
+    fn add_coord(&mut self, coord: &Coord<f64>) {
+        add_magnitude(&mut self.x, Some(coord.x));
+        add_magnitude(&mut self.y, Some(coord.y));
+        add_magnitude(&mut self.z, coord.z);
+        add_magnitude(&mut self.m, coord.m);
+    }
+}
+
+fn add_magnitude(magnitude: &mut Option<Magnitude>, value: Option<f64>) {
+    // `NaN` values are ignored, as `f64::min` and `f64::max` do.
+    let Some(value) = value.map(f64::abs).filter(|value| !value.is_nan()) else {
+        return;
+    };
+    match magnitude {
+        Some(magnitude) => {
+            magnitude.min = magnitude.min.min(value);
+            magnitude.max = magnitude.max.max(value);
+        }
+        None => {
+            *magnitude = Some(Magnitude {
+                min: value,
+                max: value,
+            })
+        }
+    }
+}
+
+/// The decimal places of a number written like `-12.50e-1`. Exponents too long for an `i64`
+/// saturate, as does the result.
+fn decimals(text: &str) -> usize {
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(i) => (&text[..i], parse_exponent(&text[i + 1..])),
+        None => (text, 0),
+    };
+    let fraction = mantissa
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len());
+    let decimals = i64::try_from(fraction)
+        .unwrap_or(i64::MAX)
+        .saturating_sub(exponent);
+    usize::try_from(decimals.max(0)).unwrap_or(usize::MAX)
+}
+
+fn parse_exponent(text: &str) -> i64 {
+    text.parse().unwrap_or_else(|_| {
+        // The scanner has already checked the number, so only an overflow can fail here.
+        if text.starts_with('-') {
+            i64::MIN
+        } else {
+            i64::MAX
+        }
+    })
+}
+
+/// Validates WKT and reports the decimals and magnitudes of its coordinates, as written, without
+/// building the geometry.
+///
+/// Trailing zeros count, so `1.500` has 3 decimals.
+///
+/// ```
+/// let precision = wkt::precision("LINESTRING (13.4050 52.52, 2.3522 48.856613)").unwrap();
+/// assert_eq!(precision.max_decimals, 6);
+/// let x = precision.x.unwrap();
+/// assert_eq!((x.min, x.max), (2.3522, 13.405));
+/// assert_eq!(precision.z, None);
+/// ```
+pub fn precision(input: &str) -> Result<Precision, Error> {
+    let mut precision = Precision::default();
+    scan(input, &mut precision)?;
+    Ok(precision)
+}
+
+impl Visitor for Precision {
+    fn coord(&mut self, coord: Coord<f64>) -> Result<(), Error> {
+        self.add_coord(&coord);
+        Ok(())
+    }
+
+    fn number(&mut self, text: &str) -> Result<(), Error> {
+        self.add_text(text);
+        Ok(())
+    }
+}
+
+impl<T: WktNum + fmt::Display> Wkt<T> {
+    /// Reports the decimals and magnitudes of the coordinates, see [`precision`]. Decimals are
+    /// counted in each value as `Display` writes it, so `0.1 + 0.2` has 17.
+    pub fn precision(&self) -> Precision {
+        let mut precision = Precision::default();
+        let mut text = String::new();
+        // The walk doesn't change the geometry, but only a mutable one exists.
+        let mut geometry = self.clone();
+        geometry.coords_mut(&mut |coord: &mut Coord<T>| {
+            for value in [Some(coord.x), Some(coord.y), coord.z, coord.m]
+                .into_iter()
+                .flatten()
+            {
+                text.clear();
+                let _ = write!(text, "{value}");
+                precision.add_text(&text);
+            }
+            let to_f64 = |value: T| value.to_f64().unwrap_or(f64::NAN);
+            precision.add_coord(&Coord {
+                x: to_f64(coord.x),
+                y: to_f64(coord.y),
+                z: coord.z.map(to_f64),
+                m: coord.m.map(to_f64),
+            });
+        });
+        precision
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn counts_decimals() {
+        for (text, expected) in [
+            ("1", 0),
+            ("-1.50", 2),
+            (".125", 3),
+            ("1.25e-3", 5),
+            ("1.25E2", 0),
+            ("1.2345e+2", 2),
+            ("1.", 0),
+        ] {
+            assert_eq!(decimals(text), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn inspects_text() {
+        let inspected =
+            precision("GEOMETRYCOLLECTION (POINT M (-500000.5 10 3.000000000000001), POINT EMPTY)")
+                .unwrap();
+        assert_eq!(
+            inspected,
+            Precision {
+                max_decimals: 15,
+                x: Some(Magnitude {
+                    min: 500000.5,
+                    max: 500000.5,
+                }),
+                y: Some(Magnitude { min: 10., max: 10. }),
+                z: None,
+                m: Some(Magnitude {
+                    min: 3.000000000000001,
+                    max: 3.000000000000001,
+                }),
+            }
+        );
+        assert_eq!(precision("POINT EMPTY").unwrap(), Precision::default());
+        assert!(precision("POINT (1.5)").is_err());
+    }
+
+    #[test]
+    fn saturates_huge_exponents() {
+        for text in [
+            "POINT (1e-9223372036854775808 0)",
+            "POINT (1e-99999999999999999999 0)",
+        ] {
+            let max_decimals = precision(text).unwrap().max_decimals;
+            assert_eq!(max_decimals, i64::MAX as usize, "{text}");
+        }
+        assert_eq!(decimals("1e99999999999999999999"), 0);
+    }
+
+    #[test]
+    fn inspects_geometries() {
+        let mut wkt: Wkt<f64> = Wkt::from_str("LINESTRING (0.1 -20, 1.5 3)").unwrap();
+        assert_eq!(wkt.precision().max_decimals, 1);
+        assert_eq!(wkt.precision().y, Some(Magnitude { min: 3., max: 20. }));
+        wkt.for_each_coord_mut(|coord| coord.x += 0.2);
+        assert_eq!(wkt.precision().max_decimals, 17);
+
+        let wkt: Wkt<i32> = Wkt::from_str("POINT (1 2)").unwrap();
+        assert_eq!(wkt.precision().max_decimals, 0);
+    }
+}
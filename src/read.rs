@@ -0,0 +1,91 @@
+//! Loading files of WKT geometries, with the `rayon` feature.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use rayon::prelude::*;
+
+use crate::{Wkt, WktNum};
+
+/// Reads a file with one WKT geometry per line, parsing the lines on the rayon thread pool.
+///
+/// The geometries are returned in the order of their lines. Blank lines are skipped, and lines
+/// may end with `\n` or `\r\n`. A line that doesn't parse gives an [`io::ErrorKind::InvalidData`]
+/// error naming the first such line, counting from 1.
+///
+/// ```
+/// # fn main() -> std::io::Result<()> {
+/// let path = std::env::temp_dir().join("wkt-par-read-lines-example.wkt");
+/// std::fs::write(&path, "POINT (1 2)\n\nLINESTRING (0 0, 1 1)\n")?;
+///
+/// let geometries: Vec<wkt::Wkt<f64>> = wkt::read::par_read_lines(&path)?;
+/// assert_eq!(geometries.len(), 2);
+/// assert_eq!(geometries[1].to_string(), "LINESTRING(0 0,1 1)");
+/// # std::fs::remove_file(&path)
+/// # }
+/// ```
+pub fn par_read_lines<T>(path: impl AsRef<Path>) -> io::Result<Vec<Wkt<T>>>
+where
+    T: WktNum + FromStr + Default + Send,
+{
+    parse_lines(&fs::read_to_string(path)?)
+}
+
+fn parse_lines<T>(text: &str) -> io::Result<Vec<Wkt<T>>>
+where
+    T: WktNum + FromStr + Default + Send,
+{
+    let lines: Vec<(usize, &str)> = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect();
+    // Results are collected in order, so that the error is always from the first invalid line.
+    let results: Vec<_> = lines
+        .par_iter()
+        .map(|&(index, line)| Wkt::from_str(line).map_err(|err| (index, err)))
+        .collect();
+    results
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .map_err(|(index, err)| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {err}", index + 1),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_line_order() {
+        let text: String = (0..1000).map(|i| format!("POINT ({i} {i})\r\n")).collect();
+        let geometries = parse_lines::<f64>(&text).unwrap();
+        assert_eq!(geometries.len(), 1000);
+        for (i, geometry) in geometries.iter().enumerate() {
+            assert_eq!(geometry.to_string(), format!("POINT({i} {i})"));
+        }
+    }
+
+    #[test]
+    fn reports_first_invalid_line() {
+        let text = "POINT (1 2)\n\n  \nPOINT (1)\nPOINT (x y)\n";
+        let err = parse_lines::<f64>(text).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            "line 4: Expected a number for the Y coordinate"
+        );
+    }
+
+    #[test]
+    fn missing_file() {
+        let err = par_read_lines::<f64>("does/not/exist.wkt").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
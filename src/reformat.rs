@@ -0,0 +1,216 @@
+use std::fmt::Write;
+
+use crate::error::Error;
+use crate::scan::{scan, Visitor};
+use crate::types::{Coord, Dimension, GeometryType};
+
+/// Validates WKT and writes it to `writer` in canonical form, without building a [`Wkt`].
+///
+/// The canonical form has uppercase keywords, a space only before the dimension tag and between
+/// the values of a coordinate, and numbers as `f64` prints them, which is how [`Wkt`]'s
+/// `Display` writes geometries without empty parts. `EMPTY` geometries are written without a
+/// dimension tag, since they have no coordinates to carry one, so `POINT Z EMPTY` becomes
+/// `POINT EMPTY` like `Display` writes it. Empty members stay where they are, so `POLYGON(EMPTY)`
+/// isn't shortened to `POLYGON EMPTY`, and a non-empty `GEOMETRYCOLLECTION` keeps the tag it was
+/// written with. Numbers too large for an `f64` are an error, since they would
+/// be written as `inf`, which isn't WKT.
+///
+/// Output is written while the input is scanned, so on error part of it may already have been
+/// written.
+///
+/// [`Wkt`]: crate::Wkt
+///
+/// ```
+/// let mut canonical = String::new();
+/// wkt::reformat("multipoint z (1 2 3.0, (4 5 +6e1))", &mut canonical).unwrap();
+/// assert_eq!(canonical, "MULTIPOINT Z((1 2 3),(4 5 60))");
+///
+/// assert!(wkt::reformat("POINT (1)", &mut String::new()).is_err());
+/// ```
+pub fn reformat(input: &str, writer: &mut impl Write) -> Result<(), Error> {
+    scan(input, &mut Canonical { writer, tag: None })
+}
+
+/// Validates WKT and returns it in the canonical form [`reformat`] writes, without building a
+/// [`Wkt`]. Inputs that are the same geometry written differently give the same string, which
+/// makes it cheap to deduplicate or diff WKT.
+///
+/// [`Wkt`]: crate::Wkt
+///
+/// ```
+/// let a = wkt::normalize_str("point(1.50 -2e0)").unwrap();
+/// let b = wkt::normalize_str("POINT ( 1.5 -2 )").unwrap();
+/// assert_eq!(a, "POINT(1.5 -2)");
+/// assert_eq!(a, b);
+/// ```
+pub fn normalize_str(input: &str) -> Result<String, Error> {
+    // The canonical form is rarely longer than the input.
+    let mut output = String::with_capacity(input.len());
+    reformat(input, &mut output)?;
+    Ok(output)
+}
+
+struct Canonical<'w, W> {
+    writer: &'w mut W,
+    /// The dimension of the geometry whose keyword was just written, which is only written when
+    /// its parentheses open, as `EMPTY` geometries have no tag.
+    tag: Option<Dimension>,
+}
+
+impl<W: Write> Visitor for Canonical<'_, W> {
+    fn geometry(&mut self, geometry_type: GeometryType, dim: Dimension) -> Result<(), Error> {
+        write!(self.writer, "{geometry_type}")?;
+        self.tag = Some(dim);
+        Ok(())
+    }
+
+    fn empty(&mut self) -> Result<(), Error> {
+        // `EMPTY` is separated by a space after a keyword, but not as a member, like `(EMPTY,`.
+        let empty = if self.tag.take().is_some() {
+            " EMPTY"
+        } else {
+            "EMPTY"
+        };
+        Ok(self.writer.write_str(empty)?)
+    }
+
+    fn open(&mut self) -> Result<(), Error> {
+        if let Some(dim) = self.tag.take().filter(|dim| *dim != Dimension::XY) {
+            write!(self.writer, " {}", dim.suffix())?;
+        }
+        Ok(self.writer.write_char('(')?)
+    }
+
+    fn close(&mut self) -> Result<(), Error> {
+        Ok(self.writer.write_char(')')?)
+    }
+
+    fn separator(&mut self) -> Result<(), Error> {
+        Ok(self.writer.write_char(',')?)
+    }
+
+    fn coord(&mut self, coord: Coord<f64>) -> Result<(), Error> {
+        let values = [Some(coord.x), Some(coord.y), coord.z, coord.m];
+        if !values.into_iter().flatten().all(f64::is_finite) {
+            return Err(Error::InvalidWkt("Number is out of range for an f64"));
+        }
+        write!(self.writer, "{} {}", coord.x, coord.y)?;
+        for value in [coord.z, coord.m].into_iter().flatten() {
+            write!(self.writer, " {value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Wkt;
+    use std::str::FromStr;
+
+    #[test]
+    fn matches_display() {
+        for input in [
+            "POINT (1.0 +2e3)",
+            "point zm (1 2 3 4)",
+            "LINESTRINGM (1 2 3, 4 5 6)",
+            "LINEARRING (0 0, 1 0, 1 1, 0 0)",
+            "POLYGON ((0 0, 10 0, 10 10, 0 0), (1 1, 2 1, 2 2, 1 1))",
+            "MULTIPOINT (1 2, (3 4))",
+            "MULTIPOINT ((1 2), EMPTY)",
+            "MULTILINESTRING Z ((1 2 3, 4 5 6), (7 8 9, 1 2 3))",
+            "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))",
+            "GEOMETRYCOLLECTION (POINT (1 2), GEOMETRYCOLLECTION EMPTY, LINESTRING (1 2, -3 .5))",
+            "POINT EMPTY",
+            "POINT Z EMPTY",
+            "LINESTRING ZM EMPTY",
+            include_str!("../benches/small.wkt"),
+        ] {
+            let wkt = Wkt::<f64>::from_str(input).unwrap();
+            assert_eq!(normalize_str(input).unwrap(), wkt.to_string(), "{input}");
+        }
+    }
+
+    #[test]
+    fn drops_tags_of_empty_geometries_and_keeps_empty_members() {
+        assert_eq!(normalize_str("POINT Z EMPTY").unwrap(), "POINT EMPTY");
+        assert_eq!(normalize_str("point zm empty").unwrap(), "POINT EMPTY");
+        assert_eq!(
+            normalize_str("polygon (EMPTY, (0 0, 1 0, 1 1, 0 0))").unwrap(),
+            "POLYGON(EMPTY,(0 0,1 0,1 1,0 0))"
+        );
+        assert_eq!(
+            normalize_str("MULTIPOLYGON M (EMPTY)").unwrap(),
+            "MULTIPOLYGON M(EMPTY)"
+        );
+        assert_eq!(
+            normalize_str("GEOMETRYCOLLECTION Z (POINT Z EMPTY)").unwrap(),
+            "GEOMETRYCOLLECTION Z(POINT EMPTY)"
+        );
+        assert_eq!(
+            normalize_str("GEOMETRYCOLLECTION M EMPTY").unwrap(),
+            "GEOMETRYCOLLECTION EMPTY"
+        );
+    }
+
+    #[test]
+    fn output_round_trips() {
+        for input in [
+            "point (1.50 -2e0)",
+            "POINT Z EMPTY",
+            "polygon (EMPTY, (0 0, 1 0, 1 1, 0 0))",
+            "MULTIPOINT M (1 2 3, EMPTY)",
+            "GEOMETRYCOLLECTION Z (POINT Z (1 2 3), MULTIPOLYGON Z EMPTY)",
+            "LINESTRING (1.7976931348623157e308 -1.7976931348623157e308, 5e-324 1e-400)",
+            "POINT (123456789012345678901234567890 0.000000000000000000001)",
+            include_str!("../benches/small.wkt"),
+        ] {
+            let normalized = normalize_str(input).unwrap();
+            crate::check_syntax(&normalized).unwrap();
+            Wkt::<f64>::from_str(&normalized).unwrap();
+            assert_eq!(normalize_str(&normalized).unwrap(), normalized, "{input}");
+        }
+        for out_of_range in ["POINT (1e309 0)", "LINESTRING (0 0, 1 -2e400)"] {
+            assert!(normalize_str(out_of_range).is_err(), "{out_of_range}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_wkt() {
+        for (input, message) in [
+            ("", "Invalid WKT format"),
+            ("POINT", "End of stream"),
+            ("POINT (1)", "Expected a number for the Y coordinate"),
+            ("POINT Z (1 2)", "Expected a number for the Z coordinate"),
+            ("POINT (1 2 3)", "Missing closing parenthesis for type"),
+            ("POINT (1 x)", "Expected a number for the Y coordinate"),
+            (
+                "POINT (1 2e)",
+                "Unable to parse input number as the desired output type",
+            ),
+            (
+                "LINESTRING (1 2, 3 4",
+                "Missing closing parenthesis for type",
+            ),
+            (
+                "GEOMETRYCOLLECTION ((1 2))",
+                "Expected a word in GEOMETRYCOLLECTION",
+            ),
+            ("CIRCULARSTRING (0 0, 1 1, 2 0)", "Invalid type encountered"),
+            (
+                "POINT (1 2) POINT (3 4)",
+                "Unexpected token after the geometry",
+            ),
+            ("POINT (1e400 2)", "Number is out of range for an f64"),
+            (
+                "POINT ZM (1 2 3 -1e309)",
+                "Number is out of range for an f64",
+            ),
+        ] {
+            match normalize_str(input) {
+                Err(Error::InvalidWkt(found)) => assert_eq!(found, message, "{input}"),
+                other => panic!("{input}: expected an error, found {other:?}"),
+            }
+        }
+    }
+}
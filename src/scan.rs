@@ -0,0 +1,243 @@
+//! A validating walk over WKT tokens that reports the structure to a [`Visitor`] instead of
+//! building a geometry.
+
+use crate::error::Error;
+use crate::infer_geom_dimension;
+use crate::tokenizer::{parse_number, Token, Tokens};
+use crate::types::{Coord, Dimension, GeometryType};
+
+/// Receives the structure of a WKT string from [`scan`], in input order.
+///
+/// Parentheses are reported as they are in the canonical form, so a `MULTIPOINT (1 2, 3 4)`
+/// reports each point in its own `open` and `close`.
+///
+/// Every method does nothing by default, so implementations only override what they look at.
+pub(crate) trait Visitor {
+    /// A geometry's keyword, with the dimension it declares.
+    fn geometry(&mut self, _geometry_type: GeometryType, _dim: Dimension) -> Result<(), Error> {
+        Ok(())
+    }
+    /// `EMPTY`, in place of a geometry's or a member's parentheses.
+    fn empty(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+    fn open(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+    fn close(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+    /// The comma between two coordinates or members.
+    fn separator(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+    fn coord(&mut self, _coord: Coord<f64>) -> Result<(), Error> {
+        Ok(())
+    }
+    /// The text of each value of a coordinate, as written, before the coordinate itself.
+    fn number(&mut self, _text: &str) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Checks that `input` is a single WKT geometry, reporting its structure to `visitor`.
+///
+/// This accepts what [`Wkt::from_str`](crate::Wkt) accepts, except that nothing may follow the
+/// geometry.
+pub(crate) fn scan(input: &str, visitor: &mut impl Visitor) -> Result<(), Error> {
+    let mut scanner = Scanner {
+        tokens: Tokens::from_str(input),
+        visitor,
+    };
+    match scanner.tokens.next() {
+        Some(Token::Word(word)) => scanner.geometry(word)?,
+        _ => return Err(Error::InvalidWkt("Invalid WKT format")),
+    }
+    match scanner.tokens.next() {
+        None => Ok(()),
+        Some(_) => Err(Error::InvalidWkt("Unexpected token after the geometry")),
+    }
+}
+
+/// Checks the syntax of WKT without building the geometry, returning the first error.
+///
+/// This accepts what [`Wkt::from_str`](crate::Wkt) accepts, except that nothing may follow the
+/// geometry. Nothing is allocated, so it's cheap to gate input that is stored as it was written.
+///
+/// ```
+/// assert!(wkt::check_syntax("POLYGON ((0 0, 1 0, 1 1, 0 0))").is_ok());
+/// assert_eq!(
+///     wkt::check_syntax("POINT (1 2) POINT (3 4)").unwrap_err().to_string(),
+///     "Invalid WKT: Unexpected token after the geometry"
+/// );
+/// ```
+pub fn check_syntax(input: &str) -> Result<(), Error> {
+    scan(input, &mut Ignore)
+}
+
+/// Whether `input` is a single syntactically valid WKT geometry, see [`check_syntax`].
+///
+/// ```
+/// assert!(wkt::is_valid("POINT Z (1 2 3)"));
+/// assert!(!wkt::is_valid("POINT (1 2"));
+/// ```
+pub fn is_valid(input: &str) -> bool {
+    check_syntax(input).is_ok()
+}
+
+struct Ignore;
+
+impl Visitor for Ignore {}
+
+struct Scanner<'a, 'v, V> {
+    tokens: Tokens<'a>,
+    visitor: &'v mut V,
+}
+
+impl<'a, V: Visitor> Scanner<'a, '_, V> {
+    fn geometry(&mut self, word: &str) -> Result<(), Error> {
+        let (geometry_type, dim) = GeometryType::from_keyword(word)
+            .filter(|(geometry_type, _)| !geometry_type.is_extended())
+            .ok_or(Error::InvalidWkt("Invalid type encountered"))?;
+        let dim = match dim {
+            Some(dim) => dim,
+            None => infer_geom_dimension(&mut self.tokens).map_err(Error::InvalidWkt)?,
+        };
+        self.visitor.geometry(geometry_type, dim)?;
+        match geometry_type {
+            GeometryType::Point => self.parens_or_empty(|s| s.coord(dim)),
+            GeometryType::LineString | GeometryType::LinearRing => {
+                self.parens_or_empty(|s| s.coords(dim))
+            }
+            GeometryType::Polygon => self.parens_or_empty(|s| s.rings(dim)),
+            GeometryType::MultiPoint => self.parens_or_empty(|s| {
+                s.list(|s| match s.tokens.peek() {
+                    Some(Token::ParenOpen) => s.parens(|s| s.coord(dim)),
+                    Some(Token::Word(w)) if w.eq_ignore_ascii_case("EMPTY") => {
+                        s.parens_or_empty(|s| s.coord(dim))
+                    }
+                    // Points in a multi point may be written without parentheses.
+                    _ => {
+                        s.visitor.open()?;
+                        s.coord(dim)?;
+                        s.visitor.close()
+                    }
+                })
+            }),
+            GeometryType::MultiLineString => {
+                self.parens_or_empty(|s| s.list(|s| s.parens_or_empty(|s| s.coords(dim))))
+            }
+            GeometryType::MultiPolygon => {
+                self.parens_or_empty(|s| s.list(|s| s.parens_or_empty(|s| s.rings(dim))))
+            }
+            GeometryType::GeometryCollection => self.parens_or_empty(|s| {
+                s.list(|s| match s.tokens.next() {
+                    Some(Token::Word(word)) => s.geometry(word),
+                    _ => Err(Error::InvalidWkt("Expected a word in GEOMETRYCOLLECTION")),
+                })
+            }),
+            _ => unreachable!("extended types are rejected above"),
+        }
+    }
+
+    /// `( ... )` or `EMPTY`.
+    fn parens_or_empty(
+        &mut self,
+        body: impl FnOnce(&mut Self) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        match self.tokens.peek() {
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case("EMPTY") => {
+                self.tokens.next();
+                self.visitor.empty()
+            }
+            _ => self.parens(body),
+        }
+    }
+
+    fn parens(&mut self, body: impl FnOnce(&mut Self) -> Result<(), Error>) -> Result<(), Error> {
+        match self.tokens.next() {
+            Some(Token::ParenOpen) => self.visitor.open()?,
+            _ => return Err(Error::InvalidWkt("Missing open parenthesis for type")),
+        }
+        body(self)?;
+        match self.tokens.next() {
+            Some(Token::ParenClose) => self.visitor.close(),
+            _ => Err(Error::InvalidWkt("Missing closing parenthesis for type")),
+        }
+    }
+
+    /// One or more comma separated items.
+    fn list(&mut self, mut item: impl FnMut(&mut Self) -> Result<(), Error>) -> Result<(), Error> {
+        item(self)?;
+        while let Some(Token::Comma) = self.tokens.peek() {
+            self.tokens.next();
+            self.visitor.separator()?;
+            item(self)?;
+        }
+        Ok(())
+    }
+
+    fn rings(&mut self, dim: Dimension) -> Result<(), Error> {
+        self.list(|s| s.parens_or_empty(|s| s.coords(dim)))
+    }
+
+    fn coords(&mut self, dim: Dimension) -> Result<(), Error> {
+        self.list(|s| s.coord(dim))
+    }
+
+    fn coord(&mut self, dim: Dimension) -> Result<(), Error> {
+        let x = self.number("Expected a number for the X coordinate")?;
+        let y = self.number("Expected a number for the Y coordinate")?;
+        let z = if dim.has_z() {
+            Some(self.number("Expected a number for the Z coordinate")?)
+        } else {
+            None
+        };
+        let m = if dim.has_m() {
+            Some(self.number("Expected a number for the M coordinate")?)
+        } else {
+            None
+        };
+        self.visitor.coord(Coord { x, y, z, m })
+    }
+
+    fn number(&mut self, error: &'static str) -> Result<f64, Error> {
+        match self.tokens.next() {
+            Some(Token::Number(n)) => {
+                let value = parse_number(n).map_err(Error::InvalidWkt)?;
+                self.visitor.number(n)?;
+                Ok(value)
+            }
+            _ => Err(Error::InvalidWkt(error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_syntax() {
+        for valid in [
+            "POINT EMPTY",
+            "MULTIPOINT (1 2, (3 4), EMPTY)",
+            "GEOMETRYCOLLECTION (POINT M (1 2 3), LINESTRING EMPTY)",
+        ] {
+            assert!(is_valid(valid), "{valid}");
+        }
+        for invalid in [
+            "",
+            "POINT",
+            "POINT (1)",
+            "LINESTRING (1 2,)",
+            "POINT (1 2))",
+        ] {
+            assert!(!is_valid(invalid), "{invalid}");
+        }
+        assert!(matches!(
+            check_syntax("POINT Z (1 2)"),
+            Err(Error::InvalidWkt(_))
+        ));
+    }
+}
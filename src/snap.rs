@@ -0,0 +1,96 @@
+//! Snapping coordinates to a grid, like PostGIS `ST_SnapToGrid`.
+
+use crate::coords_mut::{CoordsMut, LineStringsMut};
+use crate::types::{
+    GeometryCollection, LineString, LinearRing, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+use crate::{Wkt, WktFloat};
+
+/// Rounds `value` to the nearest multiple of `size`.
+fn snap<T: WktFloat>(value: T, size: T) -> T {
+    (value / size).round() * size
+}
+
+macro_rules! impl_snap_to_grid {
+    ($($type: ident),+) => {
+        $(
+            impl<T: WktFloat> $type<T> {
+                /// Rounds `x` and `y` of every coordinate to the nearest multiple of `size`, in
+                /// place, then removes consecutive coordinates of a line string or ring that have
+                /// snapped to the same point. Z and M values are left as they are.
+                ///
+                /// A `size` that isn't positive leaves the coordinates unchanged, as in PostGIS.
+                pub fn snap_to_grid(&mut self, size: T) {
+                    if size.is_nan() || size <= T::zero() {
+                        return;
+                    }
+                    self.coords_mut(&mut |coord| {
+                        coord.x = snap(coord.x, size);
+                        coord.y = snap(coord.y, size);
+                    });
+                    self.line_strings_mut(&mut |line_string| {
+                        line_string.0.dedup_by(|a, b| a.x == b.x && a.y == b.y)
+                    });
+                }
+            }
+        )+
+    };
+}
+
+impl_snap_to_grid!(
+    Wkt,
+    Point,
+    LineString,
+    LinearRing,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn snapped(input: &str, size: f64) -> String {
+        let mut wkt: Wkt<f64> = Wkt::from_str(input).unwrap();
+        wkt.snap_to_grid(size);
+        wkt.to_string()
+    }
+
+    #[test]
+    fn rounds_and_removes_collapsed_vertices() {
+        assert_eq!(
+            snapped("LINESTRING (0.1 0.2, 0.4 -0.2, 1.6 2.4, 1.9 2.1)", 1.),
+            "LINESTRING(0 0,2 2)"
+        );
+        assert_eq!(
+            snapped(
+                "POLYGON Z ((0 0 1.25, 10.2 0 2, 10.4 0.1 3, 10 10 4, 0 0 1.25))",
+                0.5
+            ),
+            "POLYGON Z((0 0 1.25,10 0 2,10.5 0 3,10 10 4,0 0 1.25))"
+        );
+        assert_eq!(
+            snapped("MULTIPOINT ((0.1 0.1), (0.2 0.2))", 1.),
+            "MULTIPOINT((0 0),(0 0))"
+        );
+    }
+
+    #[test]
+    fn ignores_sizes_that_are_not_positive() {
+        assert_eq!(snapped("POINT (0.1 0.2)", 0.), "POINT(0.1 0.2)");
+        assert_eq!(snapped("POINT (0.1 0.2)", -1.), "POINT(0.1 0.2)");
+        assert_eq!(snapped("POINT (0.1 0.2)", f64::NAN), "POINT(0.1 0.2)");
+    }
+
+    #[test]
+    fn snaps_types() {
+        let mut point = Point::new(crate::types::Coord::xy(1.2_f32, 3.7));
+        point.snap_to_grid(0.5);
+        assert_eq!(point.to_string(), "POINT(1 3.5)");
+    }
+}
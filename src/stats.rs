@@ -0,0 +1,163 @@
+//! [`Wkt::stats`], which summarizes a geometry in one walk.
+
+use geo_traits::GeometryTrait;
+
+use crate::extract::Bbox;
+use crate::types::{Coord, Dimension, GeometryType, LineString, Point, Polygon};
+use crate::{Wkt, WktNum};
+
+/// A summary of a geometry, returned by [`Wkt::stats`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeometryStats {
+    pub geometry_type: GeometryType,
+    pub dimension: Dimension,
+    /// The number of non-empty points, line strings, linear rings and polygons, including those
+    /// inside multi geometries and collections.
+    pub parts: usize,
+    /// The number of non-empty polygon rings and linear rings.
+    pub rings: usize,
+    /// The number of coordinates.
+    pub vertices: usize,
+    /// The 2D bounding box of the coordinates, or `None` if there are none.
+    pub bbox: Option<Bbox>,
+    /// Whether a member of a multi geometry or collection, or a ring of a polygon, is empty.
+    pub has_empty_members: bool,
+}
+
+impl<T: WktNum> Wkt<T> {
+    /// Counts the parts, rings and coordinates of the geometry and finds its bounding box, all in
+    /// one walk.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str(
+    ///     "MULTIPOLYGON (((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1)), EMPTY)",
+    /// ).unwrap();
+    /// let stats = wkt.stats();
+    /// assert_eq!((stats.parts, stats.rings, stats.vertices), (1, 2, 8));
+    /// assert_eq!(stats.bbox.unwrap().max_x, 4.);
+    /// assert!(stats.has_empty_members);
+    /// ```
+    pub fn stats(&self) -> GeometryStats {
+        let mut stats = GeometryStats {
+            geometry_type: self.geometry_type(),
+            dimension: Dimension::try_from(self.dim()).unwrap_or_default(),
+            parts: 0,
+            rings: 0,
+            vertices: 0,
+            bbox: None,
+            has_empty_members: false,
+        };
+        stats.wkt(self, false);
+        stats
+    }
+}
+
+impl GeometryStats {
+    fn wkt<T: WktNum>(&mut self, wkt: &Wkt<T>, member: bool) {
+        match wkt {
+            Wkt::Point(g) => self.point(g, member),
+            Wkt::LineString(g) => self.line_string(g, member),
+            Wkt::LinearRing(g) => {
+                self.line_string(&g.0, member);
+                self.rings += usize::from(!g.0 .0.is_empty());
+            }
+            Wkt::Polygon(g) => self.polygon(g, member),
+            Wkt::MultiPoint(g) => g.0.iter().for_each(|g| self.point(g, true)),
+            Wkt::MultiLineString(g) => g.0.iter().for_each(|g| self.line_string(g, true)),
+            Wkt::MultiPolygon(g) => g.0.iter().for_each(|g| self.polygon(g, true)),
+            Wkt::GeometryCollection(g) => g.0.iter().for_each(|g| self.wkt(g, true)),
+        }
+    }
+
+    fn point<T: WktNum>(&mut self, point: &Point<T>, member: bool) {
+        match &point.0 {
+            Some(coord) => {
+                self.parts += 1;
+                self.coord(coord);
+            }
+            None => self.has_empty_members |= member,
+        }
+    }
+
+    fn line_string<T: WktNum>(&mut self, line_string: &LineString<T>, member: bool) {
+        if line_string.0.is_empty() {
+            self.has_empty_members |= member;
+        } else {
+            self.parts += 1;
+            line_string.0.iter().for_each(|coord| self.coord(coord));
+        }
+    }
+
+    fn polygon<T: WktNum>(&mut self, polygon: &Polygon<T>, member: bool) {
+        if polygon.0.is_empty() {
+            self.has_empty_members |= member;
+            return;
+        }
+        self.parts += 1;
+        for ring in &polygon.0 {
+            if ring.0.is_empty() {
+                self.has_empty_members = true;
+            } else {
+                self.rings += 1;
+                ring.0.iter().for_each(|coord| self.coord(coord));
+            }
+        }
+    }
+
+    fn coord<T: WktNum>(&mut self, coord: &Coord<T>) {
+        self.vertices += 1;
+        if let (Some(x), Some(y)) = (coord.x.to_f64(), coord.y.to_f64()) {
+            Bbox::include(&mut self.bbox, &Coord::xy(x, y));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn stats(input: &str) -> GeometryStats {
+        Wkt::<f64>::from_str(input).unwrap().stats()
+    }
+
+    #[test]
+    fn counts_in_one_walk() {
+        let stats = stats(
+            "GEOMETRYCOLLECTION Z (POINT Z (10 20 30), LINESTRING Z (-1 5 0, 3 -7 0), \
+            MULTIPOINT Z ((1 1 1), EMPTY))",
+        );
+        assert_eq!(
+            stats,
+            GeometryStats {
+                geometry_type: GeometryType::GeometryCollection,
+                dimension: Dimension::XYZ,
+                parts: 3,
+                rings: 0,
+                vertices: 4,
+                bbox: Some(Bbox {
+                    min_x: -1.,
+                    min_y: -7.,
+                    max_x: 10.,
+                    max_y: 20.,
+                }),
+                has_empty_members: true,
+            }
+        );
+    }
+
+    #[test]
+    fn empty_geometries() {
+        let point = stats("POINT EMPTY");
+        assert_eq!((point.parts, point.vertices, point.bbox), (0, 0, None));
+        assert!(!point.has_empty_members);
+
+        let polygon = stats("POLYGON ((0 0, 1 0, 1 1, 0 0), EMPTY)");
+        assert_eq!((polygon.parts, polygon.rings), (1, 1));
+        assert!(polygon.has_empty_members);
+        assert!(!stats("MULTIPOLYGON EMPTY").has_empty_members);
+    }
+}
@@ -0,0 +1,197 @@
+//! [`assert_wkt_eq!`](crate::assert_wkt_eq), which compares geometries with a tolerance.
+
+use std::str::FromStr;
+
+use crate::approx::{difference, Difference};
+#[cfg(feature = "geo-types")]
+use crate::to_wkt::ToWkt;
+use crate::Wkt;
+
+/// Asserts that two geometries have the same structure, and coordinates that differ by at most
+/// `epsilon`, which defaults to zero.
+///
+/// Each side is WKT text, a [`Wkt<f64>`](crate::Wkt), or any other geometry that can be converted
+/// to one, like the types in [`wkt::types`](crate::types) and, with the `geo-types` feature,
+/// `geo_types` geometries. On failure, the message names the first coordinate that differs:
+///
+/// ```should_panic
+/// # use wkt::assert_wkt_eq;
+/// // Panics with: coordinates differ: `1 1` != `1 1.1` at rings[0].coords[2]
+/// assert_wkt_eq!(
+///     "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+///     "POLYGON ((0 0, 1 0, 1 1.1, 0 0))",
+///     epsilon = 0.01
+/// );
+/// ```
+///
+/// ```
+/// # use wkt::assert_wkt_eq;
+/// assert_wkt_eq!("POINT (1 2)", "POINT (1.0000000001 2)", epsilon = 1e-9);
+/// ```
+///
+/// NaN values are equal to each other.
+#[macro_export]
+macro_rules! assert_wkt_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_wkt_eq!($left, $right, epsilon = 0.)
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::test_util::compare::assert_wkt_eq(&$left, &$right, $epsilon)
+    };
+}
+
+/// A side of [`assert_wkt_eq!`](crate::assert_wkt_eq).
+pub trait WktOperand {
+    /// The geometry to compare. WKT text that can't be parsed panics.
+    fn to_wkt_operand(&self) -> Wkt<f64>;
+}
+
+impl WktOperand for str {
+    #[track_caller]
+    fn to_wkt_operand(&self) -> Wkt<f64> {
+        match Wkt::from_str(self) {
+            Ok(wkt) => wkt,
+            Err(err) => panic!("Couldn't parse {self:?}: {err}"),
+        }
+    }
+}
+
+impl WktOperand for String {
+    #[track_caller]
+    fn to_wkt_operand(&self) -> Wkt<f64> {
+        self.as_str().to_wkt_operand()
+    }
+}
+
+impl<T: WktOperand + ?Sized> WktOperand for &T {
+    #[track_caller]
+    fn to_wkt_operand(&self) -> Wkt<f64> {
+        (**self).to_wkt_operand()
+    }
+}
+
+impl WktOperand for Wkt<f64> {
+    fn to_wkt_operand(&self) -> Wkt<f64> {
+        self.clone()
+    }
+}
+
+macro_rules! impl_wkt_operand {
+    ($($type: ident),+) => {
+        $(
+            impl WktOperand for crate::types::$type<f64> {
+                fn to_wkt_operand(&self) -> Wkt<f64> {
+                    self.clone().into()
+                }
+            }
+        )+
+    };
+}
+
+impl_wkt_operand!(
+    Point,
+    LineString,
+    LinearRing,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+#[cfg(feature = "geo-types")]
+macro_rules! impl_wkt_operand_for_geo_types {
+    ($($type: ident),+) => {
+        $(
+            impl WktOperand for geo_types::$type<f64> {
+                fn to_wkt_operand(&self) -> Wkt<f64> {
+                    self.to_wkt()
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "geo-types")]
+impl_wkt_operand_for_geo_types!(
+    Geometry,
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle
+);
+
+/// The implementation of [`assert_wkt_eq!`](crate::assert_wkt_eq).
+#[track_caller]
+pub fn assert_wkt_eq(left: &impl WktOperand, right: &impl WktOperand, epsilon: f64) {
+    let (left, right) = (left.to_wkt_operand(), right.to_wkt_operand());
+    if let Some(Difference { problem, path }) = difference(&left, &right, epsilon) {
+        let at = if path.is_empty() {
+            String::new()
+        } else {
+            format!(" at {path}")
+        };
+        panic!(
+            "assertion `left == right` failed (epsilon = {epsilon}): {problem}{at}\n  left: \
+            {left}\n right: {right}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Coord, Point};
+
+    fn message(left: &str, right: &str, epsilon: f64) -> String {
+        let panic = std::panic::catch_unwind(|| assert_wkt_eq(&left, &right, epsilon)).unwrap_err();
+        panic.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn equal_within_epsilon() {
+        crate::assert_wkt_eq!("POINT (1 2)", "POINT (1 2)");
+        crate::assert_wkt_eq!(
+            "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), EMPTY)",
+            "MULTIPOLYGON (((0 0, 1 0, 1 1.0001, 0 0)), EMPTY)",
+            epsilon = 1e-3,
+        );
+        crate::assert_wkt_eq!(String::from("POINT (1 1)"), Point::new(Coord::xy(1., 1.)));
+        let nan = Point::new(Coord::xy(f64::NAN, 1.));
+        crate::assert_wkt_eq!(nan, nan);
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn compares_geo_types() {
+        let line = geo_types::Line::new((0., 0.), (1., 2.));
+        crate::assert_wkt_eq!(line, "LINESTRING (0 0, 1 2.0000001)", epsilon = 1e-6);
+    }
+
+    #[test]
+    fn names_the_first_difference() {
+        assert_eq!(
+            message(
+                "GEOMETRYCOLLECTION (POINT (1 2), POLYGON ((0 0, 1 0, 1 1, 0 0)))",
+                "GEOMETRYCOLLECTION (POINT (1 2), POLYGON ((0 0, 1 0, 1 1.5, 0 0)))",
+                0.1
+            ),
+            "assertion `left == right` failed (epsilon = 0.1): coordinates differ: `1 1` != `1 1.5` \
+            at geometries[1].rings[0].coords[2]\n  \
+            left: GEOMETRYCOLLECTION(POINT(1 2),POLYGON((0 0,1 0,1 1,0 0)))\n \
+            right: GEOMETRYCOLLECTION(POINT(1 2),POLYGON((0 0,1 0,1 1.5,0 0)))"
+        );
+        assert!(message("POINT (1 2)", "LINESTRING (1 2, 3 4)", 0.)
+            .contains("geometry types differ: POINT != LINESTRING\n"));
+        assert!(message("MULTIPOINT (1 2)", "MULTIPOINT (1 2, 3 4)", 0.)
+            .contains("lengths differ: 1 != 2 at points\n"));
+        assert!(message("POINT Z (1 2 3)", "POINT (1 2)", 0.)
+            .contains("coordinates differ: `1 2 3` != `1 2` at coord\n"));
+    }
+}
@@ -0,0 +1,236 @@
+//! A corpus of edge case WKT, collected from the OGC specification and the PostGIS and JTS test
+//! suites, and a harness that runs any parser and writer against it.
+//!
+//! Each [`Case`] says whether its input must be accepted, and if so which geometry it is. The
+//! written output is compared by parsing it again with this crate, so writers that format numbers
+//! or spaces differently still pass.
+//!
+//! ```
+//! use std::str::FromStr;
+//! use wkt::test_util::conformance;
+//! use wkt::Wkt;
+//!
+//! let failures = conformance::run(Wkt::<f64>::from_str, |wkt| wkt.to_string());
+//! assert!(failures.is_empty(), "{failures:?}");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Wkt;
+
+/// An input in the [`CORPUS`], and how a conforming parser handles it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Case {
+    /// What the input exercises, e.g. `"empty"` or `"number"`.
+    pub category: &'static str,
+    pub input: &'static str,
+    /// The geometry as this crate writes it, or `None` if the input must be rejected.
+    pub expected: Option<&'static str>,
+}
+
+const fn valid(category: &'static str, input: &'static str, expected: &'static str) -> Case {
+    Case {
+        category,
+        input,
+        expected: Some(expected),
+    }
+}
+
+const fn invalid(input: &'static str) -> Case {
+    Case {
+        category: "invalid",
+        input,
+        expected: None,
+    }
+}
+
+/// Every case, grouped by category.
+pub const CORPUS: &[Case] = &[
+    valid("empty", "POINT EMPTY", "POINT EMPTY"),
+    valid("empty", "LINESTRING EMPTY", "LINESTRING EMPTY"),
+    valid("empty", "POLYGON EMPTY", "POLYGON EMPTY"),
+    valid("empty", "MULTIPOINT EMPTY", "MULTIPOINT EMPTY"),
+    valid("empty", "MULTILINESTRING EMPTY", "MULTILINESTRING EMPTY"),
+    valid("empty", "MULTIPOLYGON EMPTY", "MULTIPOLYGON EMPTY"),
+    valid(
+        "empty",
+        "GEOMETRYCOLLECTION EMPTY",
+        "GEOMETRYCOLLECTION EMPTY",
+    ),
+    valid("empty", "point empty", "POINT EMPTY"),
+    valid(
+        "empty",
+        "MULTIPOINT ((1 2), EMPTY)",
+        "MULTIPOINT((1 2),EMPTY)",
+    ),
+    valid(
+        "empty",
+        "MULTILINESTRING (EMPTY, (1 2, 3 4))",
+        "MULTILINESTRING(EMPTY,(1 2,3 4))",
+    ),
+    valid(
+        "empty",
+        "MULTIPOLYGON (EMPTY, ((0 0, 1 0, 1 1, 0 0)))",
+        "MULTIPOLYGON(EMPTY,((0 0,1 0,1 1,0 0)))",
+    ),
+    valid(
+        "empty",
+        "GEOMETRYCOLLECTION (POINT EMPTY, LINESTRING EMPTY)",
+        "GEOMETRYCOLLECTION(POINT EMPTY,LINESTRING EMPTY)",
+    ),
+    valid(
+        "nested",
+        "GEOMETRYCOLLECTION (GEOMETRYCOLLECTION (POINT (1 2)), GEOMETRYCOLLECTION EMPTY)",
+        "GEOMETRYCOLLECTION(GEOMETRYCOLLECTION(POINT(1 2)),GEOMETRYCOLLECTION EMPTY)",
+    ),
+    valid(
+        "nested",
+        "GEOMETRYCOLLECTION (GEOMETRYCOLLECTION (GEOMETRYCOLLECTION (LINESTRING (1 2, 3 4))))",
+        "GEOMETRYCOLLECTION(GEOMETRYCOLLECTION(GEOMETRYCOLLECTION(LINESTRING(1 2,3 4))))",
+    ),
+    valid(
+        "nested",
+        "GEOMETRYCOLLECTION (MULTIPOINT (1 2), POLYGON ((0 0, 1 0, 1 1, 0 0)))",
+        "GEOMETRYCOLLECTION(MULTIPOINT((1 2)),POLYGON((0 0,1 0,1 1,0 0)))",
+    ),
+    valid(
+        "nested",
+        "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (1 1, 2 1, 2 2, 1 1), (5 5, 6 5, 6 6, 5 5))",
+        "POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,2 1,2 2,1 1),(5 5,6 5,6 6,5 5))",
+    ),
+    valid("dimension", "POINT Z (1 2 3)", "POINT Z(1 2 3)"),
+    valid("dimension", "POINTZ (1 2 3)", "POINT Z(1 2 3)"),
+    valid("dimension", "POINT M (1 2 3)", "POINT M(1 2 3)"),
+    valid("dimension", "POINT ZM (1 2 3 4)", "POINT ZM(1 2 3 4)"),
+    valid("dimension", "point zm (1 2 3 4)", "POINT ZM(1 2 3 4)"),
+    valid(
+        "dimension",
+        "LINESTRING M (1 2 3, 4 5 6)",
+        "LINESTRING M(1 2 3,4 5 6)",
+    ),
+    valid(
+        "dimension",
+        "MULTIPOINT Z (1 2 3, 4 5 6)",
+        "MULTIPOINT Z((1 2 3),(4 5 6))",
+    ),
+    valid(
+        "dimension",
+        "GEOMETRYCOLLECTION Z (POINT Z (1 2 3), LINESTRING Z (1 2 3, 4 5 6))",
+        "GEOMETRYCOLLECTION Z(POINT Z(1 2 3),LINESTRING Z(1 2 3,4 5 6))",
+    ),
+    valid("number", "POINT (1e3 -2.5E-3)", "POINT(1000 -0.0025)"),
+    valid("number", "POINT (+1 .5)", "POINT(1 0.5)"),
+    valid("number", "POINT (1. -0)", "POINT(1 -0)"),
+    valid("number", "POINT (1e+2 1E-2)", "POINT(100 0.01)"),
+    valid(
+        "number",
+        "POINT (-0.000001 123456789.5)",
+        "POINT(-0.000001 123456789.5)",
+    ),
+    valid("whitespace", "POINT(1 2)", "POINT(1 2)"),
+    valid("whitespace", "  POINT  (  1   2  )  ", "POINT(1 2)"),
+    valid(
+        "whitespace",
+        "LINESTRING(\n1 2,\t3 4\r\n)",
+        "LINESTRING(1 2,3 4)",
+    ),
+    valid(
+        "whitespace",
+        "MULTIPOINT(1 2,3 4)",
+        "MULTIPOINT((1 2),(3 4))",
+    ),
+    invalid(""),
+    invalid("POINT"),
+    invalid("POINT ()"),
+    invalid("POINT (1)"),
+    invalid("POINT (1 2"),
+    invalid("POINT 1 2"),
+    invalid("POINT (a b)"),
+    invalid("POINT (1 2 3 4 5)"),
+    invalid("POINT Z (1 2)"),
+    invalid("POINT ZM (1 2 3)"),
+    invalid("LINESTRING (1 2,)"),
+    invalid("LINESTRING (1 2, 3)"),
+    invalid("POLYGON (0 0, 1 0, 1 1, 0 0)"),
+    invalid("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0))"),
+    invalid("GEOMETRYCOLLECTION ((1 2))"),
+];
+
+/// A case that a parser and writer got wrong.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    pub case: Case,
+    pub problem: String,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.case.input, self.problem)
+    }
+}
+
+/// Runs `parse` on every input in the [`CORPUS`] and `write` on what it accepts, and returns the
+/// cases they got wrong.
+///
+/// A parser fails a case by accepting invalid input or rejecting valid input. A writer fails it by
+/// writing WKT that this crate parses into a different geometry than the expected one.
+pub fn run<G, E: fmt::Display>(
+    mut parse: impl FnMut(&str) -> Result<G, E>,
+    mut write: impl FnMut(&G) -> String,
+) -> Vec<Failure> {
+    let mut failures = vec![];
+    for case in CORPUS {
+        let problem = match (parse(case.input), case.expected) {
+            (Err(_), None) => continue,
+            (Ok(geometry), None) => format!("Accepted invalid WKT, writing {:?}", write(&geometry)),
+            (Err(err), Some(_)) => format!("Rejected valid WKT: {err}"),
+            (Ok(geometry), Some(expected)) => {
+                let written = write(&geometry);
+                match Wkt::<f64>::from_str(&written) {
+                    Ok(wkt) if wkt == Wkt::from_str(expected).unwrap() => continue,
+                    Ok(_) => format!("Wrote {written:?}, expected {expected:?}"),
+                    Err(err) => format!("Wrote {written:?}, which is invalid WKT: {err}"),
+                }
+            }
+        };
+        failures.push(Failure {
+            case: *case,
+            problem,
+        });
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn this_crate_writes_the_expected_text() {
+        for case in CORPUS {
+            let parsed = Wkt::<f64>::from_str(case.input);
+            match case.expected {
+                Some(expected) => {
+                    let parsed = parsed.unwrap_or_else(|err| panic!("{:?}: {err}", case.input));
+                    assert_eq!(parsed.to_string(), expected);
+                }
+                None => assert!(parsed.is_err(), "{:?} was accepted", case.input),
+            }
+        }
+    }
+
+    #[test]
+    fn reports_failures() {
+        // Writes every geometry as the same point, and accepts everything.
+        let failures = run(|_| Ok::<_, &str>(()), |_| "POINT (1 2)".to_string());
+        let failed = |input| failures.iter().any(|failure| failure.case.input == input);
+        assert!(failed("POINT EMPTY"));
+        assert!(failed("POINT 1 2"));
+        assert!(!failed("POINT(1 2)"));
+        assert_eq!(
+            failures[0].to_string(),
+            "\"POINT EMPTY\": Wrote \"POINT (1 2)\", expected \"POINT EMPTY\""
+        );
+    }
+}
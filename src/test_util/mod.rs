@@ -0,0 +1,108 @@
+//! Assertions for testing that WKT survives being parsed and written, for this crate's own tests
+//! and for crates that implement [`geo_traits`] and write WKT with [`to_wkt`](crate::to_wkt).
+//!
+//! ```
+//! use wkt::test_util::{assert_roundtrip, assert_writes_like_wkt};
+//! use wkt::types::{Coord, Point};
+//!
+//! assert_eq!(assert_roundtrip("MULTIPOINT ((1 2), EMPTY)"), "MULTIPOINT((1 2),EMPTY)");
+//! assert_eq!(assert_writes_like_wkt(&Point::new(Coord::xy(1., 2.))), "POINT(1 2)");
+//! ```
+//!
+//! Geometries are compared with `==`, so coordinates that are NaN never round trip.
+//!
+//! [`assert_wkt_eq!`](crate::assert_wkt_eq) compares geometries with a tolerance instead.
+//!
+//! The [`conformance`] corpus has edge cases to run them on, or to run another parser and writer
+//! on.
+
+use std::fmt;
+use std::str::FromStr;
+
+use geo_traits::GeometryTrait;
+
+use crate::to_wkt::write_geometry;
+use crate::{Wkt, WktNum};
+
+pub mod compare;
+pub mod conformance;
+
+/// Parses `input`, writes it and parses the written WKT, and panics unless both parses are equal
+/// and writing the second gives the same text again. Returns the written WKT.
+#[track_caller]
+pub fn assert_roundtrip(input: &str) -> String {
+    let parsed: Wkt<f64> = parse(input);
+    let written = parsed.to_string();
+    let reparsed = parse(&written);
+    assert_eq!(
+        parsed, reparsed,
+        "{input:?} changed after being written as {written:?}"
+    );
+    assert_eq!(
+        reparsed.to_string(),
+        written,
+        "{input:?} was written differently the second time"
+    );
+    written
+}
+
+/// Writes `geometry` with [`write_geometry`], and panics unless the written WKT parses into a
+/// [`Wkt`] that's written the same way. Returns the written WKT.
+///
+/// This catches a [`GeometryTrait`] implementation that the writer turns into invalid WKT, e.g.
+/// one whose dimension doesn't match its coordinates.
+#[track_caller]
+pub fn assert_writes_like_wkt<T>(geometry: &impl GeometryTrait<T = T>) -> String
+where
+    T: WktNum + FromStr + Default + fmt::Display,
+{
+    let mut written = String::new();
+    if let Err(err) = write_geometry(&mut written, geometry) {
+        panic!("Couldn't write the geometry: {err}");
+    }
+    let parsed: Wkt<T> = parse(&written);
+    assert_eq!(
+        parsed.to_string(),
+        written,
+        "The geometry was written differently after being parsed"
+    );
+    written
+}
+
+#[track_caller]
+fn parse<T: WktNum + FromStr + Default>(input: &str) -> Wkt<T> {
+    match Wkt::from_str(input) {
+        Ok(wkt) => wkt,
+        Err(err) => panic!("Couldn't parse {input:?}: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrips() {
+        assert_eq!(
+            assert_roundtrip("polygon z ((0 0 1, 1 0 1, 1 1 1, 0 0 1))"),
+            "POLYGON Z((0 0 1,1 0 1,1 1 1,0 0 1))"
+        );
+        assert_eq!(
+            assert_roundtrip("GEOMETRYCOLLECTION (POINT EMPTY, MULTIPOLYGON (EMPTY))"),
+            "GEOMETRYCOLLECTION(POINT EMPTY,MULTIPOLYGON(EMPTY))"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Couldn't parse \"POINT (1)\"")]
+    fn invalid_input_panics() {
+        assert_roundtrip("POINT (1)");
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn geo_types_write_like_wkt() {
+        let line = geo_types::Line::new((0., 0.), (1., 2.5));
+        assert_eq!(assert_writes_like_wkt(&line), "LINESTRING(0 0,1 2.5)");
+    }
+}
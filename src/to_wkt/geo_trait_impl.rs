@@ -8,7 +8,7 @@ use geo_traits::{
 };
 
 use crate::error::Error;
-use crate::types::Coord;
+use crate::types::{Coord, Dimension};
 use crate::WktNum;
 
 /// The physical size of the coordinate dimension
@@ -22,36 +22,39 @@ enum PhysicalCoordinateDimension {
     Four,
 }
 
-impl TryFrom<geo_traits::Dimensions> for PhysicalCoordinateDimension {
-    type Error = Error;
-
-    fn try_from(value: geo_traits::Dimensions) -> Result<Self, Self::Error> {
-        match value.size() {
-            2 => Ok(Self::Two),
-            3 => Ok(Self::Three),
-            4 => Ok(Self::Four),
-            _ => Err(Error::UnknownDimension),
+impl From<Dimension> for PhysicalCoordinateDimension {
+    fn from(value: Dimension) -> Self {
+        match value {
+            Dimension::XY => Self::Two,
+            Dimension::XYZ | Dimension::XYM => Self::Three,
+            Dimension::XYZM => Self::Four,
         }
     }
 }
 
+/// Write the WKT keyword of a geometry, followed by its dimension tag if it isn't 2D, e.g.
+/// `POINT Z`.
+pub(super) fn write_keyword(
+    f: &mut impl Write,
+    keyword: &str,
+    dim: Dimension,
+) -> Result<(), Error> {
+    f.write_str(keyword)?;
+    if dim != Dimension::XY {
+        f.write_char(' ')?;
+        f.write_str(dim.suffix())?;
+    }
+    Ok(())
+}
+
 /// Write an object implementing [`PointTrait`] to a WKT string.
 pub fn write_point<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     g: &impl PointTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = g.dim();
-    // Write prefix
-    match dim {
-        geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => f.write_str("POINT"),
-        geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => f.write_str("POINT Z"),
-        geo_traits::Dimensions::Xym => f.write_str("POINT M"),
-        geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
-            f.write_str("POINT ZM")
-        }
-        geo_traits::Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-    }?;
-    let size = dim.try_into()?;
+    let dim = Dimension::try_from(g.dim())?;
+    write_keyword(f, "POINT", dim)?;
+    let size = dim.into();
     if let Some(coord) = g.coord() {
         f.write_char('(')?;
         write_coord(f, &coord, size)?;
@@ -67,22 +70,27 @@ pub fn write_linestring<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     linestring: &impl LineStringTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = linestring.dim();
-    // Write prefix
-    match dim {
-        geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => {
-            f.write_str("LINESTRING")
-        }
-        geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => {
-            f.write_str("LINESTRING Z")
-        }
-        geo_traits::Dimensions::Xym => f.write_str("LINESTRING M"),
-        geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
-            f.write_str("LINESTRING ZM")
-        }
-        geo_traits::Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-    }?;
-    let size = dim.try_into()?;
+    write_linestring_with_keyword(f, linestring, "LINESTRING")
+}
+
+/// Write an object implementing [`LineStringTrait`] to a WKT string as a `LINEARRING`.
+///
+/// The ring isn't checked for closure.
+pub fn write_linear_ring<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    ring: &impl LineStringTrait<T = T>,
+) -> Result<(), Error> {
+    write_linestring_with_keyword(f, ring, "LINEARRING")
+}
+
+fn write_linestring_with_keyword<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    linestring: &impl LineStringTrait<T = T>,
+    keyword: &str,
+) -> Result<(), Error> {
+    let dim = Dimension::try_from(linestring.dim())?;
+    write_keyword(f, keyword, dim)?;
+    let size = dim.into();
     if linestring.num_coords() == 0 {
         Ok(f.write_str(" EMPTY")?)
     } else {
@@ -95,20 +103,9 @@ pub fn write_polygon<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     polygon: &impl PolygonTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = polygon.dim();
-    // Write prefix
-    match dim {
-        geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => f.write_str("POLYGON"),
-        geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => {
-            f.write_str("POLYGON Z")
-        }
-        geo_traits::Dimensions::Xym => f.write_str("POLYGON M"),
-        geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
-            f.write_str("POLYGON ZM")
-        }
-        geo_traits::Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-    }?;
-    let size = dim.try_into()?;
+    let dim = Dimension::try_from(polygon.dim())?;
+    write_keyword(f, "POLYGON", dim)?;
+    let size = dim.into();
     if let Some(exterior) = polygon.exterior() {
         if exterior.num_coords() != 0 {
             f.write_str("(")?;
@@ -133,39 +130,22 @@ pub fn write_multi_point<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     multipoint: &impl MultiPointTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = multipoint.dim();
-    // Write prefix
-    match dim {
-        geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => {
-            f.write_str("MULTIPOINT")
-        }
-        geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => {
-            f.write_str("MULTIPOINT Z")
-        }
-        geo_traits::Dimensions::Xym => f.write_str("MULTIPOINT M"),
-        geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
-            f.write_str("MULTIPOINT ZM")
-        }
-        geo_traits::Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-    }?;
-    let size = dim.try_into()?;
+    let dim = Dimension::try_from(multipoint.dim())?;
+    write_keyword(f, "MULTIPOINT", dim)?;
+    let size = dim.into();
 
     let mut points = multipoint.points();
 
-    // Note: This is largely copied from `write_coord_sequence`, because `multipoint.points()`
-    // yields a sequence of Point, not Coord.
     if let Some(first_point) = points.next() {
-        f.write_str("((")?;
-
-        // Assume no empty points within this MultiPoint
-        write_coord(f, &first_point.coord().unwrap(), size)?;
+        f.write_char('(')?;
+        write_point_member(f, &first_point, size)?;
 
         for point in points {
-            f.write_str("),(")?;
-            write_coord(f, &point.coord().unwrap(), size)?;
+            f.write_char(',')?;
+            write_point_member(f, &point, size)?;
         }
 
-        f.write_str("))")?;
+        f.write_char(')')?;
     } else {
         f.write_str(" EMPTY")?;
     }
@@ -173,27 +153,29 @@ pub fn write_multi_point<T: WktNum + fmt::Display>(
     Ok(())
 }
 
+/// Write a point within a MultiPoint, as `(x y)` or `EMPTY`.
+fn write_point_member<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    point: &impl PointTrait<T = T>,
+    size: PhysicalCoordinateDimension,
+) -> Result<(), Error> {
+    if let Some(coord) = point.coord() {
+        f.write_char('(')?;
+        write_coord(f, &coord, size)?;
+        Ok(f.write_char(')')?)
+    } else {
+        Ok(f.write_str("EMPTY")?)
+    }
+}
+
 /// Write an object implementing [`MultiLineStringTrait`] to a WKT string.
 pub fn write_multi_linestring<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     multilinestring: &impl MultiLineStringTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = multilinestring.dim();
-    // Write prefix
-    match dim {
-        geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => {
-            f.write_str("MULTILINESTRING")
-        }
-        geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => {
-            f.write_str("MULTILINESTRING Z")
-        }
-        geo_traits::Dimensions::Xym => f.write_str("MULTILINESTRING M"),
-        geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
-            f.write_str("MULTILINESTRING ZM")
-        }
-        geo_traits::Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-    }?;
-    let size = dim.try_into()?;
+    let dim = Dimension::try_from(multilinestring.dim())?;
+    write_keyword(f, "MULTILINESTRING", dim)?;
+    let size = dim.into();
     let mut line_strings = multilinestring.line_strings();
     if let Some(first_linestring) = line_strings.next() {
         f.write_str("(")?;
@@ -217,50 +199,47 @@ pub fn write_multi_polygon<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     multipolygon: &impl MultiPolygonTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = multipolygon.dim();
-    // Write prefix
-    match dim {
-        geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => {
-            f.write_str("MULTIPOLYGON")
-        }
-        geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => {
-            f.write_str("MULTIPOLYGON Z")
-        }
-        geo_traits::Dimensions::Xym => f.write_str("MULTIPOLYGON M"),
-        geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
-            f.write_str("MULTIPOLYGON ZM")
-        }
-        geo_traits::Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-    }?;
-    let size = dim.try_into()?;
+    let dim = Dimension::try_from(multipolygon.dim())?;
+    write_keyword(f, "MULTIPOLYGON", dim)?;
+    let size = dim.into();
 
     let mut polygons = multipolygon.polygons();
 
     if let Some(first_polygon) = polygons.next() {
-        f.write_str("((")?;
+        f.write_char('(')?;
+        write_polygon_member(f, &first_polygon, size)?;
 
-        write_coord_sequence(f, first_polygon.exterior().unwrap().coords(), size)?;
-        for interior in first_polygon.interiors() {
+        for polygon in polygons {
             f.write_char(',')?;
-            write_coord_sequence(f, interior.coords(), size)?;
+            write_polygon_member(f, &polygon, size)?;
         }
 
-        for polygon in polygons {
-            f.write_str("),(")?;
+        f.write_char(')')?;
+    } else {
+        f.write_str(" EMPTY")?;
+    };
 
-            write_coord_sequence(f, polygon.exterior().unwrap().coords(), size)?;
+    Ok(())
+}
+
+/// Write a polygon within a MultiPolygon, as `((...),...)` or `EMPTY`.
+fn write_polygon_member<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    polygon: &impl PolygonTrait<T = T>,
+    size: PhysicalCoordinateDimension,
+) -> Result<(), Error> {
+    match polygon.exterior() {
+        Some(exterior) if exterior.num_coords() != 0 => {
+            f.write_char('(')?;
+            write_coord_sequence(f, exterior.coords(), size)?;
             for interior in polygon.interiors() {
                 f.write_char(',')?;
                 write_coord_sequence(f, interior.coords(), size)?;
             }
+            Ok(f.write_char(')')?)
         }
-
-        f.write_str("))")?;
-    } else {
-        f.write_str(" EMPTY")?;
-    };
-
-    Ok(())
+        _ => Ok(f.write_str("EMPTY")?),
+    }
 }
 
 /// Write an object implementing [`GeometryTrait`] to a WKT string.
@@ -289,21 +268,8 @@ pub fn write_geometry_collection<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     gc: &impl GeometryCollectionTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = gc.dim();
-    // Write prefix
-    match dim {
-        geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => {
-            f.write_str("GEOMETRYCOLLECTION")
-        }
-        geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => {
-            f.write_str("GEOMETRYCOLLECTION Z")
-        }
-        geo_traits::Dimensions::Xym => f.write_str("GEOMETRYCOLLECTION M"),
-        geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
-            f.write_str("GEOMETRYCOLLECTION ZM")
-        }
-        geo_traits::Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-    }?;
+    let dim = Dimension::try_from(gc.dim())?;
+    write_keyword(f, "GEOMETRYCOLLECTION", dim)?;
     let mut geometries = gc.geometries();
 
     if let Some(first_geometry) = geometries.next() {
@@ -388,20 +354,9 @@ pub fn write_triangle<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     triangle: &impl TriangleTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = triangle.dim();
-    // Write prefix
-    match dim {
-        geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => f.write_str("POLYGON"),
-        geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => {
-            f.write_str("POLYGON Z")
-        }
-        geo_traits::Dimensions::Xym => f.write_str("POLYGON M"),
-        geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
-            f.write_str("POLYGON ZM")
-        }
-        geo_traits::Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-    }?;
-    let size = dim.try_into()?;
+    let dim = Dimension::try_from(triangle.dim())?;
+    write_keyword(f, "POLYGON", dim)?;
+    let size = dim.into();
     f.write_str("(")?;
 
     let coords_iter = triangle
@@ -420,22 +375,9 @@ pub fn write_line<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     line: &impl LineTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = line.dim();
-    // Write prefix
-    match dim {
-        geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => {
-            f.write_str("LINESTRING")
-        }
-        geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => {
-            f.write_str("LINESTRING Z")
-        }
-        geo_traits::Dimensions::Xym => f.write_str("LINESTRING M"),
-        geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
-            f.write_str("LINESTRING ZM")
-        }
-        geo_traits::Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-    }?;
-    let size = dim.try_into()?;
+    let dim = Dimension::try_from(line.dim())?;
+    write_keyword(f, "LINESTRING", dim)?;
+    let size = dim.into();
     write_coord_sequence(f, line.coords().into_iter(), size)
 }
 
@@ -446,31 +388,58 @@ fn write_coord<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     coord: &impl CoordTrait<T = T>,
     size: PhysicalCoordinateDimension,
-) -> Result<(), std::fmt::Error> {
-    match size {
-        PhysicalCoordinateDimension::Two => write!(f, "{} {}", coord.x(), coord.y()),
-        PhysicalCoordinateDimension::Three => {
-            // Safety:
-            // We've validated that there are three dimensions
-            write!(f, "{} {} {}", coord.x(), coord.y(), unsafe {
-                coord.nth_unchecked(2)
-            })
-        }
-        PhysicalCoordinateDimension::Four => {
-            write!(
-                f,
-                "{} {} {} {}",
-                coord.x(),
-                coord.y(),
-                // Safety:
-                // We've validated that there are four dimensions
-                unsafe { coord.nth_unchecked(2) },
-                // Safety:
-                // We've validated that there are four dimensions
-                unsafe { coord.nth_unchecked(3) }
-            )
-        }
+) -> Result<(), Error> {
+    write_separated_coord(f, "", coord, size)
+}
+
+/// Write `separator` and then a single coordinate to the writer.
+///
+/// They're formatted into a [`CoordBuffer`] first, so that the writer gets one `write_str` call
+/// per coordinate instead of one for each number and space.
+fn write_separated_coord<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    separator: &str,
+    coord: &impl CoordTrait<T = T>,
+    size: PhysicalCoordinateDimension,
+) -> Result<(), Error> {
+    // The geometry's dimension comes from its first coordinate, so later ones may have fewer
+    // values than `size` asks for.
+    let ordinate = |n| coord.nth(n).ok_or(Error::MissingOrdinate);
+    let (z, m) = match size {
+        PhysicalCoordinateDimension::Two => (None, None),
+        PhysicalCoordinateDimension::Three => (Some(ordinate(2)?), None),
+        PhysicalCoordinateDimension::Four => (Some(ordinate(2)?), Some(ordinate(3)?)),
+    };
+    let (x, y) = (coord.x(), coord.y());
+
+    let mut buffer = CoordBuffer::new();
+    let buffered = buffer
+        .write_str(separator)
+        .and_then(|()| write_coord_numbers(&mut buffer, x, y, z, m));
+    if buffered.is_ok() {
+        Ok(f.write_str(buffer.as_str())?)
+    } else {
+        // Too long for the buffer, which only happens for unusually long numbers.
+        f.write_str(separator)?;
+        Ok(write_coord_numbers(f, x, y, z, m)?)
+    }
+}
+
+fn write_coord_numbers<T: fmt::Display>(
+    f: &mut impl Write,
+    x: T,
+    y: T,
+    z: Option<T>,
+    m: Option<T>,
+) -> fmt::Result {
+    write!(f, "{x} {y}")?;
+    if let Some(z) = z {
+        write!(f, " {z}")?;
     }
+    if let Some(m) = m {
+        write!(f, " {m}")?;
+    }
+    Ok(())
 }
 
 /// Includes the `()` characters to start and end this sequence.
@@ -479,23 +448,64 @@ fn write_coord<T: WktNum + fmt::Display>(
 /// ```notest
 /// (1 2, 3 4, 5 6)
 /// ```
-/// for a coordinate sequence with three coordinates.
+/// for a coordinate sequence with three coordinates, and `EMPTY` for one with none.
 fn write_coord_sequence<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     mut coords: impl Iterator<Item = impl CoordTrait<T = T>>,
     size: PhysicalCoordinateDimension,
 ) -> Result<(), Error> {
-    f.write_char('(')?;
-
     if let Some(first_coord) = coords.next() {
+        f.write_char('(')?;
         write_coord(f, &first_coord, size)?;
 
         for coord in coords {
-            f.write_char(',')?;
-            write_coord(f, &coord, size)?;
+            write_separated_coord(f, ",", &coord, size)?;
         }
-    }
 
-    f.write_char(')')?;
+        f.write_char(')')?;
+    } else {
+        // An empty member of a collection, e.g. an empty ring, which `()` can't express.
+        f.write_str("EMPTY")?;
+    }
     Ok(())
 }
+
+/// A fixed size buffer on the stack, for formatting a coordinate.
+///
+/// Writing fails when the buffer is full, rather than growing it.
+struct CoordBuffer {
+    bytes: [u8; Self::CAPACITY],
+    len: usize,
+}
+
+impl CoordBuffer {
+    /// Enough for a separator and four typical coordinate values, with spaces. `f64`s are written
+    /// without an exponent, so very large or small ones take hundreds of digits, and a coordinate
+    /// that doesn't fit is written directly instead.
+    const CAPACITY: usize = 128;
+
+    fn new() -> Self {
+        Self {
+            bytes: [0; Self::CAPACITY],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // Safety:
+        // Only whole `str`s are copied into the buffer
+        unsafe { std::str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+}
+
+impl Write for CoordBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
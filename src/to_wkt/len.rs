@@ -0,0 +1,195 @@
+//! Estimating the length of a [`Wkt`]'s text, so the output `String` can be allocated once.
+
+use std::fmt::{self, Write};
+
+use crate::types::{Coord, LineString, Polygon};
+use crate::{Wkt, WktNum};
+
+/// About how many numbers are formatted to find their average length.
+const SAMPLE_LEN: usize = 64;
+
+/// The length of the WKT text of `wkt`, or a little more.
+///
+/// The punctuation and keywords are counted exactly. Number lengths are extrapolated from about
+/// [`SAMPLE_LEN`] numbers spread evenly over the geometry, with an eighth added for numbers that
+/// turn out longer, so the estimate for a geometry with very irregular numbers can still fall
+/// short.
+pub(crate) fn estimate_len<T: WktNum + fmt::Display>(wkt: &Wkt<T>) -> usize {
+    // The first pass counts the numbers, to choose which ones the second pass samples.
+    let mut counted = Estimate::default();
+    counted.wkt(wkt);
+    let mut estimate = Estimate {
+        stride: (counted.numbers / SAMPLE_LEN).max(1),
+        ..Estimate::default()
+    };
+    estimate.wkt(wkt);
+    let unsampled = estimate.numbers - estimate.sampled;
+    let extrapolated = match estimate.sampled {
+        0 => 0,
+        sampled => unsampled * estimate.sampled_len.div_ceil(sampled),
+    };
+    estimate.exact + estimate.sampled_len + extrapolated + extrapolated / 8
+}
+
+#[derive(Default)]
+struct Estimate {
+    /// The length of everything except the unsampled numbers.
+    exact: usize,
+    numbers: usize,
+    /// Every `stride`th number is sampled, or none if it's zero.
+    stride: usize,
+    sampled: usize,
+    sampled_len: usize,
+}
+
+impl Estimate {
+    fn wkt<T: WktNum + fmt::Display>(&mut self, wkt: &Wkt<T>) {
+        // The longest dimension tag, " ZM", is assumed for every geometry.
+        let (keyword, empty) = match wkt {
+            Wkt::Point(point) => {
+                self.point(&point.0);
+                ("POINT", point.0.is_none())
+            }
+            Wkt::LineString(line_string) => {
+                self.line_string(line_string);
+                ("LINESTRING", line_string.0.is_empty())
+            }
+            Wkt::LinearRing(ring) => {
+                self.line_string(&ring.0);
+                ("LINEARRING", ring.0 .0.is_empty())
+            }
+            Wkt::Polygon(polygon) => {
+                self.polygon(polygon);
+                ("POLYGON", polygon.0.is_empty())
+            }
+            Wkt::MultiPoint(multi_point) => {
+                self.list(multi_point.0.len());
+                multi_point.0.iter().for_each(|point| self.point(&point.0));
+                ("MULTIPOINT", multi_point.0.is_empty())
+            }
+            Wkt::MultiLineString(multi_line_string) => {
+                self.list(multi_line_string.0.len());
+                multi_line_string
+                    .0
+                    .iter()
+                    .for_each(|line_string| self.line_string(line_string));
+                ("MULTILINESTRING", multi_line_string.0.is_empty())
+            }
+            Wkt::MultiPolygon(multi_polygon) => {
+                self.list(multi_polygon.0.len());
+                multi_polygon
+                    .0
+                    .iter()
+                    .for_each(|polygon| self.polygon(polygon));
+                ("MULTIPOLYGON", multi_polygon.0.is_empty())
+            }
+            Wkt::GeometryCollection(collection) => {
+                self.list(collection.0.len());
+                collection.0.iter().for_each(|geometry| self.wkt(geometry));
+                ("GEOMETRYCOLLECTION", collection.0.is_empty())
+            }
+        };
+        self.exact += keyword.len() + " ZM".len();
+        if empty {
+            // The space before `EMPTY`, which `list` counted.
+            self.exact += 1;
+        }
+    }
+
+    fn point<T: WktNum + fmt::Display>(&mut self, coord: &Option<Coord<T>>) {
+        match coord {
+            Some(coord) => {
+                self.list(1);
+                self.coord(coord);
+            }
+            None => self.list(0),
+        }
+    }
+
+    fn polygon<T: WktNum + fmt::Display>(&mut self, polygon: &Polygon<T>) {
+        self.list(polygon.0.len());
+        polygon.0.iter().for_each(|ring| self.line_string(ring));
+    }
+
+    fn line_string<T: WktNum + fmt::Display>(&mut self, line_string: &LineString<T>) {
+        self.list(line_string.0.len());
+        line_string.0.iter().for_each(|coord| self.coord(coord));
+    }
+
+    /// Counts the parentheses and commas of a list of `len` items, or `EMPTY` for an empty one.
+    fn list(&mut self, len: usize) {
+        self.exact += match len {
+            0 => "EMPTY".len(),
+            len => len + 1,
+        };
+    }
+
+    fn coord<T: WktNum + fmt::Display>(&mut self, coord: &Coord<T>) {
+        let numbers = [
+            Some(&coord.x),
+            Some(&coord.y),
+            coord.z.as_ref(),
+            coord.m.as_ref(),
+        ];
+        for number in numbers.into_iter().flatten() {
+            if self.stride > 0 && self.numbers % self.stride == 0 {
+                let mut counter = Counter(0);
+                let _ = write!(counter, "{number}");
+                self.sampled += 1;
+                self.sampled_len += counter.0;
+            }
+            self.numbers += 1;
+        }
+        // The spaces between the numbers.
+        self.exact += coord.z.is_some() as usize + coord.m.is_some() as usize + 1;
+    }
+}
+
+/// A [`Write`] that only counts the bytes written to it.
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn check(input: &str) {
+        let wkt = Wkt::<f64>::from_str(input).unwrap();
+        let estimate = estimate_len(&wkt);
+        let written = wkt.to_string();
+        assert!(
+            (written.len()..=written.len() + written.len() / 4 + 16).contains(&estimate),
+            "{estimate} for {} bytes of {written}",
+            written.len()
+        );
+    }
+
+    #[test]
+    fn estimates_every_geometry_type() {
+        check("POINT(1 2)");
+        check("POINT EMPTY");
+        check("LINESTRING ZM(1 2 3 4,-5.5 6 7 8)");
+        check("LINEARRING(0 0,1 0,1 1,0 0)");
+        check("POLYGON((0 0,10 0,10 10,0 0),(1 1,2 1,2 2,1 1))");
+        check("MULTIPOINT((1 2),(3 4),EMPTY)");
+        check("MULTILINESTRING((1 2,3 4),EMPTY)");
+        check("MULTIPOLYGON(((0 0,1 0,1 1,0 0)),EMPTY,((5 5,6 5,6 6,5 5)))");
+        check("GEOMETRYCOLLECTION(POINT(1 2),GEOMETRYCOLLECTION EMPTY,LINESTRING(0 0,1 1))");
+    }
+
+    #[test]
+    fn big_geometry_needs_no_reallocation() {
+        let wkt = Wkt::<f64>::from_str(include_str!("../../benches/big.wkt")).unwrap();
+        let estimate = estimate_len(&wkt);
+        let written = wkt.wkt_string().unwrap();
+        assert!(written.len() <= estimate, "{} > {estimate}", written.len());
+        assert_eq!(written.capacity(), estimate);
+    }
+}
@@ -1,21 +1,30 @@
 //! Serialize geometries to WKT strings.
+//!
+//! Numbers are written with their [`Display`](std::fmt::Display) impl. For `f64` and `f32`
+//! that's the shortest decimal that parses back to the same value, with no exponent, e.g.
+//! `0.30000000000000004` or `1000000000000000000000`. It's computed by the standard library
+//! in pure Rust, so the same geometry is written byte for byte the same on every platform. The
+//! tests pin the output for edge cases, so a change in a new Rust version would be caught.
 
 use crate::{Wkt, WktNum};
 
 mod geo_trait_impl;
+mod len;
+mod wkt_impl;
 
 pub use geo_trait_impl::{
-    write_geometry, write_geometry_collection, write_line, write_linestring,
+    write_geometry, write_geometry_collection, write_line, write_linear_ring, write_linestring,
     write_multi_linestring, write_multi_point, write_multi_polygon, write_point, write_polygon,
     write_rect, write_triangle,
 };
+pub(crate) use wkt_impl::{write_wkt, write_wkt_geometry_collection};
 
 use crate::error::Error;
 use std::io;
 
 /// A wrapper around something that implements std::io::Write to be used with our writer traits,
 /// which require std::fmt::Write
-struct WriterWrapper<W: io::Write> {
+pub(crate) struct WriterWrapper<W: io::Write> {
     writer: W,
     most_recent_err: Option<io::Error>,
 }
@@ -31,7 +40,7 @@ impl<W: io::Write> WriterWrapper<W> {
 
 impl<W: io::Write> std::fmt::Write for WriterWrapper<W> {
     fn write_str(&mut self, s: &str) -> std::fmt::Result {
-        self.writer.write(s.as_bytes()).map_err(|err| {
+        self.writer.write_all(s.as_bytes()).map_err(|err| {
             self.most_recent_err = Some(err);
             std::fmt::Error
         })?;
@@ -39,6 +48,29 @@ impl<W: io::Write> std::fmt::Write for WriterWrapper<W> {
     }
 }
 
+impl<T> Wkt<T>
+where
+    T: WktNum + std::fmt::Display,
+{
+    /// Serialize as a WKT string, like [`to_string`](ToString::to_string) but allocating the
+    /// `String` once, at about the right size, instead of growing it as it's written.
+    ///
+    /// Unlike `to_string`, which panics when [`Display`](std::fmt::Display) fails, this returns
+    /// [`Error::MissingOrdinate`] for a coordinate with fewer values than its geometry's
+    /// dimension.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// let wkt = wkt::Wkt::<f64>::from_str("LINESTRING (1 2, 3 4)").unwrap();
+    /// assert_eq!(wkt.wkt_string().unwrap(), "LINESTRING(1 2,3 4)");
+    /// ```
+    pub fn wkt_string(&self) -> Result<String, Error> {
+        let mut string = String::with_capacity(len::estimate_len(self));
+        write_wkt(&mut string, self)?;
+        Ok(string)
+    }
+}
+
 /// A trait for converting values to WKT
 pub trait ToWkt<T>
 where
@@ -58,8 +90,27 @@ where
     /// let point: geo_types::Point<f64> = geo_types::point!(x: 1.2, y: 3.4);
     /// assert_eq!("POINT(1.2 3.4)", &point.wkt_string());
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// If [`to_wkt`](Self::to_wkt) returns a geometry that can't be written, which none of the
+    /// `geo-types` conversions do. Use [`try_wkt_string`](Self::try_wkt_string) to get the error
+    /// instead.
     fn wkt_string(&self) -> String {
-        self.to_wkt().to_string()
+        self.try_wkt_string().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Serialize as a WKT string, returning [`Error::MissingOrdinate`] if a coordinate has fewer
+    /// values than its geometry's dimension.
+    #[cfg_attr(feature = "geo-types", doc = "```")]
+    #[cfg_attr(not(feature = "geo-types"), doc = "```ignore")]
+    /// // This example requires the geo-types feature (on by default).
+    /// use wkt::ToWkt;
+    /// let point: geo_types::Point<f64> = geo_types::point!(x: 1.2, y: 3.4);
+    /// assert_eq!("POINT(1.2 3.4)", &point.try_wkt_string().unwrap());
+    /// ```
+    fn try_wkt_string(&self) -> Result<String, Error> {
+        self.to_wkt().wkt_string()
     }
 
     /// Write a WKT string to a [`File`](std::fs::File), or anything else that implements [`Write`](std::io::Write).
@@ -81,20 +132,27 @@ where
     /// assert_eq!(wkt_string, "POINT(1.2 3.4)");
     /// ```
     fn write_wkt(&self, writer: impl io::Write) -> io::Result<()> {
-        let mut writer_wrapper = WriterWrapper::new(writer);
-        write_geometry(&mut writer_wrapper, &self.to_wkt()).map_err(|err| {
-            match (err, writer_wrapper.most_recent_err) {
-                (Error::FmtError(_), Some(io_err)) => io_err,
-                (Error::FmtError(fmt_err), None) => {
-                    debug_assert!(false, "FmtError without setting an error on WriterWrapper");
-                    io::Error::new(io::ErrorKind::Other, fmt_err.to_string())
-                }
-                (other, _) => io::Error::new(io::ErrorKind::Other, other.to_string()),
-            }
-        })
+        write_to_io(writer, |f| write_wkt(f, &self.to_wkt()))
     }
 }
 
+/// Runs `write` against a [`std::fmt::Write`] adapter for `writer`, returning the
+/// [`io::Error`] that made it fail, if any.
+pub(crate) fn write_to_io<W: io::Write>(
+    writer: W,
+    write: impl FnOnce(&mut WriterWrapper<W>) -> Result<(), Error>,
+) -> io::Result<()> {
+    let mut writer_wrapper = WriterWrapper::new(writer);
+    write(&mut writer_wrapper).map_err(|err| match (err, writer_wrapper.most_recent_err) {
+        (Error::FmtError(_), Some(io_err)) => io_err,
+        (Error::FmtError(fmt_err), None) => {
+            debug_assert!(false, "FmtError without setting an error on WriterWrapper");
+            io::Error::other(fmt_err.to_string())
+        }
+        (other, _) => io::Error::other(other.to_string()),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,10 +163,7 @@ mod tests {
         struct FailingWriter;
         impl io::Write for FailingWriter {
             fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
-                Err(io::Error::new(
-                    io::ErrorKind::Other,
-                    "FailingWriter always fails",
-                ))
+                Err(io::Error::other("FailingWriter always fails"))
             }
 
             fn flush(&mut self) -> io::Result<()> {
@@ -120,4 +175,133 @@ mod tests {
         let err = point.write_wkt(FailingWriter).unwrap_err();
         assert_eq!(err.to_string(), "FailingWriter always fails");
     }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn write_wkt_handles_short_writes() {
+        /// Accepts at most three bytes per call, as a socket or pipe may.
+        struct ShortWriter(Vec<u8>);
+        impl io::Write for ShortWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                let len = buf.len().min(3);
+                self.0.extend_from_slice(&buf[..len]);
+                Ok(len)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let line_string = geo_types::LineString::from(vec![(1.25, 2.5), (30., 40.)]);
+        let mut writer = ShortWriter(Vec::new());
+        line_string.write_wkt(&mut writer).unwrap();
+        assert_eq!(writer.0, b"LINESTRING(1.25 2.5,30 40)");
+    }
+
+    #[test]
+    fn write_long_numbers() {
+        use std::str::FromStr;
+
+        // Each number is written as over 300 digits, more than fits in the coordinate buffer.
+        let wkt = Wkt::<f64>::from_str("LINESTRING ZM (1 2 3 4, 1e300 -1e300 0.5 1e-300)").unwrap();
+        let expected = format!("LINESTRING ZM(1 2 3 4,{} {} 0.5 {})", 1e300, -1e300, 1e-300);
+        assert_eq!(wkt.to_string(), expected);
+    }
+
+    #[test]
+    fn float_formatting_is_pinned() {
+        use crate::types::{Coord, LineString};
+
+        let cases: [(f64, String); 12] = [
+            (0.1 + 0.2, "0.30000000000000004".to_string()),
+            (-0., "-0".to_string()),
+            (2.5, "2.5".to_string()),
+            (1e-7, "0.0000001".to_string()),
+            (123456789.12345679, "123456789.12345679".to_string()),
+            (1e21, "1000000000000000000000".to_string()),
+            (f64::MAX, format!("17976931348623157{}", "0".repeat(292))),
+            (5e-324, format!("0.{}5", "0".repeat(323))),
+            (
+                f64::MIN_POSITIVE,
+                format!("0.{}22250738585072014", "0".repeat(307)),
+            ),
+            (f64::NAN, "NaN".to_string()),
+            (f64::INFINITY, "inf".to_string()),
+            (f64::NEG_INFINITY, "-inf".to_string()),
+        ];
+        for (value, expected) in cases {
+            let coord = Coord {
+                x: value,
+                y: value,
+                z: None,
+                m: None,
+            };
+            let wkt = Wkt::LineString(LineString(vec![coord]));
+            let expected = format!("LINESTRING({expected} {expected})");
+            assert_eq!(wkt.to_string(), expected);
+            assert_eq!(wkt.wkt_string().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn empty_members_round_trip() {
+        use std::str::FromStr;
+
+        for input in [
+            "MULTIPOINT((1 2),EMPTY)",
+            "MULTILINESTRING(EMPTY,(1 2,3 4))",
+            "MULTIPOLYGON(EMPTY,((0 0,1 0,1 1,0 0)))",
+            "POLYGON((0 0,1 0,1 1,0 0),EMPTY)",
+            "MULTIPOINT Z(EMPTY,(1 2 3))",
+            "MULTILINESTRING M(EMPTY,(1 2 3,4 5 6))",
+            "MULTIPOLYGON ZM(EMPTY,((0 0 0 0,1 0 0 0,1 1 0 0,0 0 0 0)))",
+        ] {
+            let wkt = Wkt::<f64>::from_str(input).unwrap();
+            assert_eq!(wkt.to_string(), input);
+            assert_eq!(Wkt::<f64>::from_str(&wkt.to_string()).unwrap(), wkt);
+        }
+    }
+
+    #[test]
+    fn missing_ordinate_is_an_error() {
+        use crate::types::{Coord, LineString};
+
+        let xyz = Coord {
+            x: 1.,
+            y: 2.,
+            z: Some(3.),
+            m: None,
+        };
+        let xy = Coord {
+            x: 4.,
+            y: 5.,
+            z: None,
+            m: None,
+        };
+        // The line string takes its dimension from the first coordinate.
+        let linestring = LineString(vec![xyz, xy]);
+        let mut output = String::new();
+        assert!(matches!(
+            write_linestring(&mut output, &linestring),
+            Err(Error::MissingOrdinate)
+        ));
+
+        let wkt = Wkt::LineString(linestring);
+        assert!(matches!(wkt.wkt_string(), Err(Error::MissingOrdinate)));
+        assert!(std::fmt::write(&mut String::new(), format_args!("{wkt}")).is_err());
+
+        struct Mixed(Wkt<f64>);
+        impl ToWkt<f64> for Mixed {
+            fn to_wkt(&self) -> Wkt<f64> {
+                self.0.clone()
+            }
+        }
+        let mixed = Mixed(wkt.clone());
+        assert!(matches!(
+            mixed.try_wkt_string(),
+            Err(Error::MissingOrdinate)
+        ));
+        assert!(mixed.write_wkt(Vec::new()).is_err());
+    }
 }
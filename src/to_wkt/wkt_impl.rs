@@ -0,0 +1,50 @@
+//! Writing this crate's own types, for the keywords that [`geo_traits`] has no type for.
+
+use std::fmt::{self, Write};
+
+use geo_traits::GeometryCollectionTrait;
+
+use super::geo_trait_impl::write_keyword;
+use super::{write_geometry, write_linear_ring};
+use crate::error::Error;
+use crate::types::{Dimension, GeometryCollection};
+use crate::{Wkt, WktNum};
+
+/// Write a [`Wkt`], keeping the `LINEARRING` keyword, which is written as `LINESTRING` when going
+/// through [`write_geometry`].
+pub(crate) fn write_wkt<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    wkt: &Wkt<T>,
+) -> Result<(), Error> {
+    match wkt {
+        Wkt::LinearRing(ring) => write_linear_ring(f, ring),
+        Wkt::GeometryCollection(collection) => write_wkt_geometry_collection(f, collection),
+        geometry => write_geometry(f, geometry),
+    }
+}
+
+/// [`write_geometry_collection`](super::write_geometry_collection), writing each member with
+/// [`write_wkt`].
+pub(crate) fn write_wkt_geometry_collection<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    collection: &GeometryCollection<T>,
+) -> Result<(), Error> {
+    let dim = Dimension::try_from(collection.dim())?;
+    write_keyword(f, "GEOMETRYCOLLECTION", dim)?;
+    let mut geometries = collection.0.iter();
+
+    if let Some(first_geometry) = geometries.next() {
+        f.write_str("(")?;
+
+        write_wkt(f, first_geometry)?;
+        for geom in geometries {
+            f.write_char(',')?;
+            write_wkt(f, geom)?;
+        }
+
+        f.write_char(')')?;
+    } else {
+        f.write_str(" EMPTY")?;
+    }
+    Ok(())
+}
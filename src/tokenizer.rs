@@ -12,211 +12,240 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::WktNum;
-use std::any::type_name;
-use std::iter::Peekable;
-use std::marker::PhantomData;
-use std::str;
+use std::str::{self, FromStr};
 
+/// A WKT token, borrowed from the input.
+///
+/// Numbers are kept as text, and only converted to the coordinate type by [`parse_number`], so
+/// the tokenizer isn't monomorphized for each coordinate type.
 #[derive(Debug, PartialEq, Eq)]
-pub enum Token<T>
-where
-    T: WktNum,
-{
+pub enum Token<'a> {
     Comma,
-    Number(T),
+    Number(&'a str),
     ParenClose,
     ParenOpen,
-    Word(String),
+    Word(&'a str),
+}
+
+/// Converts the text of a [`Token::Number`] into a coordinate value.
+pub fn parse_number<T: FromStr>(number: &str) -> Result<T, &'static str> {
+    number.parse().map_err(|_| {
+        #[cfg(feature = "log")]
+        log::warn!(
+            "Failed to parse input: '{}' as {}",
+            number,
+            std::any::type_name::<T>()
+        );
+        "Unable to parse input number as the desired output type"
+    })
 }
 
 #[inline]
-fn is_whitespace(c: char) -> bool {
-    c == ' ' || c == '\n' || c == '\r' || c == '\t'
+fn is_whitespace(b: u8) -> bool {
+    b == b' ' || b == b'\n' || b == b'\r' || b == b'\t'
 }
 
 #[inline]
-fn is_numberlike(c: char) -> bool {
-    c == '.' || c == '-' || c == '+' || c.is_ascii_digit()
+fn is_numberlike(b: u8) -> bool {
+    b == b'.' || b == b'-' || b == b'+' || b.is_ascii_digit()
 }
 
-pub type PeekableTokens<'a, T> = Peekable<Tokens<'a, T>>;
+/// The bytes that end a word or number: whitespace, the markers and `\0`.
+static IS_DELIMITER: [bool; 256] = {
+    let mut table = [false; 256];
+    let delimiters = *b" \n\r\t(),\0";
+    let mut i = 0;
+    while i < delimiters.len() {
+        table[delimiters[i] as usize] = true;
+        i += 1;
+    }
+    table
+};
 
+/// Splits WKT into tokens, scanning the input as bytes, with one token of lookahead.
+///
+/// All delimiters are ASCII, so word and number boundaries always fall on `char` boundaries.
+/// Tokenizing can't fail: numbers are only converted later, by [`parse_number`].
 #[derive(Debug)]
-pub struct Tokens<'a, T> {
-    chars: Peekable<str::Chars<'a>>,
-    phantom: PhantomData<T>,
+pub struct Tokens<'a> {
+    input: &'a str,
+    /// Where scanning continues, after the peeked token if there is one.
+    pos: usize,
+    /// The next token, if it has been peeked.
+    peeked: Option<Token<'a>>,
+    /// Where the token most recently read from the input starts, or the input's length once
+    /// there are none left.
+    last_start: usize,
 }
 
-impl<'a, T> Tokens<'a, T>
-where
-    T: WktNum,
-{
+impl<'a> Tokens<'a> {
     pub fn from_str(input: &'a str) -> Self {
         Tokens {
-            chars: input.chars().peekable(),
-            phantom: PhantomData,
+            input,
+            pos: 0,
+            peeked: None,
+            last_start: 0,
         }
     }
-}
 
-impl<T> Iterator for Tokens<'_, T>
-where
-    T: WktNum + str::FromStr,
-{
-    type Item = Result<Token<T>, &'static str>;
+    pub fn peek(&mut self) -> Option<&Token<'a>> {
+        if self.peeked.is_none() {
+            self.peeked = self.scan();
+        }
+        self.peeked.as_ref()
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        // TODO: should this return Result?
-        let mut next_char = self.chars.next()?;
+    /// The byte offset where the most recently read token starts, whether it was peeked or
+    /// returned by `next`, or the input's length once no tokens are left. This is the token a
+    /// parser that has just failed rejected.
+    pub fn last_position(&self) -> usize {
+        self.last_start
+    }
 
-        // Skip whitespace
-        while is_whitespace(next_char) {
-            next_char = self.chars.next()?
+    /// Estimates the number of comma separated items before the `)` that closes the current
+    /// list, by scanning the remaining input for top-level commas. Used to size `Vec`s up front.
+    pub fn estimate_items(&self) -> usize {
+        // A peeked token has already been read from the input, and a peeked `)` ends the list.
+        if let Some(Token::ParenClose) = self.peeked {
+            return 0;
+        }
+        let mut depth = 0usize;
+        let mut commas = 0;
+        for &byte in &self.input.as_bytes()[self.pos..] {
+            match byte {
+                b'(' => depth += 1,
+                b')' if depth == 0 => break,
+                b')' => depth -= 1,
+                b',' if depth == 0 => commas += 1,
+                _ => {}
+            }
         }
+        commas + 1
+    }
+
+    /// Reads the token after [`pos`](Self::pos).
+    fn scan(&mut self) -> Option<Token<'a>> {
+        let bytes = self.input.as_bytes();
 
-        let token = match next_char {
-            '\0' => return None,
-            '(' => Token::ParenOpen,
-            ')' => Token::ParenClose,
-            ',' => Token::Comma,
-            c if is_numberlike(c) => {
-                let number = self.read_until_whitespace(if c == '+' { None } else { Some(c) });
-                match number.parse::<T>() {
-                    Ok(parsed_num) => Token::Number(parsed_num),
-                    Err(_) => {
-                        log::warn!(
-                            "Failed to parse input: '{}' as {}",
-                            &number,
-                            type_name::<T>()
-                        );
-                        return Some(Err(
-                            "Unable to parse input number as the desired output type",
-                        ));
-                    }
-                }
+        // Skip whitespace
+        let Some(len) = bytes[self.pos..].iter().position(|&b| !is_whitespace(b)) else {
+            self.last_start = bytes.len();
+            return None;
+        };
+        let start = self.pos + len;
+        self.last_start = start;
+        self.pos = start + 1;
+
+        let token = match bytes[start] {
+            b'\0' => {
+                // Nothing after a NUL is read, so it stays the end of the input.
+                self.pos = start;
+                return None;
+            }
+            b'(' => Token::ParenOpen,
+            b')' => Token::ParenClose,
+            b',' => Token::Comma,
+            b if is_numberlike(b) => {
+                // A leading `+` isn't accepted by `FromStr` for floats, so skip it.
+                let start = if b == b'+' { start + 1 } else { start };
+                Token::Number(self.read_until_delimiter(start))
             }
-            c => Token::Word(self.read_until_whitespace(Some(c))),
+            _ => Token::Word(self.read_until_delimiter(start)),
         };
-        Some(Ok(token))
+        Some(token)
     }
-}
 
-impl<T> Tokens<'_, T>
-where
-    T: str::FromStr,
-{
-    fn read_until_whitespace(&mut self, first_char: Option<char>) -> String {
-        let mut result = String::with_capacity(12); // Big enough for most tokens
-        if let Some(c) = first_char {
-            result.push(c);
-        }
+    /// Reads a word or number from `start` up to the next delimiter, which isn't consumed.
+    fn read_until_delimiter(&mut self, start: usize) -> &'a str {
+        let bytes = self.input.as_bytes();
+        let len = bytes[self.pos..]
+            .iter()
+            .position(|&b| IS_DELIMITER[b as usize])
+            .unwrap_or(bytes.len() - self.pos);
+        self.pos += len;
+        &self.input[start..self.pos]
+    }
+}
 
-        while let Some(&next_char) = self.chars.peek() {
-            match next_char {
-                '\0' | '(' | ')' | ',' => break, // Just stop on a marker
-                c if is_whitespace(c) => {
-                    let _ = self.chars.next();
-                    break;
-                }
-                _ => {
-                    result.push(next_char);
-                    let _ = self.chars.next();
-                }
-            }
-        }
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
 
-        result
+    fn next(&mut self) -> Option<Self::Item> {
+        self.peeked.take().or_else(|| self.scan())
     }
 }
 
 #[test]
 fn test_tokenizer_empty() {
     let test_str = "";
-    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
-    let tokens = tokens.unwrap();
+    let tokens: Vec<Token> = Tokens::from_str(test_str).collect();
     assert_eq!(tokens, vec![]);
 }
 
 #[test]
 fn test_tokenizer_1word() {
     let test_str = "hello";
-    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
-    let tokens = tokens.unwrap();
-    assert_eq!(tokens, vec![Token::Word("hello".to_string())]);
+    let tokens: Vec<Token> = Tokens::from_str(test_str).collect();
+    assert_eq!(tokens, vec![Token::Word("hello")]);
 }
 
 #[test]
 fn test_tokenizer_2words() {
     let test_str = "hello world";
-    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
-    let tokens = tokens.unwrap();
-    assert_eq!(
-        tokens,
-        vec![
-            Token::Word("hello".to_string()),
-            Token::Word("world".to_string()),
-        ]
-    );
+    let tokens: Vec<Token> = Tokens::from_str(test_str).collect();
+    assert_eq!(tokens, vec![Token::Word("hello"), Token::Word("world"),]);
 }
 
 #[test]
 fn test_tokenizer_1number() {
     let test_str = "4.2";
-    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
-    let tokens = tokens.unwrap();
-    assert_eq!(tokens, vec![Token::Number(4.2)]);
+    let tokens: Vec<Token> = Tokens::from_str(test_str).collect();
+    assert_eq!(tokens, vec![Token::Number("4.2")]);
 }
 
 #[test]
 fn test_tokenizer_1number_plus() {
     let test_str = "+4.2";
-    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
-    let tokens = tokens.unwrap();
-    assert_eq!(tokens, vec![Token::Number(4.2)]);
+    let tokens: Vec<Token> = Tokens::from_str(test_str).collect();
+    assert_eq!(tokens, vec![Token::Number("4.2")]);
 }
 
 #[test]
 fn test_tokenizer_invalid_number() {
     let test_str = "4.2p";
-    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
-    let tokens = tokens.unwrap_err();
+    let tokens: Vec<Token> = Tokens::from_str(test_str).collect();
+    assert_eq!(tokens, vec![Token::Number("4.2p")]);
     assert_eq!(
-        tokens,
-        "Unable to parse input number as the desired output type"
+        parse_number::<f64>("4.2p"),
+        Err("Unable to parse input number as the desired output type")
     );
+    assert_eq!(parse_number::<f64>("4.2"), Ok(4.2));
 }
 
 #[test]
 fn test_tokenizer_not_a_number() {
     let test_str = "¾"; // A number according to char.is_numeric()
-    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
-    let tokens = tokens.unwrap();
-    assert_eq!(tokens, vec![Token::Word("¾".to_owned())]);
+    let tokens: Vec<Token> = Tokens::from_str(test_str).collect();
+    assert_eq!(tokens, vec![Token::Word("¾")]);
 }
 
 #[test]
 fn test_tokenizer_2numbers() {
     let test_str = ".4 -2";
-    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
-    let tokens = tokens.unwrap();
-    assert_eq!(tokens, vec![Token::Number(0.4), Token::Number(-2.0)]);
+    let tokens: Vec<Token> = Tokens::from_str(test_str).collect();
+    assert_eq!(tokens, vec![Token::Number(".4"), Token::Number("-2")]);
 }
 
 #[test]
 fn test_no_stack_overflow() {
     fn check(c: &str, count: usize, expected: usize) {
         let test_str = c.repeat(count);
-        assert_eq!(
-            expected,
-            Tokens::<f64>::from_str(&test_str)
-                .filter(Result::is_ok)
-                .count()
-        );
+        assert_eq!(expected, Tokens::from_str(&test_str).count());
     }
 
     let count = 100_000;
-    check("+", count, 0);
+    // A single number token, which fails to convert.
+    check("+", count, 1);
     check(" ", count, 0);
     check("A", count, 1);
     check("1", count, 1);
@@ -225,18 +254,65 @@ fn test_no_stack_overflow() {
     check(",", count, count);
 }
 
+#[test]
+fn test_tokenizer_delimiters() {
+    let test_str = "é(1,\t+2)\0 ignored";
+    let tokens: Vec<Token> = Tokens::from_str(test_str).collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Word("é"),
+            Token::ParenOpen,
+            Token::Number("1"),
+            Token::Comma,
+            Token::Number("2"),
+            Token::ParenClose,
+        ]
+    );
+}
+
+#[test]
+fn test_estimate_items() {
+    let mut tokens = Tokens::from_str("((1 2, 3 4), (5 6)), (7 8)");
+    assert_eq!(tokens.estimate_items(), 2);
+    tokens.next();
+    assert_eq!(tokens.estimate_items(), 2);
+    tokens.next();
+    assert_eq!(tokens.estimate_items(), 2);
+    assert_eq!(tokens.peek(), Some(&Token::Number("1")));
+    assert_eq!(tokens.estimate_items(), 2);
+
+    let mut tokens = Tokens::from_str(")");
+    tokens.peek();
+    assert_eq!(tokens.estimate_items(), 0);
+}
+
+#[test]
+fn test_last_position() {
+    let mut tokens = Tokens::from_str("POINT ( 1\t2 )  ");
+    assert_eq!(tokens.last_position(), 0);
+    tokens.next();
+    tokens.next();
+    assert_eq!(tokens.last_position(), 6);
+    assert_eq!(tokens.peek(), Some(&Token::Number("1")));
+    assert_eq!(tokens.last_position(), 8);
+    tokens.next();
+    assert_eq!(tokens.last_position(), 8);
+    assert_eq!(tokens.by_ref().count(), 2);
+    assert_eq!(tokens.last_position(), 15);
+}
+
 #[test]
 fn test_tokenizer_point() {
     let test_str = "POINT (10 -20)";
-    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
-    let tokens = tokens.unwrap();
+    let tokens: Vec<Token> = Tokens::from_str(test_str).collect();
     assert_eq!(
         tokens,
         vec![
-            Token::Word("POINT".to_string()),
+            Token::Word("POINT"),
             Token::ParenOpen,
-            Token::Number(10.0),
-            Token::Number(-20.0),
+            Token::Number("10"),
+            Token::Number("-20"),
             Token::ParenClose,
         ]
     );
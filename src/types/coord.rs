@@ -14,12 +14,12 @@
 
 use geo_traits::CoordTrait;
 
-use crate::tokenizer::{PeekableTokens, Token};
+use crate::tokenizer::{parse_number, Token, Tokens};
 use crate::types::Dimension;
 use crate::{FromTokens, WktNum};
 use std::str::FromStr;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Coord<T>
 where
     T: WktNum,
@@ -30,17 +30,71 @@ where
     pub m: Option<T>,
 }
 
+impl<T: WktNum> Coord<T> {
+    /// A coordinate with only X and Y values.
+    ///
+    /// ```
+    /// use wkt::types::{Coord, Dimension};
+    ///
+    /// const ORIGIN: Coord<f64> = Coord::xy(0., 0.);
+    /// assert_eq!(ORIGIN.dimension(), Dimension::XY);
+    /// ```
+    pub const fn xy(x: T, y: T) -> Self {
+        Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+        }
+    }
+
+    /// A coordinate with X, Y and Z values.
+    pub const fn xyz(x: T, y: T, z: T) -> Self {
+        Coord {
+            x,
+            y,
+            z: Some(z),
+            m: None,
+        }
+    }
+
+    /// A coordinate with X, Y and M values.
+    pub const fn xym(x: T, y: T, m: T) -> Self {
+        Coord {
+            x,
+            y,
+            z: None,
+            m: Some(m),
+        }
+    }
+
+    /// A coordinate with X, Y, Z and M values.
+    pub const fn xyzm(x: T, y: T, z: T, m: T) -> Self {
+        Coord {
+            x,
+            y,
+            z: Some(z),
+            m: Some(m),
+        }
+    }
+
+    /// The dimension of this coordinate, from which of `z` and `m` are set.
+    pub const fn dimension(&self) -> Dimension {
+        Dimension::from_zm(self.z.is_some(), self.m.is_some())
+    }
+}
+
 impl<T> FromTokens<T> for Coord<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
-        let x = match tokens.next().transpose()? {
-            Some(Token::Number(n)) => n,
+    fn from_tokens(tokens: &mut Tokens<'_>, dim: Dimension) -> Result<Self, &'static str> {
+        let x = match tokens.next() {
+            Some(Token::Number(n)) => parse_number(n)?,
             _ => return Err("Expected a number for the X coordinate"),
         };
-        let y = match tokens.next().transpose()? {
-            Some(Token::Number(n)) => n,
+        let y = match tokens.next() {
+            Some(Token::Number(n)) => parse_number(n)?,
             _ => return Err("Expected a number for the Y coordinate"),
         };
 
@@ -49,28 +103,28 @@ where
 
         match dim {
             Dimension::XY => (),
-            Dimension::XYZ => match tokens.next().transpose()? {
+            Dimension::XYZ => match tokens.next() {
                 Some(Token::Number(n)) => {
-                    z = Some(n);
+                    z = Some(parse_number(n)?);
                 }
                 _ => return Err("Expected a number for the Z coordinate"),
             },
-            Dimension::XYM => match tokens.next().transpose()? {
+            Dimension::XYM => match tokens.next() {
                 Some(Token::Number(n)) => {
-                    m = Some(n);
+                    m = Some(parse_number(n)?);
                 }
                 _ => return Err("Expected a number for the M coordinate"),
             },
             Dimension::XYZM => {
-                match tokens.next().transpose()? {
+                match tokens.next() {
                     Some(Token::Number(n)) => {
-                        z = Some(n);
+                        z = Some(parse_number(n)?);
                     }
                     _ => return Err("Expected a number for the Z coordinate"),
                 }
-                match tokens.next().transpose()? {
+                match tokens.next() {
                     Some(Token::Number(n)) => {
-                        m = Some(n);
+                        m = Some(parse_number(n)?);
                     }
                     _ => return Err("Expected a number for the M coordinate"),
                 }
@@ -81,6 +135,38 @@ where
     }
 }
 
+/// Parses a comma separated sequence of 2D coordinates, like
+/// [`comma_many`](FromTokens::comma_many) with [`Coord::from_tokens`] but without checking the
+/// dimension for each coordinate.
+pub(crate) fn xy_coords_from_tokens<T>(
+    tokens: &mut Tokens<'_>,
+) -> Result<Vec<Coord<T>>, &'static str>
+where
+    T: WktNum + FromStr + Default,
+{
+    let mut coords = Vec::with_capacity(tokens.estimate_items());
+    loop {
+        let x = match tokens.next() {
+            Some(Token::Number(n)) => parse_number(n)?,
+            _ => return Err("Expected a number for the X coordinate"),
+        };
+        let y = match tokens.next() {
+            Some(Token::Number(n)) => parse_number(n)?,
+            _ => return Err("Expected a number for the Y coordinate"),
+        };
+        coords.push(Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+        });
+        match tokens.peek() {
+            Some(Token::Comma) => tokens.next(),
+            _ => return Ok(coords),
+        };
+    }
+}
+
 impl<T: WktNum> CoordTrait for Coord<T> {
     type T = T;
 
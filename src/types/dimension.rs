@@ -1,3 +1,13 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+use crate::types::{
+    Coord, GeometryCollection, LineString, LinearRing, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use crate::{Wkt, WktNum};
+
 /// The dimension of geometry that we're parsing.
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
@@ -8,3 +18,306 @@ pub enum Dimension {
     XYM,
     XYZM,
 }
+
+impl Dimension {
+    /// Whether coordinates of this dimension have a Z value.
+    pub const fn has_z(&self) -> bool {
+        matches!(self, Dimension::XYZ | Dimension::XYZM)
+    }
+
+    /// Whether coordinates of this dimension have an M value.
+    pub const fn has_m(&self) -> bool {
+        matches!(self, Dimension::XYM | Dimension::XYZM)
+    }
+
+    /// The number of values in each coordinate, from 2 to 4.
+    pub const fn size(&self) -> usize {
+        match self {
+            Dimension::XY => 2,
+            Dimension::XYZ | Dimension::XYM => 3,
+            Dimension::XYZM => 4,
+        }
+    }
+
+    /// The dimension with the given Z and M values.
+    pub const fn from_zm(has_z: bool, has_m: bool) -> Self {
+        match (has_z, has_m) {
+            (false, false) => Dimension::XY,
+            (true, false) => Dimension::XYZ,
+            (false, true) => Dimension::XYM,
+            (true, true) => Dimension::XYZM,
+        }
+    }
+
+    /// Parses the dimension tag that follows a WKT keyword, e.g. the `ZM` in `POINT ZM`. An empty
+    /// suffix is `XY`. Case is ignored.
+    ///
+    /// ```
+    /// use wkt::types::Dimension;
+    ///
+    /// assert_eq!(Dimension::from_suffix("ZM"), Some(Dimension::XYZM));
+    /// assert_eq!(Dimension::from_suffix("m"), Some(Dimension::XYM));
+    /// assert_eq!(Dimension::from_suffix(""), Some(Dimension::XY));
+    /// assert_eq!(Dimension::from_suffix("MZ"), None);
+    /// ```
+    pub fn from_suffix(suffix: &str) -> Option<Self> {
+        match suffix {
+            "" => Some(Dimension::XY),
+            s if s.eq_ignore_ascii_case("Z") => Some(Dimension::XYZ),
+            s if s.eq_ignore_ascii_case("M") => Some(Dimension::XYM),
+            s if s.eq_ignore_ascii_case("ZM") => Some(Dimension::XYZM),
+            _ => None,
+        }
+    }
+
+    /// The dimension tag written after a WKT keyword: `""`, `"Z"`, `"M"` or `"ZM"`.
+    pub const fn suffix(&self) -> &'static str {
+        match self {
+            Dimension::XY => "",
+            Dimension::XYZ => "Z",
+            Dimension::XYM => "M",
+            Dimension::XYZM => "ZM",
+        }
+    }
+}
+
+impl fmt::Display for Dimension {
+    /// Writes the name of the dimension, e.g. `XYZ`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let name = match self {
+            Dimension::XY => "XY",
+            Dimension::XYZ => "XYZ",
+            Dimension::XYM => "XYM",
+            Dimension::XYZM => "XYZM",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Dimension {
+    type Err = &'static str;
+
+    /// Parses the name of a dimension, e.g. `XYZ`, ignoring ASCII case.
+    ///
+    /// Use [`Dimension::from_suffix`] to parse a WKT dimension tag like `Z`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            s if s.eq_ignore_ascii_case("XY") => Ok(Dimension::XY),
+            s if s.eq_ignore_ascii_case("XYZ") => Ok(Dimension::XYZ),
+            s if s.eq_ignore_ascii_case("XYM") => Ok(Dimension::XYM),
+            s if s.eq_ignore_ascii_case("XYZM") => Ok(Dimension::XYZM),
+            _ => Err("Invalid dimension"),
+        }
+    }
+}
+
+impl From<Dimension> for geo_traits::Dimensions {
+    fn from(value: Dimension) -> Self {
+        match value {
+            Dimension::XY => geo_traits::Dimensions::Xy,
+            Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
+            Dimension::XYZM => geo_traits::Dimensions::Xyzm,
+        }
+    }
+}
+
+impl TryFrom<geo_traits::Dimensions> for Dimension {
+    type Error = Error;
+
+    /// Unknown dimensions of size 3 are assumed to be `XYZ`.
+    fn try_from(value: geo_traits::Dimensions) -> Result<Self, Self::Error> {
+        match value {
+            geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => Ok(Dimension::XY),
+            geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => Ok(Dimension::XYZ),
+            geo_traits::Dimensions::Xym => Ok(Dimension::XYM),
+            geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
+                Ok(Dimension::XYZM)
+            }
+            geo_traits::Dimensions::Unknown(_) => Err(Error::UnknownDimension),
+        }
+    }
+}
+
+/// The single dimension shared by every coordinate of a geometry, used by the checked
+/// constructors.
+pub(crate) trait HomogeneousDimension {
+    /// `Ok(None)` if the geometry is empty, or an error with the index of the first element whose
+    /// dimension differs from the ones before it.
+    fn homogeneous_dim(&self) -> Result<Option<Dimension>, Error>;
+}
+
+/// The shared dimension of `items`. Empty items are skipped, and an item that is itself mixed is
+/// reported at its own index.
+pub(crate) fn homogeneous_dim<G: HomogeneousDimension>(
+    items: &[G],
+) -> Result<Option<Dimension>, Error> {
+    let mut expected = None;
+    for (index, item) in items.iter().enumerate() {
+        check_dim(item, index, &mut expected)?;
+    }
+    Ok(expected)
+}
+
+/// Appends `new` to `items` if it has the dimension of the items already there, which must agree
+/// with each other. On error, `items` is left unchanged and the index counts from its start.
+pub(crate) fn try_extend<G: HomogeneousDimension>(
+    items: &mut Vec<G>,
+    new: impl IntoIterator<Item = G>,
+) -> Result<(), Error> {
+    let mut expected = items
+        .iter()
+        .find_map(|item| item.homogeneous_dim().ok().flatten());
+    let start = items.len();
+    items.extend(new);
+    let result = items[start..]
+        .iter()
+        .enumerate()
+        .try_for_each(|(index, item)| check_dim(item, start + index, &mut expected));
+    if result.is_err() {
+        items.truncate(start);
+    }
+    result
+}
+
+/// Checks the item at `index` against the dimension of the ones before it, setting `expected`
+/// from the first that isn't empty.
+fn check_dim<G: HomogeneousDimension>(
+    item: &G,
+    index: usize,
+    expected: &mut Option<Dimension>,
+) -> Result<(), Error> {
+    let found = match item.homogeneous_dim() {
+        Ok(Some(found)) => found,
+        Ok(None) => return Ok(()),
+        Err(Error::MixedDimension {
+            expected, found, ..
+        }) => {
+            return Err(Error::MixedDimension {
+                index,
+                expected,
+                found,
+            })
+        }
+        Err(err) => return Err(err),
+    };
+    match *expected {
+        None => *expected = Some(found),
+        Some(expected) if expected != found => {
+            return Err(Error::MixedDimension {
+                index,
+                expected,
+                found,
+            })
+        }
+        Some(_) => (),
+    }
+    Ok(())
+}
+
+impl<T: WktNum> HomogeneousDimension for Coord<T> {
+    fn homogeneous_dim(&self) -> Result<Option<Dimension>, Error> {
+        Ok(Some(self.dimension()))
+    }
+}
+
+impl<T: WktNum> HomogeneousDimension for Point<T> {
+    fn homogeneous_dim(&self) -> Result<Option<Dimension>, Error> {
+        Ok(self.0.as_ref().map(Coord::dimension))
+    }
+}
+
+macro_rules! impl_homogeneous_dimension_for_vec_wrapper {
+    ($($type: ident),+) => {
+        $(
+            impl<T: WktNum> HomogeneousDimension for $type<T> {
+                fn homogeneous_dim(&self) -> Result<Option<Dimension>, Error> {
+                    homogeneous_dim(&self.0)
+                }
+            }
+        )+
+    };
+}
+
+impl_homogeneous_dimension_for_vec_wrapper!(
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+impl<T: WktNum> HomogeneousDimension for LinearRing<T> {
+    fn homogeneous_dim(&self) -> Result<Option<Dimension>, Error> {
+        self.0.homogeneous_dim()
+    }
+}
+
+impl<T: WktNum> HomogeneousDimension for Wkt<T> {
+    fn homogeneous_dim(&self) -> Result<Option<Dimension>, Error> {
+        match self {
+            Wkt::Point(g) => g.homogeneous_dim(),
+            Wkt::LineString(g) => g.homogeneous_dim(),
+            Wkt::LinearRing(g) => g.homogeneous_dim(),
+            Wkt::Polygon(g) => g.homogeneous_dim(),
+            Wkt::MultiPoint(g) => g.homogeneous_dim(),
+            Wkt::MultiLineString(g) => g.homogeneous_dim(),
+            Wkt::MultiPolygon(g) => g.homogeneous_dim(),
+            Wkt::GeometryCollection(g) => g.homogeneous_dim(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dimension;
+    use std::str::FromStr;
+
+    const ALL: [Dimension; 4] = [
+        Dimension::XY,
+        Dimension::XYZ,
+        Dimension::XYM,
+        Dimension::XYZM,
+    ];
+
+    #[test]
+    fn helpers() {
+        assert!(!Dimension::XY.has_z() && !Dimension::XY.has_m());
+        assert!(Dimension::XYZ.has_z() && !Dimension::XYZ.has_m());
+        assert!(!Dimension::XYM.has_z() && Dimension::XYM.has_m());
+        assert!(Dimension::XYZM.has_z() && Dimension::XYZM.has_m());
+
+        for dim in ALL {
+            assert_eq!(Dimension::from_zm(dim.has_z(), dim.has_m()), dim);
+            assert_eq!(dim.size(), 2 + dim.has_z() as usize + dim.has_m() as usize);
+        }
+    }
+
+    #[test]
+    fn suffix_round_trip() {
+        for dim in ALL {
+            assert_eq!(Dimension::from_suffix(dim.suffix()), Some(dim));
+        }
+        assert_eq!(Dimension::from_suffix("zm"), Some(Dimension::XYZM));
+        assert_eq!(Dimension::from_suffix("XYZ"), None);
+    }
+
+    #[test]
+    fn display_and_from_str_round_trip() {
+        for dim in ALL {
+            assert_eq!(Dimension::from_str(&dim.to_string()), Ok(dim));
+        }
+        assert_eq!(Dimension::from_str("xyzm"), Ok(Dimension::XYZM));
+        assert!(Dimension::from_str("ZM").is_err());
+    }
+
+    #[test]
+    fn geo_traits_round_trip() {
+        for dim in ALL {
+            let geo_dim: geo_traits::Dimensions = dim.into();
+            assert_eq!(Dimension::try_from(geo_dim).unwrap(), dim);
+        }
+        assert!(Dimension::try_from(geo_traits::Dimensions::Unknown(5)).is_err());
+    }
+}
@@ -1,11 +1,188 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::types::Dimension;
+
 /// The geometry type of the WKT object
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum GeometryType {
     Point,
     LineString,
+    LinearRing,
     Polygon,
     MultiPoint,
     MultiLineString,
     MultiPolygon,
     GeometryCollection,
+    // The SQL/MM types below are recognized by `infer_type`, but can't be parsed into a `Wkt` yet.
+    CircularString,
+    CompoundCurve,
+    CurvePolygon,
+    MultiCurve,
+    MultiSurface,
+    PolyhedralSurface,
+    Tin,
+    Triangle,
+}
+
+impl GeometryType {
+    /// Whether this is one of the extended SQL/MM types, like `CIRCULARSTRING`, which
+    /// [`infer_type`](crate::infer_type) recognizes but which are not supported by [`Wkt`](crate::Wkt).
+    pub fn is_extended(self) -> bool {
+        matches!(
+            self,
+            GeometryType::CircularString
+                | GeometryType::CompoundCurve
+                | GeometryType::CurvePolygon
+                | GeometryType::MultiCurve
+                | GeometryType::MultiSurface
+                | GeometryType::PolyhedralSurface
+                | GeometryType::Tin
+                | GeometryType::Triangle
+        )
+    }
+
+    /// Splits a keyword like `POINT` or `POINTZ` into its type and, if attached, its dimension.
+    ///
+    /// The keyword is uppercased once and matched as bytes, since this runs for every geometry
+    /// parsed.
+    pub(crate) fn from_keyword(word: &str) -> Option<(Self, Option<Dimension>)> {
+        let mut buf = [0; MAX_KEYWORD_LEN];
+        let upper = buf.get_mut(..word.len())?;
+        upper.copy_from_slice(word.as_bytes());
+        upper.make_ascii_uppercase();
+
+        // No keyword ends in Z or M, so a trailing one is always a dimension tag.
+        let (keyword, dim) = match upper {
+            [keyword @ .., b'Z', b'M'] => (&*keyword, Some(Dimension::XYZM)),
+            [keyword @ .., b'Z'] => (&*keyword, Some(Dimension::XYZ)),
+            [keyword @ .., b'M'] => (&*keyword, Some(Dimension::XYM)),
+            keyword => (&*keyword, None),
+        };
+        Self::from_upper(keyword).map(|geometry_type| (geometry_type, dim))
+    }
+
+    fn from_upper(keyword: &[u8]) -> Option<Self> {
+        let geometry_type = match keyword {
+            b"POINT" => GeometryType::Point,
+            b"LINESTRING" => GeometryType::LineString,
+            b"LINEARRING" => GeometryType::LinearRing,
+            b"POLYGON" => GeometryType::Polygon,
+            b"MULTIPOINT" => GeometryType::MultiPoint,
+            b"MULTILINESTRING" => GeometryType::MultiLineString,
+            b"MULTIPOLYGON" => GeometryType::MultiPolygon,
+            b"GEOMETRYCOLLECTION" => GeometryType::GeometryCollection,
+            b"CIRCULARSTRING" => GeometryType::CircularString,
+            b"COMPOUNDCURVE" => GeometryType::CompoundCurve,
+            b"CURVEPOLYGON" => GeometryType::CurvePolygon,
+            b"MULTICURVE" => GeometryType::MultiCurve,
+            b"MULTISURFACE" => GeometryType::MultiSurface,
+            b"POLYHEDRALSURFACE" => GeometryType::PolyhedralSurface,
+            b"TIN" => GeometryType::Tin,
+            b"TRIANGLE" => GeometryType::Triangle,
+            _ => return None,
+        };
+        Some(geometry_type)
+    }
+}
+
+/// The length of the longest keyword, `GEOMETRYCOLLECTIONZM`.
+const MAX_KEYWORD_LEN: usize = 20;
+
+impl fmt::Display for GeometryType {
+    /// Writes the WKT keyword for this geometry type, e.g. `POINT`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let keyword = match self {
+            GeometryType::Point => "POINT",
+            GeometryType::LineString => "LINESTRING",
+            GeometryType::LinearRing => "LINEARRING",
+            GeometryType::Polygon => "POLYGON",
+            GeometryType::MultiPoint => "MULTIPOINT",
+            GeometryType::MultiLineString => "MULTILINESTRING",
+            GeometryType::MultiPolygon => "MULTIPOLYGON",
+            GeometryType::GeometryCollection => "GEOMETRYCOLLECTION",
+            GeometryType::CircularString => "CIRCULARSTRING",
+            GeometryType::CompoundCurve => "COMPOUNDCURVE",
+            GeometryType::CurvePolygon => "CURVEPOLYGON",
+            GeometryType::MultiCurve => "MULTICURVE",
+            GeometryType::MultiSurface => "MULTISURFACE",
+            GeometryType::PolyhedralSurface => "POLYHEDRALSURFACE",
+            GeometryType::Tin => "TIN",
+            GeometryType::Triangle => "TRIANGLE",
+        };
+        f.write_str(keyword)
+    }
+}
+
+impl FromStr for GeometryType {
+    type Err = &'static str;
+
+    /// Parses a WKT keyword such as `POINT` or `multipolygon`, ignoring ASCII case.
+    ///
+    /// Dimension suffixes like `POINT Z` are not accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match GeometryType::from_keyword(s) {
+            Some((geometry_type, None)) => Ok(geometry_type),
+            _ => Err("Invalid geometry type"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeometryType;
+    use crate::types::Dimension;
+    use std::str::FromStr;
+
+    #[test]
+    fn display_and_from_str_round_trip() {
+        for geometry_type in [
+            GeometryType::Point,
+            GeometryType::LineString,
+            GeometryType::LinearRing,
+            GeometryType::Polygon,
+            GeometryType::MultiPoint,
+            GeometryType::MultiLineString,
+            GeometryType::MultiPolygon,
+            GeometryType::GeometryCollection,
+            GeometryType::CircularString,
+            GeometryType::CompoundCurve,
+            GeometryType::CurvePolygon,
+            GeometryType::MultiCurve,
+            GeometryType::MultiSurface,
+            GeometryType::PolyhedralSurface,
+            GeometryType::Tin,
+            GeometryType::Triangle,
+        ] {
+            let keyword = geometry_type.to_string();
+            assert_eq!(GeometryType::from_str(&keyword), Ok(geometry_type));
+        }
+    }
+
+    #[test]
+    fn from_str_ignores_case() {
+        assert_eq!(
+            GeometryType::from_str("multiLineString"),
+            Ok(GeometryType::MultiLineString)
+        );
+    }
+
+    #[test]
+    fn from_keyword_splits_dimension() {
+        assert_eq!(
+            GeometryType::from_keyword("multipointzm"),
+            Some((GeometryType::MultiPoint, Some(Dimension::XYZM)))
+        );
+        assert_eq!(
+            GeometryType::from_keyword("TIN"),
+            Some((GeometryType::Tin, None))
+        );
+        assert_eq!(GeometryType::from_keyword("POINTX"), None);
+    }
+
+    #[test]
+    fn from_str_rejects_unknown() {
+        assert!(GeometryType::from_str("POINT Z").is_err());
+        assert!(GeometryType::from_str("CIRCLE").is_err());
+    }
 }
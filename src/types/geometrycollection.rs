@@ -14,16 +14,159 @@
 
 use geo_traits::{GeometryCollectionTrait, GeometryTrait};
 
-use crate::to_wkt::write_geometry_collection;
-use crate::tokenizer::{PeekableTokens, Token};
+use crate::error::Error;
+use crate::to_wkt::write_wkt_geometry_collection;
+use crate::tokenizer::{Token, Tokens};
+use crate::types::dimension::{homogeneous_dim, try_extend};
 use crate::types::Dimension;
 use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+/// What [`GeometryCollection::try_from_geometries`] does with members of different dimensions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DimensionPolicy {
+    /// Return [`Error::MixedDimension`].
+    #[default]
+    Reject,
+    /// Give every coordinate the Z and M values that any coordinate has, setting the missing ones
+    /// to zero.
+    Promote,
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 pub struct GeometryCollection<T: WktNum>(pub Vec<Wkt<T>>);
 
+impl<T: WktNum> GeometryCollection<T> {
+    /// An empty `GeometryCollection`, with no members, written as `EMPTY`.
+    pub const fn empty() -> Self {
+        GeometryCollection(Vec::new())
+    }
+
+    /// Creates a `GeometryCollection` from geometries that must all have the same dimension, or are
+    /// promoted to a shared one, depending on `policy`.
+    ///
+    /// Empty geometries are ignored. With [`DimensionPolicy::Reject`], returns
+    /// [`Error::MixedDimension`] with the index of the first geometry that disagrees with the ones
+    /// before it, including one that is itself mixed.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::types::{DimensionPolicy, GeometryCollection};
+    /// use wkt::Wkt;
+    ///
+    /// let geometries = ["POINT Z (1 2 3)", "POINT M (4 5 6)", "POINT EMPTY"]
+    ///     .map(|wkt| Wkt::<f64>::from_str(wkt).unwrap());
+    /// assert!(GeometryCollection::try_from_geometries(geometries.clone(), DimensionPolicy::Reject)
+    ///     .is_err());
+    ///
+    /// let collection =
+    ///     GeometryCollection::try_from_geometries(geometries, DimensionPolicy::Promote).unwrap();
+    /// assert_eq!(
+    ///     collection.to_string(),
+    ///     "GEOMETRYCOLLECTION ZM(POINT ZM(1 2 3 0),POINT ZM(4 5 0 6),POINT EMPTY)"
+    /// );
+    /// ```
+    pub fn try_from_geometries(
+        geometries: impl IntoIterator<Item = Wkt<T>>,
+        policy: DimensionPolicy,
+    ) -> Result<Self, Error> {
+        let mut geometries: Vec<_> = geometries.into_iter().collect();
+        match policy {
+            DimensionPolicy::Reject => {
+                homogeneous_dim(&geometries)?;
+            }
+            DimensionPolicy::Promote => {
+                let (mut has_z, mut has_m) = (false, false);
+                for geometry in &mut geometries {
+                    geometry.for_each_coord_mut(|coord| {
+                        has_z |= coord.z.is_some();
+                        has_m |= coord.m.is_some();
+                    });
+                }
+                for geometry in &mut geometries {
+                    geometry.for_each_coord_mut(|coord| {
+                        if has_z {
+                            coord.z.get_or_insert(T::zero());
+                        }
+                        if has_m {
+                            coord.m.get_or_insert(T::zero());
+                        }
+                    });
+                }
+            }
+        }
+        Ok(GeometryCollection(geometries))
+    }
+
+    /// Appends geometries that must have the same dimension as the ones already here, which
+    /// [`Extend`] doesn't check.
+    ///
+    /// Empty geometries are ignored. Returns [`Error::MixedDimension`] with the index of the first
+    /// geometry that disagrees, counting from the start of the collection, and leaves the
+    /// collection unchanged.
+    pub fn try_extend(
+        &mut self,
+        geometries: impl IntoIterator<Item = Wkt<T>>,
+    ) -> Result<(), Error> {
+        try_extend(&mut self.0, geometries)
+    }
+
+    /// Returns the members, without cloning them.
+    pub fn into_parts(self) -> Vec<Wkt<T>> {
+        self.0
+    }
+
+    /// Recursively inlines nested geometry collections, so that no member is itself a
+    /// `GEOMETRYCOLLECTION`. Members keep their order, and multi geometries are left as they are.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str(
+    ///     "GEOMETRYCOLLECTION(POINT(1 2),GEOMETRYCOLLECTION(POINT(3 4),GEOMETRYCOLLECTION(MULTIPOINT(5 6))))",
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     wkt.flatten().to_string(),
+    ///     "GEOMETRYCOLLECTION(POINT(1 2),POINT(3 4),MULTIPOINT((5 6)))"
+    /// );
+    /// ```
+    pub fn flatten(self) -> Self {
+        let mut geometries = Vec::with_capacity(self.0.len());
+        flatten_into(self.0, &mut geometries);
+        GeometryCollection(geometries)
+    }
+}
+
+impl<T: WktNum> IntoIterator for GeometryCollection<T> {
+    type Item = Wkt<T>;
+    type IntoIter = std::vec::IntoIter<Wkt<T>>;
+
+    /// Iterates over the owned members, like [`GeometryCollection::into_parts`].
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T: WktNum> IntoIterator for &'a GeometryCollection<T> {
+    type Item = &'a Wkt<T>;
+    type IntoIter = std::slice::Iter<'a, Wkt<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+fn flatten_into<T: WktNum>(source: Vec<Wkt<T>>, target: &mut Vec<Wkt<T>>) {
+    for geometry in source {
+        match geometry {
+            Wkt::GeometryCollection(collection) => flatten_into(collection.0, target),
+            geometry => target.push(geometry),
+        }
+    }
+}
+
 impl<T> From<GeometryCollection<T>> for Wkt<T>
 where
     T: WktNum,
@@ -33,12 +176,20 @@ where
     }
 }
 
+impl<T: WktNum> Extend<Wkt<T>> for GeometryCollection<T> {
+    /// Appends the geometries. Dimensions aren't checked, since `extend` can't fail; use
+    /// [`GeometryCollection::try_extend`] for that.
+    fn extend<I: IntoIterator<Item = Wkt<T>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
 impl<T> fmt::Display for GeometryCollection<T>
 where
     T: WktNum + fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        Ok(write_geometry_collection(f, self)?)
+        Ok(write_wkt_geometry_collection(f, self)?)
     }
 }
 
@@ -49,26 +200,26 @@ where
     // Unsure if the dimension should be used in parsing GeometryCollection; is it
     // GEOMETRYCOLLECTION ( POINT Z (...) , POINT ZM (...))
     // or does a geometry collection have a known dimension?
-    fn from_tokens(tokens: &mut PeekableTokens<T>, _dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut Tokens<'_>, _dim: Dimension) -> Result<Self, &'static str> {
         let mut items = Vec::new();
 
-        let word = match tokens.next().transpose()? {
+        let word = match tokens.next() {
             Some(Token::Word(w)) => w,
             _ => return Err("Expected a word in GEOMETRYCOLLECTION"),
         };
 
-        let item = Wkt::from_word_and_tokens(&word, tokens)?;
+        let item = Wkt::from_word_and_tokens(word, tokens)?;
         items.push(item);
 
-        while let Some(&Ok(Token::Comma)) = tokens.peek() {
+        while let Some(&Token::Comma) = tokens.peek() {
             tokens.next(); // throw away comma
 
-            let word = match tokens.next().transpose()? {
+            let word = match tokens.next() {
                 Some(Token::Word(w)) => w,
                 _ => return Err("Expected a word in GEOMETRYCOLLECTION"),
             };
 
-            let item = Wkt::from_word_and_tokens(&word, tokens)?;
+            let item = Wkt::from_word_and_tokens(word, tokens)?;
             items.push(item);
         }
 
@@ -103,11 +254,108 @@ impl<T: WktNum> GeometryCollectionTrait for GeometryCollection<T> {
 
 #[cfg(test)]
 mod tests {
-    use super::GeometryCollection;
+    use super::{DimensionPolicy, GeometryCollection};
+    use crate::error::Error;
     use crate::types::*;
     use crate::Wkt;
     use std::str::FromStr;
 
+    #[test]
+    fn try_from_geometries_checks_dimensions() {
+        let geometries = [
+            "POINT Z (1 2 3)",
+            "LINESTRING EMPTY",
+            "GEOMETRYCOLLECTION (POINT Z (1 2 3))",
+            "POINT (1 2)",
+        ]
+        .map(|wkt| Wkt::<f64>::from_str(wkt).unwrap());
+        assert!(matches!(
+            GeometryCollection::try_from_geometries(geometries.clone(), DimensionPolicy::Reject),
+            Err(Error::MixedDimension {
+                index: 3,
+                expected: Dimension::XYZ,
+                found: Dimension::XY,
+            })
+        ));
+        assert!(GeometryCollection::try_from_geometries(
+            geometries[..3].to_vec(),
+            DimensionPolicy::Reject
+        )
+        .is_ok());
+
+        let collection =
+            GeometryCollection::try_from_geometries(geometries, DimensionPolicy::Promote).unwrap();
+        assert_eq!(
+            collection.to_string(),
+            "GEOMETRYCOLLECTION Z(POINT Z(1 2 3),LINESTRING EMPTY,\
+            GEOMETRYCOLLECTION Z(POINT Z(1 2 3)),POINT Z(1 2 0))"
+        );
+    }
+
+    #[test]
+    fn extend() {
+        let mut collection = GeometryCollection::empty();
+        collection
+            .extend(["POINT (1 2)", "LINESTRING EMPTY"].map(|wkt| Wkt::from_str(wkt).unwrap()));
+        let mut multi_point = MultiPoint(vec![]);
+        multi_point.extend([Point::new(Coord::xy(3., 4.))]);
+        collection.extend([Wkt::MultiPoint(multi_point)]);
+        assert_eq!(
+            collection.to_string(),
+            "GEOMETRYCOLLECTION(POINT(1 2),LINESTRING EMPTY,MULTIPOINT((3 4)))"
+        );
+    }
+
+    #[test]
+    fn try_extend() {
+        let wkt = |wkt| Wkt::<f64>::from_str(wkt).unwrap();
+        let mut collection = GeometryCollection(vec![wkt("POINT EMPTY"), wkt("POINT Z (1 2 3)")]);
+        collection
+            .try_extend([wkt("LINESTRING EMPTY"), wkt("LINESTRING Z (4 5 6, 7 8 9)")])
+            .unwrap();
+        assert_eq!(collection.0.len(), 4);
+
+        let err = collection
+            .try_extend([wkt("POINT Z (1 2 3)"), wkt("POINT (1 2)")])
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::MixedDimension {
+                index: 5,
+                expected: Dimension::XYZ,
+                found: Dimension::XY,
+            }
+        ));
+        assert_eq!(collection.0.len(), 4);
+    }
+
+    #[test]
+    fn into_owned_members() {
+        let Ok(Wkt::GeometryCollection(collection)) =
+            Wkt::<f64>::from_str("GEOMETRYCOLLECTION (POINT (1 2), LINESTRING EMPTY)")
+        else {
+            unreachable!()
+        };
+        let types: Vec<_> = (&collection)
+            .into_iter()
+            .map(|geometry| geometry.geometry_type().to_string())
+            .collect();
+        assert_eq!(types, ["POINT", "LINESTRING"]);
+        let members: Vec<Wkt<f64>> = collection.clone().into_iter().collect();
+        assert_eq!(members, collection.into_parts());
+
+        let wkt: Wkt<f64> = Wkt::from_str(
+            "GEOMETRYCOLLECTION (MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), EMPTY), MULTIPOINT EMPTY, \
+            GEOMETRYCOLLECTION (POINT EMPTY))",
+        )
+        .unwrap();
+        let parts: Vec<_> = wkt.into_primitives().iter().map(Wkt::to_string).collect();
+        assert_eq!(
+            parts,
+            ["POLYGON((0 0,1 0,1 1,0 0))", "POLYGON EMPTY", "POINT EMPTY"]
+        );
+    }
+
     #[test]
     fn basic_geometrycollection() {
         let wkt: Wkt<f64> = Wkt::from_str("GEOMETRYCOLLECTION (POINT (8 4)))")
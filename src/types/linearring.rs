@@ -0,0 +1,169 @@
+// Copyright 2014-2015 The GeoRust Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use geo_traits::LineStringTrait;
+
+use crate::to_wkt::write_linear_ring;
+use crate::tokenizer::Tokens;
+use crate::types::coord::Coord;
+use crate::types::linestring::LineString;
+use crate::types::{Dimension, Winding};
+use crate::{FromTokens, Wkt, WktNum};
+use std::fmt;
+use std::str::FromStr;
+
+/// A `LINEARRING`, as written by JTS: a line string whose first and last coordinates are equal.
+///
+/// Closure isn't enforced when parsing, so that existing data can still be read. Use
+/// [`LinearRing::is_closed`] or [`Wkt::validate`](crate::Wkt::validate) to check it.
+///
+/// Through [`geo_traits`] a linear ring is a line string, but it keeps its `LINEARRING` keyword
+/// when written as part of a [`Wkt`].
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct LinearRing<T: WktNum>(pub LineString<T>);
+
+impl<T: WktNum> LinearRing<T> {
+    /// An empty `LinearRing`, with no coordinates, written as `EMPTY`.
+    pub const fn empty() -> Self {
+        LinearRing(LineString::empty())
+    }
+
+    /// Reverses the order of the coordinates, in place.
+    pub fn reverse(&mut self) {
+        self.0.reverse()
+    }
+
+    /// Whether the ring is empty, or its first and last coordinates are equal.
+    pub fn is_closed(&self) -> bool {
+        self.0.is_closed()
+    }
+
+    /// The direction in which this ring winds, see [`LineString::winding`].
+    pub fn winding(&self) -> Option<Winding> {
+        self.0.winding()
+    }
+
+    /// Whether this ring winds counter-clockwise, see [`LineString::winding`].
+    pub fn is_ccw(&self) -> bool {
+        self.0.is_ccw()
+    }
+}
+
+impl<T> From<LinearRing<T>> for Wkt<T>
+where
+    T: WktNum,
+{
+    fn from(value: LinearRing<T>) -> Self {
+        Wkt::LinearRing(value)
+    }
+}
+
+impl<T> From<LinearRing<T>> for LineString<T>
+where
+    T: WktNum,
+{
+    fn from(value: LinearRing<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T> FromTokens<T> for LinearRing<T>
+where
+    T: WktNum + FromStr + Default,
+{
+    fn from_tokens(tokens: &mut Tokens<'_>, dim: Dimension) -> Result<Self, &'static str> {
+        <LineString<T> as FromTokens<T>>::from_tokens(tokens, dim).map(LinearRing)
+    }
+}
+
+impl<T> fmt::Display for LinearRing<T>
+where
+    T: WktNum + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        Ok(write_linear_ring(f, self)?)
+    }
+}
+
+impl<T: WktNum> LineStringTrait for LinearRing<T> {
+    type T = T;
+    type CoordType<'a>
+        = &'a Coord<T>
+    where
+        Self: 'a;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.0.dim()
+    }
+
+    fn num_coords(&self) -> usize {
+        self.0.num_coords()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.0.coord_unchecked(i)
+    }
+}
+
+impl<T: WktNum> LineStringTrait for &LinearRing<T> {
+    type T = T;
+    type CoordType<'a>
+        = &'a Coord<T>
+    where
+        Self: 'a;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.0.dim()
+    }
+
+    fn num_coords(&self) -> usize {
+        self.0.num_coords()
+    }
+
+    unsafe fn coord_unchecked(&self, i: usize) -> Self::CoordType<'_> {
+        self.0.coord_unchecked(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinearRing;
+    use crate::Wkt;
+    use std::str::FromStr;
+
+    #[test]
+    fn round_trip_linear_ring() {
+        for wkt_str in [
+            "LINEARRING(0 0,1 0,1 1,0 0)",
+            "LINEARRING Z(0 0 1,1 0 1,1 1 1,0 0 1)",
+            "LINEARRING EMPTY",
+            "GEOMETRYCOLLECTION(LINEARRING(0 0,1 0,1 1,0 0),LINESTRING(0 0,1 1))",
+        ] {
+            let wkt: Wkt<f64> = Wkt::from_str(wkt_str).unwrap();
+            assert_eq!(wkt.to_string(), wkt_str);
+        }
+    }
+
+    #[test]
+    fn is_closed() {
+        let ring = |wkt_str| match Wkt::<f64>::from_str(wkt_str).unwrap() {
+            Wkt::LinearRing(ring) => ring,
+            _ => unreachable!(),
+        };
+        assert!(ring("LINEARRING(0 0,1 0,1 1,0 0)").is_closed());
+        assert!(ring("LINEARRING EMPTY").is_closed());
+        assert!(!ring("LINEARRING(0 0,1 0,1 1)").is_closed());
+        assert_eq!(LinearRing::<f64>::default().to_string(), "LINEARRING EMPTY");
+    }
+}
@@ -14,17 +14,116 @@
 
 use geo_traits::{CoordTrait, LineStringTrait};
 
+use crate::error::Error;
 use crate::to_wkt::write_linestring;
-use crate::tokenizer::PeekableTokens;
-use crate::types::coord::Coord;
-use crate::types::Dimension;
+use crate::tokenizer::Tokens;
+use crate::types::coord::{xy_coords_from_tokens, Coord};
+use crate::types::dimension::{homogeneous_dim, try_extend};
+use crate::types::{Dimension, Winding};
 use crate::{FromTokens, Wkt, WktNum};
+use std::cmp::Ordering;
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 pub struct LineString<T: WktNum>(pub Vec<Coord<T>>);
 
+impl<T: WktNum> LineString<T> {
+    /// An empty `LineString`, with no coordinates, written as `EMPTY`.
+    pub const fn empty() -> Self {
+        LineString(Vec::new())
+    }
+
+    /// Creates a `LineString` from coordinates that must all have the same dimension.
+    ///
+    /// Returns [`Error::MixedDimension`] with the index of the first coordinate that disagrees
+    /// with the ones before it.
+    pub fn from_coords_checked(coords: Vec<Coord<T>>) -> Result<Self, Error> {
+        homogeneous_dim(&coords)?;
+        Ok(LineString(coords))
+    }
+
+    /// Appends coordinates that must have the same dimension as the ones already here, which
+    /// [`Extend`] doesn't check.
+    ///
+    /// Returns [`Error::MixedDimension`] with the index of the first coordinate that disagrees,
+    /// counting from the start of the line string, and leaves the line string unchanged.
+    pub fn try_extend(&mut self, coords: impl IntoIterator<Item = Coord<T>>) -> Result<(), Error> {
+        try_extend(&mut self.0, coords)
+    }
+
+    /// The segments between consecutive coordinates, as `(start, end)` pairs.
+    ///
+    /// A line string with fewer than two coordinates has no segments.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING(0 0,3 4,3 5)").unwrap();
+    /// let line_string = wkt.as_line_string().unwrap();
+    /// let length: f64 = line_string
+    ///     .segments()
+    ///     .map(|(a, b)| (b.x - a.x).hypot(b.y - a.y))
+    ///     .sum();
+    /// assert_eq!(length, 6.0);
+    /// ```
+    pub fn segments(&self) -> impl ExactSizeIterator<Item = (&Coord<T>, &Coord<T>)> + '_ {
+        self.0.windows(2).map(|pair| (&pair[0], &pair[1]))
+    }
+
+    /// Reverses the order of the coordinates, in place.
+    pub fn reverse(&mut self) {
+        self.0.reverse();
+    }
+
+    /// Whether the line string is empty, or its first and last coordinates are equal.
+    pub fn is_closed(&self) -> bool {
+        self.0.first() == self.0.last()
+    }
+
+    /// The direction in which this line string winds when treated as a ring, from the sign of the
+    /// area it encloses. Only `x` and `y` are considered, and the ring doesn't need to be closed.
+    ///
+    /// The area is summed as `f64`, so integer coordinates can't overflow. Returns `None` if it's
+    /// zero, e.g. for fewer than three distinct coordinates, or if a coordinate can't be
+    /// converted to `f64`.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    /// use wkt::types::Winding;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING(0 0,1 0,1 1,0 0)").unwrap();
+    /// let ring = wkt.as_line_string().unwrap();
+    /// assert_eq!(ring.winding(), Some(Winding::CounterClockwise));
+    /// assert!(ring.is_ccw());
+    /// ```
+    pub fn winding(&self) -> Option<Winding> {
+        let coords = &self.0;
+        let mut area = 0.;
+        for (i, a) in coords.iter().enumerate() {
+            let b = &coords[(i + 1) % coords.len()];
+            let (Some(ax), Some(ay), Some(bx), Some(by)) =
+                (a.x.to_f64(), a.y.to_f64(), b.x.to_f64(), b.y.to_f64())
+            else {
+                return None;
+            };
+            area += ax * by - bx * ay;
+        }
+        match area.partial_cmp(&0.) {
+            Some(Ordering::Greater) => Some(Winding::CounterClockwise),
+            Some(Ordering::Less) => Some(Winding::Clockwise),
+            _ => None,
+        }
+    }
+
+    /// Whether this line string winds counter-clockwise, see [`LineString::winding`].
+    pub fn is_ccw(&self) -> bool {
+        self.winding() == Some(Winding::CounterClockwise)
+    }
+}
+
 impl<T> From<LineString<T>> for Wkt<T>
 where
     T: WktNum,
@@ -34,11 +133,23 @@ where
     }
 }
 
+impl<T: WktNum> Extend<Coord<T>> for LineString<T> {
+    /// Appends the coordinates. Dimensions aren't checked, since `extend` can't fail; use
+    /// [`LineString::try_extend`] for that.
+    fn extend<I: IntoIterator<Item = Coord<T>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
 impl<T> FromTokens<T> for LineString<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut Tokens<'_>, dim: Dimension) -> Result<Self, &'static str> {
+        // Most WKT is 2D, which has its own faster loop.
+        if dim == Dimension::XY {
+            return xy_coords_from_tokens(tokens).map(LineString);
+        }
         let result = FromTokens::comma_many(<Coord<T> as FromTokens<T>>::from_tokens, tokens, dim);
         result.map(LineString)
     }
@@ -105,10 +216,37 @@ impl<T: WktNum> LineStringTrait for &LineString<T> {
 
 #[cfg(test)]
 mod tests {
-    use super::{Coord, LineString};
+    use super::{Coord, LineString, Winding};
+    use crate::error::Error;
+    use crate::types::Dimension;
     use crate::Wkt;
     use std::str::FromStr;
 
+    #[test]
+    fn extend() {
+        let mut line_string = LineString(vec![Coord::xy(0, 0)]);
+        line_string.extend((1..3).map(|i| Coord::xy(i, i)));
+        assert_eq!(line_string.to_string(), "LINESTRING(0 0,1 1,2 2)");
+    }
+
+    #[test]
+    fn try_extend() {
+        let mut line_string = LineString(vec![Coord::xy(0, 0)]);
+        line_string.try_extend([Coord::xy(1, 1)]).unwrap();
+        let err = line_string
+            .try_extend([Coord::xy(2, 2), Coord::xyz(3, 3, 3)])
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::MixedDimension {
+                index: 3,
+                expected: Dimension::XY,
+                found: Dimension::XYZ,
+            }
+        ));
+        assert_eq!(line_string.to_string(), "LINESTRING(0 0,1 1)");
+    }
+
     #[test]
     fn basic_linestring() {
         let wkt = Wkt::from_str("LINESTRING (10 -20, -0 -0.5)").ok().unwrap();
@@ -243,4 +381,77 @@ mod tests {
 
         assert_eq!("LINESTRING(10.1 20.2,30.3 40.4)", format!("{}", linestring));
     }
+
+    #[test]
+    fn from_coords_checked() {
+        let xy = |x| Coord {
+            x,
+            y: 0.,
+            z: None,
+            m: None,
+        };
+        let xyz = |x| Coord {
+            x,
+            y: 0.,
+            z: Some(0.),
+            m: None,
+        };
+
+        let linestring = LineString::from_coords_checked(vec![xy(0.), xy(1.)]).unwrap();
+        assert_eq!(linestring.0.len(), 2);
+
+        match LineString::from_coords_checked(vec![xyz(0.), xyz(1.), xy(2.)]) {
+            Err(Error::MixedDimension {
+                index,
+                expected,
+                found,
+            }) => {
+                assert_eq!(index, 2);
+                assert_eq!(expected, Dimension::XYZ);
+                assert_eq!(found, Dimension::XY);
+            }
+            other => panic!("expected a mixed dimension error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn orientation_helpers() {
+        let parse = |wkt_str| match Wkt::<f64>::from_str(wkt_str).unwrap() {
+            Wkt::LineString(line_string) => line_string,
+            _ => unreachable!(),
+        };
+
+        let mut ring = parse("LINESTRING(0 0,0 1,1 1,1 0,0 0)");
+        assert!(ring.is_closed());
+        assert_eq!(ring.winding(), Some(Winding::Clockwise));
+        assert!(!ring.is_ccw());
+        ring.reverse();
+        assert_eq!(ring.to_string(), "LINESTRING(0 0,1 0,1 1,0 1,0 0)");
+        assert!(ring.is_ccw());
+
+        assert!(!parse("LINESTRING(0 0,1 1)").is_closed());
+        assert_eq!(parse("LINESTRING(0 0,1 1,2 2)").winding(), None);
+        assert_eq!(parse("LINESTRING EMPTY").winding(), None);
+    }
+
+    #[test]
+    fn winding_of_integer_coords_does_not_overflow() {
+        fn ring<T: crate::WktNum>(xys: &[(T, T)]) -> LineString<T> {
+            LineString(
+                xys.iter()
+                    .map(|&(x, y)| Coord {
+                        x,
+                        y,
+                        z: None,
+                        m: None,
+                    })
+                    .collect(),
+            )
+        }
+
+        let big = ring(&[(0, 0), (100_000, 0), (100_000, 100_000), (0, 0)]);
+        assert_eq!(big.winding(), Some(Winding::CounterClockwise));
+        let unsigned = ring(&[(0u32, 0), (0, 1), (1, 1), (0, 0)]);
+        assert_eq!(unsigned.winding(), Some(Winding::Clockwise));
+    }
 }
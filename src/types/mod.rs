@@ -15,21 +15,25 @@
 pub use self::coord::Coord;
 pub use self::dimension::Dimension;
 pub use self::geometry_type::GeometryType;
-pub use self::geometrycollection::GeometryCollection;
+pub use self::geometrycollection::{DimensionPolicy, GeometryCollection};
+pub use self::linearring::LinearRing;
 pub use self::linestring::LineString;
 pub use self::multilinestring::MultiLineString;
 pub use self::multipoint::MultiPoint;
 pub use self::multipolygon::MultiPolygon;
 pub use self::point::Point;
 pub use self::polygon::Polygon;
+pub use self::winding::Winding;
 
 mod coord;
 mod dimension;
 mod geometry_type;
 mod geometrycollection;
+mod linearring;
 mod linestring;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
 mod point;
 mod polygon;
+mod winding;
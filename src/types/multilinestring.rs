@@ -14,17 +14,58 @@
 
 use geo_traits::{LineStringTrait, MultiLineStringTrait};
 
+use crate::error::Error;
 use crate::to_wkt::write_multi_linestring;
-use crate::tokenizer::PeekableTokens;
+use crate::tokenizer::Tokens;
+use crate::types::dimension::{homogeneous_dim, try_extend};
 use crate::types::linestring::LineString;
 use crate::types::Dimension;
 use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 pub struct MultiLineString<T: WktNum>(pub Vec<LineString<T>>);
 
+impl<T: WktNum> MultiLineString<T> {
+    /// An empty `MultiLineString`, with no line strings, written as `EMPTY`.
+    pub const fn empty() -> Self {
+        MultiLineString(Vec::new())
+    }
+
+    /// Creates a `MultiLineString` from line strings that must all have the same dimension.
+    ///
+    /// Empty line strings are ignored. Returns [`Error::MixedDimension`] with the index of the first
+    /// element that disagrees with the ones before it, including one that is itself mixed.
+    pub fn from_line_strings_checked(line_strings: Vec<LineString<T>>) -> Result<Self, Error> {
+        homogeneous_dim(&line_strings)?;
+        Ok(MultiLineString(line_strings))
+    }
+
+    /// Appends line strings that must have the same dimension as the ones already here, which
+    /// [`Extend`] doesn't check.
+    ///
+    /// Empty line strings are ignored. Returns [`Error::MixedDimension`] with the index of the
+    /// first line string that disagrees, counting from the start of the multi line string, and
+    /// leaves the multi line string unchanged.
+    pub fn try_extend(
+        &mut self,
+        line_strings: impl IntoIterator<Item = LineString<T>>,
+    ) -> Result<(), Error> {
+        try_extend(&mut self.0, line_strings)
+    }
+}
+
+impl<T> From<LineString<T>> for MultiLineString<T>
+where
+    T: WktNum,
+{
+    /// A `MultiLineString` with `line_string` as its only member.
+    fn from(line_string: LineString<T>) -> Self {
+        MultiLineString(vec![line_string])
+    }
+}
+
 impl<T> From<MultiLineString<T>> for Wkt<T>
 where
     T: WktNum,
@@ -34,6 +75,14 @@ where
     }
 }
 
+impl<T: WktNum> Extend<LineString<T>> for MultiLineString<T> {
+    /// Appends the line strings. Dimensions aren't checked, since `extend` can't fail; use
+    /// [`MultiLineString::try_extend`] for that.
+    fn extend<I: IntoIterator<Item = LineString<T>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
 impl<T> fmt::Display for MultiLineString<T>
 where
     T: WktNum + fmt::Display,
@@ -47,7 +96,7 @@ impl<T> FromTokens<T> for MultiLineString<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut Tokens<'_>, dim: Dimension) -> Result<Self, &'static str> {
         let result = FromTokens::comma_many(
             <LineString<T> as FromTokens<T>>::from_tokens_with_parens,
             tokens,
@@ -66,11 +115,11 @@ impl<T: WktNum> MultiLineStringTrait for MultiLineString<T> {
 
     fn dim(&self) -> geo_traits::Dimensions {
         // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        // Empty members have no dimension of their own, so the first non-empty one decides.
+        self.0
+            .iter()
+            .find(|line_string| !line_string.0.is_empty())
+            .map_or(geo_traits::Dimensions::Xy, |line_string| line_string.dim())
     }
 
     fn num_line_strings(&self) -> usize {
@@ -91,11 +140,11 @@ impl<T: WktNum> MultiLineStringTrait for &MultiLineString<T> {
 
     fn dim(&self) -> geo_traits::Dimensions {
         // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        // Empty members have no dimension of their own, so the first non-empty one decides.
+        self.0
+            .iter()
+            .find(|line_string| !line_string.0.is_empty())
+            .map_or(geo_traits::Dimensions::Xy, |line_string| line_string.dim())
     }
 
     fn num_line_strings(&self) -> usize {
@@ -14,17 +14,55 @@
 
 use geo_traits::{MultiPointTrait, PointTrait};
 
+use crate::error::Error;
 use crate::to_wkt::write_multi_point;
-use crate::tokenizer::PeekableTokens;
+use crate::tokenizer::Tokens;
+use crate::types::dimension::{homogeneous_dim, try_extend};
 use crate::types::point::Point;
 use crate::types::Dimension;
 use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 pub struct MultiPoint<T: WktNum>(pub Vec<Point<T>>);
 
+impl<T: WktNum> MultiPoint<T> {
+    /// An empty `MultiPoint`, with no points, written as `EMPTY`.
+    pub const fn empty() -> Self {
+        MultiPoint(Vec::new())
+    }
+
+    /// Creates a `MultiPoint` from points that must all have the same dimension.
+    ///
+    /// Empty points are ignored. Returns [`Error::MixedDimension`] with the index of the first
+    /// element that disagrees with the ones before it, including one that is itself mixed.
+    pub fn from_points_checked(points: Vec<Point<T>>) -> Result<Self, Error> {
+        homogeneous_dim(&points)?;
+        Ok(MultiPoint(points))
+    }
+
+    /// Appends points that must have the same dimension as the ones already here, which
+    /// [`Extend`] doesn't check.
+    ///
+    /// Empty points are ignored. Returns [`Error::MixedDimension`] with the index of the first
+    /// point that disagrees, counting from the start of the multi point, and leaves the multi point
+    /// unchanged.
+    pub fn try_extend(&mut self, points: impl IntoIterator<Item = Point<T>>) -> Result<(), Error> {
+        try_extend(&mut self.0, points)
+    }
+}
+
+impl<T> From<Point<T>> for MultiPoint<T>
+where
+    T: WktNum,
+{
+    /// A `MultiPoint` with `point` as its only member.
+    fn from(point: Point<T>) -> Self {
+        MultiPoint(vec![point])
+    }
+}
+
 impl<T> From<MultiPoint<T>> for Wkt<T>
 where
     T: WktNum,
@@ -34,6 +72,14 @@ where
     }
 }
 
+impl<T: WktNum> Extend<Point<T>> for MultiPoint<T> {
+    /// Appends the points. Dimensions aren't checked, since `extend` can't fail; use
+    /// [`MultiPoint::try_extend`] for that.
+    fn extend<I: IntoIterator<Item = Point<T>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
 impl<T> fmt::Display for MultiPoint<T>
 where
     T: WktNum + fmt::Display,
@@ -47,7 +93,7 @@ impl<T> FromTokens<T> for MultiPoint<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut Tokens<'_>, dim: Dimension) -> Result<Self, &'static str> {
         let result = FromTokens::comma_many(
             <Point<T> as FromTokens<T>>::from_tokens_with_optional_parens,
             tokens,
@@ -66,11 +112,11 @@ impl<T: WktNum> MultiPointTrait for MultiPoint<T> {
 
     fn dim(&self) -> geo_traits::Dimensions {
         // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        // Empty members have no dimension of their own, so the first non-empty one decides.
+        self.0
+            .iter()
+            .find(|point| point.0.is_some())
+            .map_or(geo_traits::Dimensions::Xy, |point| point.dim())
     }
 
     fn num_points(&self) -> usize {
@@ -91,11 +137,11 @@ impl<T: WktNum> MultiPointTrait for &MultiPoint<T> {
 
     fn dim(&self) -> geo_traits::Dimensions {
         // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        // Empty members have no dimension of their own, so the first non-empty one decides.
+        self.0
+            .iter()
+            .find(|point| point.0.is_some())
+            .map_or(geo_traits::Dimensions::Xy, |point| point.dim())
     }
 
     fn num_points(&self) -> usize {
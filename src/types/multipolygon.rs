@@ -14,17 +14,85 @@
 
 use geo_traits::{MultiPolygonTrait, PolygonTrait};
 
+use crate::error::Error;
 use crate::to_wkt::write_multi_polygon;
-use crate::tokenizer::PeekableTokens;
+use crate::tokenizer::Tokens;
+use crate::types::dimension::{homogeneous_dim, try_extend};
 use crate::types::polygon::Polygon;
-use crate::types::Dimension;
+use crate::types::{Dimension, Winding};
 use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 pub struct MultiPolygon<T: WktNum>(pub Vec<Polygon<T>>);
 
+impl<T: WktNum> MultiPolygon<T> {
+    /// An empty `MultiPolygon`, with no polygons, written as `EMPTY`.
+    pub const fn empty() -> Self {
+        MultiPolygon(Vec::new())
+    }
+
+    /// Creates a `MultiPolygon` from polygons that must all have the same dimension.
+    ///
+    /// Empty polygons are ignored. Returns [`Error::MixedDimension`] with the index of the first
+    /// element that disagrees with the ones before it, including one that is itself mixed.
+    pub fn from_polygons_checked(polygons: Vec<Polygon<T>>) -> Result<Self, Error> {
+        homogeneous_dim(&polygons)?;
+        Ok(MultiPolygon(polygons))
+    }
+
+    /// Appends polygons that must have the same dimension as the ones already here, which
+    /// [`Extend`] doesn't check.
+    ///
+    /// Empty polygons are ignored. Returns [`Error::MixedDimension`] with the index of the first
+    /// polygon that disagrees, counting from the start of the multi polygon, and leaves the multi
+    /// polygon unchanged.
+    pub fn try_extend(
+        &mut self,
+        polygons: impl IntoIterator<Item = Polygon<T>>,
+    ) -> Result<(), Error> {
+        try_extend(&mut self.0, polygons)
+    }
+
+    /// Orients the rings of every polygon to the given exterior winding, see [`Polygon::orient`].
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    /// use wkt::types::Winding;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("MULTIPOLYGON(((0 0,0 1,1 1,0 0)),((5 5,6 5,6 6,5 5)))")
+    ///     .unwrap();
+    /// let mut multi_polygon = wkt.into_multi_polygon().unwrap();
+    /// multi_polygon.orient(Winding::CounterClockwise);
+    /// assert_eq!(
+    ///     multi_polygon.to_string(),
+    ///     "MULTIPOLYGON(((0 0,1 1,0 1,0 0)),((5 5,6 5,6 6,5 5)))"
+    /// );
+    /// ```
+    pub fn orient(&mut self, exterior: Winding) {
+        for polygon in &mut self.0 {
+            polygon.orient(exterior);
+        }
+    }
+
+    /// Closes the unclosed rings of every polygon, see [`Polygon::close_rings`].
+    pub fn close_rings(&mut self) {
+        self.0.iter_mut().for_each(Polygon::close_rings);
+    }
+}
+
+impl<T> From<Polygon<T>> for MultiPolygon<T>
+where
+    T: WktNum,
+{
+    /// A `MultiPolygon` with `polygon` as its only member.
+    fn from(polygon: Polygon<T>) -> Self {
+        MultiPolygon(vec![polygon])
+    }
+}
+
 impl<T> From<MultiPolygon<T>> for Wkt<T>
 where
     T: WktNum,
@@ -34,6 +102,14 @@ where
     }
 }
 
+impl<T: WktNum> Extend<Polygon<T>> for MultiPolygon<T> {
+    /// Appends the polygons. Dimensions aren't checked, since `extend` can't fail; use
+    /// [`MultiPolygon::try_extend`] for that.
+    fn extend<I: IntoIterator<Item = Polygon<T>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
 impl<T> fmt::Display for MultiPolygon<T>
 where
     T: WktNum + fmt::Display,
@@ -47,7 +123,7 @@ impl<T> FromTokens<T> for MultiPolygon<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut Tokens<'_>, dim: Dimension) -> Result<Self, &'static str> {
         let result = FromTokens::comma_many(
             <Polygon<T> as FromTokens<T>>::from_tokens_with_parens,
             tokens,
@@ -66,11 +142,16 @@ impl<T: WktNum> MultiPolygonTrait for MultiPolygon<T> {
 
     fn dim(&self) -> geo_traits::Dimensions {
         // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        // Empty members have no dimension of their own, so the first non-empty one decides.
+        self.0
+            .iter()
+            .find(|polygon| {
+                polygon
+                    .0
+                    .first()
+                    .is_some_and(|exterior| !exterior.0.is_empty())
+            })
+            .map_or(geo_traits::Dimensions::Xy, |polygon| polygon.dim())
     }
 
     fn num_polygons(&self) -> usize {
@@ -91,11 +172,16 @@ impl<T: WktNum> MultiPolygonTrait for &MultiPolygon<T> {
 
     fn dim(&self) -> geo_traits::Dimensions {
         // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        // Empty members have no dimension of their own, so the first non-empty one decides.
+        self.0
+            .iter()
+            .find(|polygon| {
+                polygon
+                    .0
+                    .first()
+                    .is_some_and(|exterior| !exterior.0.is_empty())
+            })
+            .map_or(geo_traits::Dimensions::Xy, |polygon| polygon.dim())
     }
 
     fn num_polygons(&self) -> usize {
@@ -110,10 +196,38 @@ impl<T: WktNum> MultiPolygonTrait for &MultiPolygon<T> {
 #[cfg(test)]
 mod tests {
     use super::{MultiPolygon, Polygon};
-    use crate::types::{Coord, LineString};
+    use crate::types::{Coord, LineString, Winding};
     use crate::Wkt;
     use std::str::FromStr;
 
+    #[test]
+    fn orient() {
+        let Ok(Wkt::MultiPolygon(mut multi_polygon)) = Wkt::<f64>::from_str(
+            "MULTIPOLYGON (((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1)), EMPTY)",
+        ) else {
+            unreachable!()
+        };
+        multi_polygon.orient(Winding::Clockwise);
+        assert_eq!(
+            multi_polygon.to_string(),
+            "MULTIPOLYGON(((0 0,4 4,4 0,0 0),(1 1,2 1,2 2,1 1)),EMPTY)"
+        );
+    }
+
+    #[test]
+    fn close_rings() {
+        let Ok(Wkt::MultiPolygon(mut multi_polygon)) =
+            Wkt::<f64>::from_str("MULTIPOLYGON (((0 0, 1 0, 1 1)), EMPTY, ((5 5, 6 5, 6 6, 5 5)))")
+        else {
+            unreachable!()
+        };
+        multi_polygon.close_rings();
+        assert_eq!(
+            multi_polygon.to_string(),
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0)),EMPTY,((5 5,6 5,6 6,5 5)))"
+        );
+    }
+
     #[test]
     fn basic_multipolygon() {
         let wkt: Wkt<f64> = Wkt::from_str("MULTIPOLYGON (((8 4)), ((4 0)))")
@@ -15,16 +15,28 @@
 use geo_traits::{CoordTrait, PointTrait};
 
 use crate::to_wkt::write_point;
-use crate::tokenizer::PeekableTokens;
+use crate::tokenizer::Tokens;
 use crate::types::coord::Coord;
 use crate::types::Dimension;
 use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 pub struct Point<T: WktNum>(pub Option<Coord<T>>);
 
+impl<T: WktNum> Point<T> {
+    /// An empty `Point`, written as `POINT EMPTY`.
+    pub const fn empty() -> Self {
+        Point(None)
+    }
+
+    /// A `Point` at `coord`.
+    pub const fn new(coord: Coord<T>) -> Self {
+        Point(Some(coord))
+    }
+}
+
 impl<T> From<Point<T>> for Wkt<T>
 where
     T: WktNum,
@@ -47,7 +59,7 @@ impl<T> FromTokens<T> for Point<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut Tokens<'_>, dim: Dimension) -> Result<Self, &'static str> {
         let result = <Coord<T> as FromTokens<T>>::from_tokens(tokens, dim);
         result.map(|coord| Point(Some(coord)))
     }
@@ -96,7 +108,7 @@ impl<T: WktNum> PointTrait for &Point<T> {
 }
 #[cfg(test)]
 mod tests {
-    use super::{Coord, Point};
+    use super::{Coord, Dimension, Point};
     use crate::Wkt;
     use std::str::FromStr;
 
@@ -168,6 +180,23 @@ mod tests {
         assert_eq!("POINT EMPTY", format!("{}", point));
     }
 
+    #[test]
+    fn const_points() {
+        static POINTS: [Point<f64>; 3] = [
+            Point::empty(),
+            Point::new(Coord::xy(1., 2.)),
+            Point::new(Coord::xyzm(1., 2., 3., 4.)),
+        ];
+        const DIM: Dimension = Dimension::from_zm(true, true);
+        const SIZE: usize = DIM.size();
+
+        assert_eq!(POINTS[0].to_string(), "POINT EMPTY");
+        assert_eq!(POINTS[1].to_string(), "POINT(1 2)");
+        assert_eq!(POINTS[2].to_string(), "POINT ZM(1 2 3 4)");
+        assert_eq!(POINTS[2].0.as_ref().unwrap().dimension(), DIM);
+        assert_eq!(SIZE, 4);
+    }
+
     #[test]
     fn write_2d_point() {
         let point = Point(Some(Coord {
@@ -14,17 +14,109 @@
 
 use geo_traits::{LineStringTrait, PolygonTrait};
 
+use crate::error::Error;
 use crate::to_wkt::write_polygon;
-use crate::tokenizer::PeekableTokens;
+use crate::tokenizer::Tokens;
+use crate::types::dimension::{homogeneous_dim, try_extend};
 use crate::types::linestring::LineString;
-use crate::types::Dimension;
+use crate::types::Coord;
+use crate::types::{Dimension, Winding};
 use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 pub struct Polygon<T: WktNum>(pub Vec<LineString<T>>);
 
+impl<T: WktNum> Polygon<T> {
+    /// An empty `Polygon`, with no rings, written as `EMPTY`.
+    pub const fn empty() -> Self {
+        Polygon(Vec::new())
+    }
+
+    /// Creates a `Polygon` from rings that must all have the same dimension.
+    ///
+    /// Empty rings are ignored. Returns [`Error::MixedDimension`] with the index of the first
+    /// element that disagrees with the ones before it, including one that is itself mixed.
+    pub fn from_rings_checked(rings: Vec<LineString<T>>) -> Result<Self, Error> {
+        homogeneous_dim(&rings)?;
+        Ok(Polygon(rings))
+    }
+
+    /// Appends rings that must have the same dimension as the ones already here, which
+    /// [`Extend`] doesn't check.
+    ///
+    /// Empty rings are ignored. Returns [`Error::MixedDimension`] with the index of the first ring
+    /// that disagrees, counting from the start of the polygon, and leaves the polygon unchanged.
+    pub fn try_extend(
+        &mut self,
+        rings: impl IntoIterator<Item = LineString<T>>,
+    ) -> Result<(), Error> {
+        try_extend(&mut self.0, rings)
+    }
+
+    /// Orients the exterior ring to wind in the given direction and the interior rings in the
+    /// opposite one, reversing rings as needed. Rings without a winding, such as empty rings, are
+    /// left as they are.
+    ///
+    /// Counter-clockwise exteriors are the convention of the OGC simple features specification
+    /// and GeoJSON, while some renderers and the ESRI shapefile format expect clockwise ones.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    /// use wkt::types::Winding;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POLYGON((0 0,1 0,1 1,0 0))").unwrap();
+    /// let mut polygon = wkt.into_polygon().unwrap();
+    /// polygon.orient(Winding::Clockwise);
+    /// assert_eq!(polygon.to_string(), "POLYGON((0 0,1 1,1 0,0 0))");
+    /// ```
+    pub fn orient(&mut self, exterior: Winding) {
+        for (i, ring) in self.0.iter_mut().enumerate() {
+            let expected = if i == 0 {
+                exterior
+            } else {
+                exterior.reversed()
+            };
+            if ring.winding() == Some(expected.reversed()) {
+                ring.reverse();
+            }
+        }
+    }
+
+    /// Closes every ring whose first and last coordinates differ, by appending a copy of its first
+    /// coordinate, as hand-written WKT often needs. Empty rings are left as they are.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POLYGON((0 0,4 0,4 4),(1 1,2 2,1 2,1 1))").unwrap();
+    /// let mut polygon = wkt.into_polygon().unwrap();
+    /// polygon.close_rings();
+    /// assert_eq!(polygon.to_string(), "POLYGON((0 0,4 0,4 4,0 0),(1 1,2 2,1 2,1 1))");
+    /// ```
+    pub fn close_rings(&mut self) {
+        for ring in &mut self.0 {
+            if !ring.is_closed() {
+                ring.0.push(ring.0[0].clone());
+            }
+        }
+    }
+
+    /// The segments of the exterior ring, see [`LineString::segments`]. Empty if the polygon is
+    /// empty.
+    pub fn exterior_segments(&self) -> impl ExactSizeIterator<Item = (&Coord<T>, &Coord<T>)> + '_ {
+        self.0
+            .first()
+            .map(|ring| ring.0.as_slice())
+            .unwrap_or_default()
+            .windows(2)
+            .map(|pair| (&pair[0], &pair[1]))
+    }
+}
+
 impl<T> From<Polygon<T>> for Wkt<T>
 where
     T: WktNum,
@@ -34,6 +126,14 @@ where
     }
 }
 
+impl<T: WktNum> Extend<LineString<T>> for Polygon<T> {
+    /// Appends the rings. Dimensions aren't checked, since `extend` can't fail; use
+    /// [`Polygon::try_extend`] for that.
+    fn extend<I: IntoIterator<Item = LineString<T>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
 impl<T> fmt::Display for Polygon<T>
 where
     T: WktNum + fmt::Display,
@@ -47,7 +147,7 @@ impl<T> FromTokens<T> for Polygon<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut Tokens<'_>, dim: Dimension) -> Result<Self, &'static str> {
         let result = FromTokens::comma_many(
             <LineString<T> as FromTokens<T>>::from_tokens_with_parens,
             tokens,
@@ -117,11 +217,25 @@ impl<T: WktNum> PolygonTrait for &Polygon<T> {
 
 #[cfg(test)]
 mod tests {
-    use super::{LineString, Polygon};
+    use super::{LineString, Polygon, Winding};
     use crate::types::Coord;
     use crate::Wkt;
     use std::str::FromStr;
 
+    #[test]
+    fn close_rings() {
+        let mut polygon = Polygon(vec![
+            LineString(vec![Coord::xy(0, 0), Coord::xy(1, 0), Coord::xy(1, 1)]),
+            LineString(vec![]),
+            LineString(vec![Coord::xy(0, 0), Coord::xy(1, 1), Coord::xy(0, 0)]),
+        ]);
+        polygon.close_rings();
+        assert_eq!(
+            polygon.to_string(),
+            "POLYGON((0 0,1 0,1 1,0 0),EMPTY,(0 0,1 1,0 0))"
+        );
+    }
+
     #[test]
     fn basic_polygon() {
         let wkt: Wkt<f64> = Wkt::from_str("POLYGON ((8 4, 4 0, 0 4, 8 4), (7 3, 4 1, 1 4, 7 3))")
@@ -203,4 +317,81 @@ mod tests {
             format!("{}", polygon)
         );
     }
+
+    #[test]
+    fn from_rings_checked() {
+        let ring = |wkt_str| match Wkt::<f64>::from_str(wkt_str).unwrap() {
+            Wkt::LineString(ring) => ring,
+            _ => unreachable!(),
+        };
+
+        let polygon = Polygon::from_rings_checked(vec![
+            ring("LINESTRING M(0 0 1,4 0 1,4 4 1,0 0 1)"),
+            ring("LINESTRING EMPTY"),
+            ring("LINESTRING M(1 1 2,2 1 2,2 2 2,1 1 2)"),
+        ])
+        .unwrap();
+        assert_eq!(polygon.0.len(), 3);
+
+        let err = Polygon::from_rings_checked(vec![
+            ring("LINESTRING M(0 0 1,4 0 1,4 4 1,0 0 1)"),
+            ring("LINESTRING Z(1 1 2,2 1 2,2 2 2,1 1 2)"),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Mixed dimensions: expected XYM, found XYZ at index 1."
+        );
+
+        // A ring that is mixed itself is reported at its own index.
+        let mut mixed = ring("LINESTRING(1 1,2 1,2 2,1 1)");
+        mixed.0[2].z = Some(0.);
+        let err = Polygon::from_rings_checked(vec![ring("LINESTRING EMPTY"), mixed]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Mixed dimensions: expected XY, found XYZ at index 1."
+        );
+    }
+
+    #[test]
+    fn exterior_segments() {
+        let polygon = match Wkt::<f64>::from_str("POLYGON((0 0,1 0,0 1,0 0),(0 0,0 0))").unwrap() {
+            Wkt::Polygon(polygon) => polygon,
+            _ => unreachable!(),
+        };
+        let segments: Vec<_> = polygon
+            .exterior_segments()
+            .map(|(a, b)| ((a.x, a.y), (b.x, b.y)))
+            .collect();
+        assert_eq!(
+            segments,
+            [
+                ((0., 0.), (1., 0.)),
+                ((1., 0.), (0., 1.)),
+                ((0., 1.), (0., 0.))
+            ]
+        );
+
+        assert_eq!(Polygon::<f64>(vec![]).exterior_segments().len(), 0);
+    }
+
+    #[test]
+    fn orient() {
+        let mut polygon = match Wkt::<f64>::from_str(
+            "POLYGON((0 0,0 10,10 10,10 0,0 0),(2 2,2 4,4 4,4 2,2 2),(5 5,6 5,6 6,5 5))",
+        )
+        .unwrap()
+        {
+            Wkt::Polygon(polygon) => polygon,
+            _ => unreachable!(),
+        };
+
+        polygon.orient(Winding::CounterClockwise);
+        assert_eq!(
+            polygon.to_string(),
+            "POLYGON((0 0,10 0,10 10,0 10,0 0),(2 2,2 4,4 4,4 2,2 2),(5 5,6 6,6 5,5 5))"
+        );
+        assert!(polygon.0[0].is_ccw());
+        assert!(!polygon.0[1].is_ccw());
+    }
 }
@@ -0,0 +1,16 @@
+/// The direction in which a ring winds around its interior, seen with the y axis pointing up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Winding {
+    CounterClockwise,
+    Clockwise,
+}
+
+impl Winding {
+    /// The other direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            Winding::CounterClockwise => Winding::Clockwise,
+            Winding::Clockwise => Winding::CounterClockwise,
+        }
+    }
+}
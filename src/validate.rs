@@ -0,0 +1,263 @@
+//! Structural validation of parsed geometries.
+//!
+//! The parser accepts anything that is syntactically valid WKT, such as a polygon whose ring isn't
+//! closed. [`Wkt::validate`] reports these problems before the geometry is handed to a library
+//! that expects well-formed input.
+
+use std::fmt;
+
+use crate::types::{Coord, Dimension, LineString, Polygon};
+use crate::{Wkt, WktNum};
+
+/// A problem found by [`Wkt::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The indices leading from the validated geometry to the offending part.
+    ///
+    /// Each index selects an element of a collection, in nesting order: the geometry of a
+    /// `GEOMETRYCOLLECTION`, the polygon of a `MULTIPOLYGON`, the ring of a `POLYGON`, and so on,
+    /// down to the coordinate for issues about a single coordinate. For example `[1, 0]` in a
+    /// `MULTIPOLYGON` is the exterior ring of its second polygon.
+    pub path: Vec<usize>,
+    pub kind: ValidationIssueKind,
+}
+
+/// The kind of a [`ValidationIssue`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidationIssueKind {
+    /// A polygon ring or linear ring whose first and last coordinates differ.
+    UnclosedRing,
+    /// A polygon ring or linear ring with fewer than 4 coordinates.
+    TooFewRingCoords { found: usize },
+    /// A line string with fewer than 2 coordinates.
+    TooFewLineStringCoords { found: usize },
+    /// A coordinate whose dimension differs from the first coordinate of the geometry.
+    MixedDimension {
+        expected: Dimension,
+        found: Dimension,
+    },
+    /// A coordinate with a `NaN` value.
+    NaNCoord,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ValidationIssueKind::UnclosedRing => f.write_str("ring is not closed")?,
+            ValidationIssueKind::TooFewRingCoords { found } => {
+                write!(f, "ring has {found} coordinates, expected at least 4")?
+            }
+            ValidationIssueKind::TooFewLineStringCoords { found } => write!(
+                f,
+                "line string has {found} coordinates, expected at least 2"
+            )?,
+            ValidationIssueKind::MixedDimension { expected, found } => {
+                write!(f, "coordinate is {found}, expected {expected}")?
+            }
+            ValidationIssueKind::NaNCoord => f.write_str("coordinate has a NaN value")?,
+        }
+        write!(f, " at {:?}", self.path)
+    }
+}
+
+/// Walks a geometry, tracking the path to the current part.
+struct Validator {
+    path: Vec<usize>,
+    dim: Option<Dimension>,
+    issues: Vec<ValidationIssue>,
+}
+
+impl Validator {
+    fn report(&mut self, kind: ValidationIssueKind) {
+        self.issues.push(ValidationIssue {
+            path: self.path.clone(),
+            kind,
+        });
+    }
+
+    fn each<G>(&mut self, items: &[G], mut visit: impl FnMut(&mut Self, &G)) {
+        for (i, item) in items.iter().enumerate() {
+            self.path.push(i);
+            visit(self, item);
+            self.path.pop();
+        }
+    }
+
+    fn coord<T: WktNum>(&mut self, coord: &Coord<T>) {
+        let found = coord.dimension();
+        match self.dim {
+            None => self.dim = Some(found),
+            Some(expected) if expected != found => {
+                self.report(ValidationIssueKind::MixedDimension { expected, found })
+            }
+            Some(_) => (),
+        }
+
+        #[allow(clippy::eq_op)]
+        let is_nan = |value: T| value != value;
+        if is_nan(coord.x)
+            || is_nan(coord.y)
+            || coord.z.is_some_and(is_nan)
+            || coord.m.is_some_and(is_nan)
+        {
+            self.report(ValidationIssueKind::NaNCoord);
+        }
+    }
+
+    fn line_string<T: WktNum>(&mut self, line_string: &LineString<T>) {
+        let found = line_string.0.len();
+        // Empty line strings are fine.
+        if found == 1 {
+            self.report(ValidationIssueKind::TooFewLineStringCoords { found });
+        }
+        self.each(&line_string.0, Self::coord);
+    }
+
+    fn ring<T: WktNum>(&mut self, ring: &LineString<T>) {
+        let coords = &ring.0;
+        if !coords.is_empty() {
+            if coords.len() < 4 {
+                self.report(ValidationIssueKind::TooFewRingCoords {
+                    found: coords.len(),
+                });
+            }
+            if coords.first() != coords.last() {
+                self.report(ValidationIssueKind::UnclosedRing);
+            }
+        }
+        self.each(coords, Self::coord);
+    }
+
+    fn polygon<T: WktNum>(&mut self, polygon: &Polygon<T>) {
+        self.each(&polygon.0, Self::ring);
+    }
+
+    fn geometry<T: WktNum>(&mut self, wkt: &Wkt<T>) {
+        match wkt {
+            Wkt::Point(point) => {
+                if let Some(coord) = &point.0 {
+                    self.coord(coord);
+                }
+            }
+            Wkt::LineString(line_string) => self.line_string(line_string),
+            Wkt::LinearRing(ring) => self.ring(&ring.0),
+            Wkt::Polygon(polygon) => self.polygon(polygon),
+            Wkt::MultiPoint(multi_point) => self.each(&multi_point.0, |v, point| {
+                if let Some(coord) = &point.0 {
+                    v.coord(coord);
+                }
+            }),
+            Wkt::MultiLineString(multi_line_string) => {
+                self.each(&multi_line_string.0, Self::line_string)
+            }
+            Wkt::MultiPolygon(multi_polygon) => self.each(&multi_polygon.0, Self::polygon),
+            Wkt::GeometryCollection(collection) => self.each(&collection.0, Self::geometry),
+        }
+    }
+}
+
+impl<T> Wkt<T>
+where
+    T: WktNum,
+{
+    /// Checks the structure of this geometry, returning every problem found.
+    ///
+    /// The checks are:
+    ///
+    /// - polygon rings and linear rings must be closed and have at least 4 coordinates
+    /// - line strings must have at least 2 coordinates
+    /// - all coordinates must have the same dimension
+    /// - no coordinate may contain `NaN`
+    ///
+    /// Empty geometries are valid. An empty list means the geometry passed every check.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    /// use wkt::validate::ValidationIssueKind;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((0 0,1 0,1 1)))").unwrap();
+    /// let issues = wkt.validate();
+    /// assert_eq!(issues.len(), 2);
+    /// assert_eq!(issues[0].path, [1, 0]);
+    /// assert_eq!(issues[0].kind, ValidationIssueKind::TooFewRingCoords { found: 3 });
+    /// assert_eq!(issues[1].to_string(), "ring is not closed at [1, 0]");
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut validator = Validator {
+            path: vec![],
+            dim: None,
+            issues: vec![],
+        };
+        validator.geometry(self);
+        validator.issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn validate(wkt_str: &str) -> Vec<ValidationIssue> {
+        Wkt::<f64>::from_str(wkt_str).unwrap().validate()
+    }
+
+    #[test]
+    fn valid_geometries() {
+        for wkt_str in [
+            "POINT EMPTY",
+            "POINT(1 2)",
+            "LINESTRING EMPTY",
+            "LINESTRING(0 0,1 1)",
+            "POLYGON EMPTY",
+            "POLYGON((0 0,1 0,1 1,0 0),(0.1 0.1,0.2 0.1,0.2 0.2,0.1 0.1))",
+            "GEOMETRYCOLLECTION(POINT Z(1 2 3),LINESTRING Z(0 0 0,1 1 1))",
+        ] {
+            assert_eq!(validate(wkt_str), [], "{wkt_str}");
+        }
+    }
+
+    #[test]
+    fn short_line_string() {
+        assert_eq!(
+            validate("MULTILINESTRING((0 0,1 1),(2 2))"),
+            [ValidationIssue {
+                path: vec![1],
+                kind: ValidationIssueKind::TooFewLineStringCoords { found: 1 },
+            }]
+        );
+    }
+
+    #[test]
+    fn mixed_dimension() {
+        assert_eq!(
+            validate("GEOMETRYCOLLECTION(POINT(1 2),POINT M(3 4 5))"),
+            [ValidationIssue {
+                path: vec![1],
+                kind: ValidationIssueKind::MixedDimension {
+                    expected: Dimension::XY,
+                    found: Dimension::XYM,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn nan_coord() {
+        let mut line_string = LineString(vec![Coord::default(), Coord::default()]);
+        line_string.0[1].m = Some(f64::NAN);
+        let issues = Wkt::LineString(line_string).validate();
+        let kinds: Vec<_> = issues.into_iter().map(|issue| issue.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                ValidationIssueKind::MixedDimension {
+                    expected: Dimension::XY,
+                    found: Dimension::XYM,
+                },
+                ValidationIssueKind::NaNCoord,
+            ]
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! Bindings for JavaScript, with the `wasm` feature, for use through `wasm-bindgen` when compiled
+//! to `wasm32-unknown-unknown`.
+//!
+//! The functions take and return strings and numbers only, and errors become JavaScript `Error`s
+//! carrying this crate's message.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{check_syntax, extract, infer_type, reformat};
+
+/// Throws if `input` isn't a valid WKT geometry.
+#[wasm_bindgen]
+pub fn validate(input: &str) -> Result<(), JsError> {
+    Ok(check_syntax(input)?)
+}
+
+/// `input` in the canonical form [`reformat`] writes, e.g. `POINT(1 2)`.
+#[wasm_bindgen]
+pub fn format(input: &str) -> Result<String, JsError> {
+    let mut formatted = String::with_capacity(input.len());
+    reformat(input, &mut formatted)?;
+    Ok(formatted)
+}
+
+/// The geometry type keyword of `input`, e.g. `POLYGON`, read from its start only.
+#[wasm_bindgen(js_name = geometryType)]
+pub fn geometry_type(input: &str) -> Result<String, JsError> {
+    let inferred = infer_type(input).map_err(|err| JsError::new(&err))?;
+    Ok(inferred.geometry_type.to_string())
+}
+
+/// The number of coordinates in `input`.
+#[wasm_bindgen(js_name = coordCount)]
+pub fn coord_count(input: &str) -> Result<usize, JsError> {
+    Ok(extract::coord_count(input)?)
+}
+
+/// The bounding box of `input` as `[minX, minY, maxX, maxY]`, or `undefined` if it's empty.
+#[wasm_bindgen]
+pub fn bbox(input: &str) -> Result<Option<Vec<f64>>, JsError> {
+    let bbox = extract::bbox(input)?;
+    Ok(bbox.map(|bbox| vec![bbox.min_x, bbox.min_y, bbox.max_x, bbox.max_y]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Errors can only be created in a JavaScript runtime, so only successes are tested here.
+    #[test]
+    fn bindings_succeed_on_valid_input() {
+        let input = "linestring z (1 2 3, -4 5 6)";
+        assert!(validate(input).is_ok());
+        assert_eq!(format(input).unwrap(), "LINESTRING Z(1 2 3,-4 5 6)");
+        assert_eq!(geometry_type(input).unwrap(), "LINESTRING");
+        assert_eq!(coord_count(input).unwrap(), 2);
+        assert_eq!(bbox(input).unwrap(), Some(vec![-4., 2., 1., 5.]));
+        assert_eq!(bbox("POINT EMPTY").unwrap(), None);
+    }
+}
@@ -0,0 +1,72 @@
+//! Runs the `wkt` binary, built with the `cli` feature, over `tests/lines.wkt`.
+
+use std::process::{Command, Output};
+
+fn wkt(command: &str) -> Output {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/lines.wkt");
+    Command::new(env!("CARGO_BIN_EXE_wkt"))
+        .args([command, fixture])
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).unwrap()
+}
+
+const INVALID_LINE: &str = "line 5: Invalid WKT: Expected a number for the Y coordinate\n";
+
+#[test]
+fn validate_reports_invalid_lines() {
+    let output = wkt("validate");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(stderr(&output), INVALID_LINE);
+}
+
+#[test]
+fn fmt_stops_at_the_first_invalid_line() {
+    let output = wkt("fmt");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "POINT(1 2)\nLINESTRING Z(0 0 0,1 1 1)\nPOLYGON EMPTY\n"
+    );
+    assert_eq!(stderr(&output), INVALID_LINE);
+}
+
+#[test]
+fn stats_summarizes_every_line() {
+    let output = wkt("stats");
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "geometries: 5
+invalid: 1
+empty: 1
+coordinates: 4
+types:
+  POINT: 2
+  LINESTRING: 1
+  MULTIPOINT: 1
+  POLYGON: 1
+dimensions:
+  XY: 4
+  XYZ: 1
+"
+    );
+}
+
+#[test]
+fn missing_file_is_an_error() {
+    let output = Command::new(env!("CARGO_BIN_EXE_wkt"))
+        .args(["validate", "no/such/file.wkt"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("wkt: no/such/file.wkt: "));
+}
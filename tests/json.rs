@@ -0,0 +1,36 @@
+//! `Wkt::to_json_value` and `Wkt::from_json_value`, with the `serde_json` feature.
+
+use std::str::FromStr;
+
+use serde_json::json;
+use wkt::error::Error;
+use wkt::types::{Coord, LineString};
+use wkt::Wkt;
+
+#[test]
+fn round_trip() {
+    let wkt = Wkt::<f64>::from_str("MULTIPOINT Z((1 2 3),(4 5 6))").unwrap();
+    let value = wkt.to_json_value().unwrap();
+    assert_eq!(value, json!("MULTIPOINT Z((1 2 3),(4 5 6))"));
+    assert_eq!(Wkt::from_json_value(&value), Ok(wkt));
+}
+
+#[test]
+fn rejects_other_values() {
+    for value in [json!(null), json!(1), json!(["POINT (1 2)"])] {
+        assert_eq!(
+            Wkt::<f64>::from_json_value(&value),
+            Err("Expected a JSON string containing WKT")
+        );
+    }
+    assert_eq!(
+        Wkt::<f64>::from_json_value(&json!("POINT (1)")),
+        Err("Expected a number for the Y coordinate")
+    );
+}
+
+#[test]
+fn missing_ordinate_is_an_error() {
+    let wkt = Wkt::LineString(LineString(vec![Coord::xyz(1., 2., 3.), Coord::xy(4., 5.)]));
+    assert!(matches!(wkt.to_json_value(), Err(Error::MissingOrdinate)));
+}
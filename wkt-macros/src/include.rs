@@ -0,0 +1,184 @@
+//! `include_wkt!`, which reads WKT from a file and expands it like `wkt!`.
+
+use std::path::PathBuf;
+
+use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::quote;
+
+use crate::{root, Error, Kind, Result};
+
+pub(crate) fn expand(input: TokenStream) -> TokenStream {
+    match include(input) {
+        Ok(tokens) => tokens,
+        Err(err) => err.into_compile_error(),
+    }
+}
+
+fn include(input: TokenStream) -> Result<TokenStream> {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let (prefix, literal) = match tokens.split_last() {
+        Some((TokenTree::Literal(literal), prefix)) => (prefix, literal),
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "expected the path of a WKT file, as a string literal",
+            ))
+        }
+    };
+    let relative = string_literal(literal)?;
+    let path = match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => PathBuf::from(dir).join(&relative),
+        None => PathBuf::from(&relative),
+    };
+    let text = std::fs::read_to_string(&path).map_err(|err| {
+        Error::new(
+            literal.span(),
+            format!("couldn't read `{}`: {err}", path.display()),
+        )
+    })?;
+    let wkt = tokenize(&text)
+        .map_err(|message| Error::new(literal.span(), format!("in `{relative}`: {message}")))?;
+
+    let mut tokens = prefix.to_vec();
+    tokens.extend(wkt);
+    let geometry = root(&tokens).map_err(|err| Error {
+        span: literal.span(),
+        message: format!("in `{relative}`: {}", err.message),
+    })?;
+
+    // Reading the file through `include_bytes!` tells the compiler to rebuild when it changes.
+    let path = path.to_string_lossy();
+    Ok(quote!({
+        const _: &[u8] = ::core::include_bytes!(#path);
+        #geometry
+    }))
+}
+
+fn string_literal(literal: &Literal) -> Result<String> {
+    let text = literal.to_string();
+    text.strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .filter(|text| !text.contains('\\'))
+        .map(str::to_string)
+        .ok_or_else(|| {
+            Error::new(
+                literal.span(),
+                "expected the path of a WKT file, as a string literal without escapes",
+            )
+        })
+}
+
+/// Converts WKT text into the tokens `wkt!` accepts. All numbers become `f64` literals.
+fn tokenize(text: &str) -> std::result::Result<Vec<TokenTree>, String> {
+    // Each open parenthesis pushes a new list of tokens, which becomes a group when closed.
+    let mut stack: Vec<Vec<TokenTree>> = vec![vec![]];
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '(' => stack.push(vec![]),
+            ')' => {
+                if stack.len() == 1 {
+                    return Err("unbalanced `)`".to_string());
+                }
+                let stream = stack.pop().unwrap();
+                let parent = stack.last_mut().unwrap();
+                parent.push(TokenTree::Group(Group::new(
+                    Delimiter::Parenthesis,
+                    stream.into_iter().collect(),
+                )));
+            }
+            ',' => {
+                let punct = Punct::new(',', Spacing::Alone);
+                stack.last_mut().unwrap().push(TokenTree::Punct(punct));
+            }
+            _ => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if c.is_whitespace() || matches!(c, '(' | ')' | ',') {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let word = &text[start..end];
+                stack.last_mut().unwrap().extend(word_tokens(word)?);
+            }
+        }
+    }
+    if stack.len() != 1 {
+        return Err("unbalanced `(`".to_string());
+    }
+    Ok(stack.pop().unwrap())
+}
+
+fn word_tokens(word: &str) -> std::result::Result<Vec<TokenTree>, String> {
+    if let Ok(value) = word.parse::<f64>() {
+        // Non-finite values have no literal form.
+        let value = if value.is_finite() {
+            TokenTree::Literal(Literal::f64_unsuffixed(value))
+        } else {
+            let constant = match value {
+                v if v.is_nan() => quote!(::core::f64::NAN),
+                v if v > 0. => quote!(::core::f64::INFINITY),
+                _ => quote!(::core::f64::NEG_INFINITY),
+            };
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, constant))
+        };
+        return Ok(vec![value]);
+    }
+    if !word.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        || word.starts_with(|c: char| c.is_ascii_digit())
+    {
+        return Err(format!("unexpected `{word}`"));
+    }
+    let ident = |name: &str| TokenTree::Ident(Ident::new(name, Span::call_site()));
+    // Split joined dimension tags, like `POINTZ`.
+    let upper = word.to_ascii_uppercase();
+    for tag in ["ZM", "Z", "M"] {
+        if let Some(keyword) = upper.strip_suffix(tag) {
+            if Kind::ALL.iter().any(|kind| kind.keyword() == keyword) {
+                return Ok(vec![ident(keyword), ident(tag)]);
+            }
+        }
+    }
+    Ok(vec![ident(word)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(text: &str) -> String {
+        tokenize(text)
+            .unwrap()
+            .into_iter()
+            .collect::<TokenStream>()
+            .to_string()
+    }
+
+    #[test]
+    fn tokenizes_wkt() {
+        assert_eq!(
+            tokens("POLYGONZ ((1 2 -3,4e1 .5 6, 1 2 -3))"),
+            "POLYGON Z ((1.0 2.0 - 3.0 , 40.0 0.5 6.0 , 1.0 2.0 - 3.0))"
+        );
+        assert_eq!(
+            tokens("POINT(nan 1)"),
+            "POINT ((:: core :: f64 :: NAN) 1.0)"
+        );
+        assert_eq!(tokenize("POINT(1 2").unwrap_err(), "unbalanced `(`");
+        assert_eq!(tokenize("POINT(1 2))").unwrap_err(), "unbalanced `)`");
+        assert_eq!(tokenize("POINT(1 2;)").unwrap_err(), "unexpected `2;`");
+    }
+
+    #[test]
+    fn includes_file() {
+        let output = expand(quote!("../benches/small.wkt")).to_string();
+        assert!(output.contains("include_bytes"), "{output}");
+        assert!(output.contains(":: wkt :: types :: Polygon"), "{output}");
+
+        let output = expand(quote!("missing.wkt")).to_string();
+        assert!(output.contains("couldn't read"), "{output}");
+    }
+}
@@ -0,0 +1,735 @@
+//! The procedural implementation of `wkt::wkt!`, used instead of the declarative one when the
+//! `proc-macro` feature of `wkt` is enabled. See the `wkt` documentation for the syntax.
+//!
+//! Compared to the declarative macro, this one reports errors at the offending token with a
+//! message explaining the problem, and has no recursion limit on the size of the geometry. It
+//! accepts the same input, so enabling the feature never breaks a build that already compiles.
+//!
+//! The expansion names the `wkt` crate by its absolute path `::wkt`, so it only works in crates
+//! that depend on `wkt` under that name.
+//!
+//! It also provides `include_wkt!`, which expands the contents of a WKT file the same way.
+
+use proc_macro2::{Delimiter, Group, Ident, Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
+
+mod include;
+
+/// The procedural implementation of `wkt::wkt!`, see the `wkt` crate for its documentation.
+#[proc_macro]
+pub fn wkt(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(input.into()).into()
+}
+
+/// The implementation of `wkt::include_wkt!`, see the `wkt` crate for its documentation.
+#[proc_macro]
+pub fn include_wkt(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    include::expand(input.into()).into()
+}
+
+fn expand(input: TokenStream) -> TokenStream {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    match root(&tokens) {
+        Ok(tokens) => tokens,
+        Err(err) => err.into_compile_error(),
+    }
+}
+
+struct Error {
+    span: Span,
+    message: String,
+}
+
+impl Error {
+    fn new(span: Span, message: impl Into<String>) -> Self {
+        Error {
+            span,
+            message: message.into(),
+        }
+    }
+
+    fn into_compile_error(self) -> TokenStream {
+        let message = self.message;
+        quote_spanned!(self.span=> ::core::compile_error!(#message))
+    }
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Point,
+    LineString,
+    LinearRing,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+}
+
+impl Kind {
+    const ALL: [Kind; 8] = [
+        Kind::Point,
+        Kind::LineString,
+        Kind::LinearRing,
+        Kind::Polygon,
+        Kind::MultiPoint,
+        Kind::MultiLineString,
+        Kind::MultiPolygon,
+        Kind::GeometryCollection,
+    ];
+
+    fn keyword(self) -> &'static str {
+        match self {
+            Kind::Point => "POINT",
+            Kind::LineString => "LINESTRING",
+            Kind::LinearRing => "LINEARRING",
+            Kind::Polygon => "POLYGON",
+            Kind::MultiPoint => "MULTIPOINT",
+            Kind::MultiLineString => "MULTILINESTRING",
+            Kind::MultiPolygon => "MULTIPOLYGON",
+            Kind::GeometryCollection => "GEOMETRYCOLLECTION",
+        }
+    }
+
+    /// The name of both the type in `wkt::types` and the `wkt::Wkt` variant.
+    fn name(self) -> Ident {
+        let name = match self {
+            Kind::Point => "Point",
+            Kind::LineString => "LineString",
+            Kind::LinearRing => "LinearRing",
+            Kind::Polygon => "Polygon",
+            Kind::MultiPoint => "MultiPoint",
+            Kind::MultiLineString => "MultiLineString",
+            Kind::MultiPolygon => "MultiPolygon",
+            Kind::GeometryCollection => "GeometryCollection",
+        };
+        Ident::new(name, Span::call_site())
+    }
+}
+
+/// The types the macro expands to.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Wkt,
+    GeoTypes,
+    /// The slice-backed geometries of `wkt::borrowed`.
+    Borrowed,
+}
+
+impl Target {
+    fn types(self) -> TokenStream {
+        match self {
+            Target::Wkt => quote!(::wkt::types),
+            Target::GeoTypes => quote!(::wkt::__private::geo_types),
+            Target::Borrowed => quote!(::wkt::borrowed),
+        }
+    }
+
+    /// The members of a geometry, in the container its type holds them in.
+    fn list(self, items: &[TokenStream]) -> TokenStream {
+        match self {
+            Target::Wkt | Target::GeoTypes => quote!(::std::vec![#(#items),*]),
+            Target::Borrowed => quote!(&[#(#items),*]),
+        }
+    }
+
+    /// The name of the type for geometries of `kind`.
+    fn name(self, kind: Kind) -> Ident {
+        match (self, kind) {
+            (Target::GeoTypes | Target::Borrowed, Kind::LinearRing) => Kind::LineString.name(),
+            _ => kind.name(),
+        }
+    }
+
+    /// Wraps a geometry in the enum of all geometries.
+    fn any(self, kind: Kind, geometry: TokenStream) -> TokenStream {
+        let variant = self.name(kind);
+        match self {
+            Target::Wkt => quote!(::wkt::Wkt::#variant(#geometry)),
+            Target::GeoTypes => quote!(::wkt::__private::geo_types::Geometry::#variant(#geometry)),
+            Target::Borrowed => unreachable!("borrowed geometries are rejected before wrapping"),
+        }
+    }
+}
+
+/// Which optional values the coordinates of a geometry have.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Layout {
+    z: bool,
+    m: bool,
+}
+
+impl Layout {
+    fn from_tag(tag: &str) -> Option<Self> {
+        match tag.to_ascii_uppercase().as_str() {
+            "Z" => Some(Layout { z: true, m: false }),
+            "M" => Some(Layout { z: false, m: true }),
+            "ZM" => Some(Layout { z: true, m: true }),
+            _ => None,
+        }
+    }
+
+    fn len(self) -> usize {
+        2 + self.z as usize + self.m as usize
+    }
+}
+
+/// The state shared by all coordinates of one geometry.
+struct Context {
+    target: Target,
+    keyword: String,
+    layout: Option<Layout>,
+    tagged: bool,
+    coords: usize,
+}
+
+fn root(tokens: &[TokenTree]) -> Result<TokenStream> {
+    let (target, tokens) = match tokens {
+        [TokenTree::Ident(ident), TokenTree::Punct(colon), rest @ ..]
+            if ident == "geo" && colon.as_char() == ':' =>
+        {
+            (Target::GeoTypes, rest)
+        }
+        [TokenTree::Ident(ident), TokenTree::Punct(colon), rest @ ..]
+            if ident == "borrowed" && colon.as_char() == ':' =>
+        {
+            (Target::Borrowed, rest)
+        }
+        _ => (Target::Wkt, tokens),
+    };
+    match tokens.first() {
+        Some(TokenTree::Ident(ident))
+            if ident.to_string().eq_ignore_ascii_case("GEOMETRY") && target == Target::Borrowed =>
+        {
+            Err(Error::new(
+                ident.span(),
+                "borrowed geometries have no `Wkt` enum",
+            ))
+        }
+        Some(TokenTree::Ident(ident)) if ident.to_string().eq_ignore_ascii_case("GEOMETRY") => {
+            let (kind, geometry) = geometry_only(&tokens[1..], ident.span(), target)?;
+            Ok(target.any(kind, geometry))
+        }
+        _ => Ok(geometry_only(tokens, Span::call_site(), target)?.1),
+    }
+}
+
+/// Parses tokens that must hold exactly one geometry.
+fn geometry_only(tokens: &[TokenTree], span: Span, target: Target) -> Result<(Kind, TokenStream)> {
+    let (kind, geometry, used) = geometry(tokens, span, target)?;
+    match tokens.get(used) {
+        Some(extra) => Err(Error::new(
+            extra.span(),
+            "unexpected token after the geometry",
+        )),
+        None => Ok((kind, geometry)),
+    }
+}
+
+/// Parses a geometry from the start of `tokens`, returning how many tokens it used.
+fn geometry(
+    tokens: &[TokenTree],
+    span: Span,
+    target: Target,
+) -> Result<(Kind, TokenStream, usize)> {
+    let keyword = match tokens.first() {
+        Some(TokenTree::Ident(ident)) => ident,
+        Some(token) => return Err(Error::new(token.span(), "expected a geometry type")),
+        None => return Err(Error::new(span, "expected a geometry type")),
+    };
+    let word = keyword.to_string();
+    let kind = Kind::ALL
+        .into_iter()
+        .find(|kind| kind.keyword().eq_ignore_ascii_case(&word))
+        .ok_or_else(|| {
+            let expected: Vec<_> = Kind::ALL.iter().map(|kind| kind.keyword()).collect();
+            Error::new(
+                keyword.span(),
+                format!(
+                    "unknown geometry type `{word}`, expected one of {}",
+                    expected.join(", ")
+                ),
+            )
+        })?;
+    if kind == Kind::GeometryCollection && target == Target::Borrowed {
+        return Err(Error::new(
+            keyword.span(),
+            "borrowed geometries have no GEOMETRYCOLLECTION",
+        ));
+    }
+
+    let mut context = Context {
+        target,
+        keyword: kind.keyword().to_string(),
+        layout: None,
+        tagged: false,
+        coords: 0,
+    };
+    let mut used = 1;
+    if let Some(TokenTree::Ident(tag)) = tokens.get(used) {
+        if let Some(layout) = Layout::from_tag(&tag.to_string()) {
+            context.keyword = format!("{} {}", kind.keyword(), tag.to_string().to_uppercase());
+            context.layout = Some(layout);
+            context.tagged = true;
+            used += 1;
+        }
+    }
+
+    let geometry = match tokens.get(used) {
+        Some(TokenTree::Ident(ident)) if ident.to_string().eq_ignore_ascii_case("EMPTY") => {
+            empty(kind, target, ident.span())?
+        }
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            body(kind, group, &mut context)?
+        }
+        Some(token) => {
+            return Err(Error::new(
+                token.span(),
+                format!("expected `(` or EMPTY after {}", context.keyword),
+            ))
+        }
+        None => {
+            return Err(Error::new(
+                keyword.span(),
+                format!("expected `(` or EMPTY after {}", context.keyword),
+            ))
+        }
+    };
+    Ok((kind, geometry, used + 1))
+}
+
+fn empty(kind: Kind, target: Target, span: Span) -> Result<TokenStream> {
+    let types = target.types();
+    Ok(match (target, kind) {
+        (Target::Wkt | Target::Borrowed, Kind::Point) => {
+            quote!(#types::Point(::core::option::Option::None))
+        }
+        (Target::GeoTypes, Kind::Point) => {
+            return Err(Error::new(span, "geo-types has no empty point"))
+        }
+        (Target::Wkt, Kind::LinearRing) => {
+            quote!(#types::LinearRing(#types::LineString(::std::vec::Vec::new())))
+        }
+        (Target::GeoTypes, Kind::Polygon) => {
+            quote!(#types::Polygon::new(#types::LineString(::std::vec::Vec::new()), ::std::vec::Vec::new()))
+        }
+        _ => {
+            let name = target.name(kind);
+            match target {
+                Target::Borrowed => quote!(#types::#name(&[])),
+                _ => quote!(#types::#name(::std::vec::Vec::new())),
+            }
+        }
+    })
+}
+
+fn body(kind: Kind, group: &Group, context: &mut Context) -> Result<TokenStream> {
+    let target = context.target;
+    let types = target.types();
+    Ok(match kind {
+        Kind::Point => {
+            let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+            let coord = coord(&tokens, group.span(), context)?;
+            point(coord, target)
+        }
+        Kind::LineString => line_string(group, context)?,
+        Kind::LinearRing => {
+            let line_string = line_string(group, context)?;
+            match target {
+                Target::Wkt => quote!(#types::LinearRing(#line_string)),
+                Target::GeoTypes | Target::Borrowed => line_string,
+            }
+        }
+        Kind::Polygon => polygon(group, context)?,
+        Kind::MultiPoint => {
+            let points = split(group)?
+                .into_iter()
+                .map(|(tokens, span)| match tokens.as_slice() {
+                    [TokenTree::Group(point)] if point.delimiter() == Delimiter::Parenthesis => {
+                        body(Kind::Point, point, context)
+                    }
+                    [TokenTree::Ident(ident)]
+                        if ident.to_string().eq_ignore_ascii_case("EMPTY") =>
+                    {
+                        empty(Kind::Point, target, ident.span())
+                    }
+                    _ => Ok(point(coord(&tokens, span, context)?, target)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let points = target.list(&points);
+            quote!(#types::MultiPoint(#points))
+        }
+        Kind::MultiLineString => {
+            let line_strings = members(group, Kind::LineString, target, |group| {
+                line_string(group, context)
+            })?;
+            let line_strings = target.list(&line_strings);
+            quote!(#types::MultiLineString(#line_strings))
+        }
+        Kind::MultiPolygon => {
+            let polygons = members(group, Kind::Polygon, target, |group| {
+                polygon(group, context)
+            })?;
+            let polygons = target.list(&polygons);
+            quote!(#types::MultiPolygon(#polygons))
+        }
+        Kind::GeometryCollection => {
+            let geometries = split(group)?
+                .into_iter()
+                .map(|(tokens, span)| {
+                    let (kind, geometry) = geometry_only(&tokens, span, target)?;
+                    Ok(target.any(kind, geometry))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            quote!(#types::GeometryCollection(::std::vec![#(#geometries),*]))
+        }
+    })
+}
+
+fn point(coord: TokenStream, target: Target) -> TokenStream {
+    let types = target.types();
+    match target {
+        Target::Wkt | Target::Borrowed => {
+            quote!(#types::Point(::core::option::Option::Some(#coord)))
+        }
+        Target::GeoTypes => quote!(#types::Point(#coord)),
+    }
+}
+
+fn line_string(group: &Group, context: &mut Context) -> Result<TokenStream> {
+    let coords = split(group)?
+        .into_iter()
+        .map(|(tokens, span)| coord(&tokens, span, context))
+        .collect::<Result<Vec<_>>>()?;
+    let types = context.target.types();
+    let coords = context.target.list(&coords);
+    Ok(quote!(#types::LineString(#coords)))
+}
+
+fn polygon(group: &Group, context: &mut Context) -> Result<TokenStream> {
+    let target = context.target;
+    let types = target.types();
+    let rings = members(group, Kind::LineString, target, |group| {
+        line_string(group, context)
+    })?;
+    Ok(match target {
+        Target::Wkt | Target::Borrowed => {
+            let rings = target.list(&rings);
+            quote!(#types::Polygon(#rings))
+        }
+        Target::GeoTypes => {
+            let (exterior, interiors) = rings.split_first().expect("split returns a member");
+            quote!(#types::Polygon::new(#exterior, ::std::vec![#(#interiors),*]))
+        }
+    })
+}
+
+/// Parses a comma separated list of parenthesized members, each of which may also be `EMPTY`.
+fn members(
+    group: &Group,
+    kind: Kind,
+    target: Target,
+    mut member: impl FnMut(&Group) -> Result<TokenStream>,
+) -> Result<Vec<TokenStream>> {
+    split(group)?
+        .into_iter()
+        .map(|(tokens, span)| match tokens.as_slice() {
+            [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+                member(group)
+            }
+            [TokenTree::Ident(ident)] if ident.to_string().eq_ignore_ascii_case("EMPTY") => {
+                empty(kind, target, ident.span())
+            }
+            _ => Err(Error::new(
+                span,
+                format!("expected a parenthesized {} or EMPTY", kind.keyword()),
+            )),
+        })
+        .collect()
+}
+
+/// Splits the contents of a group on commas, allowing a trailing comma, and returns each part with
+/// a span for errors.
+fn split(group: &Group) -> Result<Vec<(Vec<TokenTree>, Span)>> {
+    let mut parts = vec![];
+    let mut current = vec![];
+    let mut span = group.span();
+    for token in group.stream() {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                if current.is_empty() {
+                    return Err(Error::new(punct.span(), "expected a value before `,`"));
+                }
+                parts.push((std::mem::take(&mut current), span));
+                span = punct.span();
+            }
+            _ => {
+                if current.is_empty() {
+                    span = token.span();
+                }
+                current.push(token);
+            }
+        }
+    }
+    if !current.is_empty() {
+        parts.push((current, span));
+    } else if parts.is_empty() {
+        return Err(Error::new(span, "expected a value"));
+    }
+    Ok(parts)
+}
+
+fn coord(tokens: &[TokenTree], span: Span, context: &mut Context) -> Result<TokenStream> {
+    let mut values = vec![];
+    let mut rest = tokens;
+    while let Some((first, tail)) = rest.split_first() {
+        match first {
+            TokenTree::Punct(punct) if punct.as_char() == '-' => {
+                let (value, tail) = tail
+                    .split_first()
+                    .ok_or_else(|| Error::new(punct.span(), "expected a value after `-`"))?;
+                let value = value_tokens(value);
+                values.push(quote!(-#value));
+                rest = tail;
+            }
+            TokenTree::Punct(punct) => {
+                return Err(Error::new(
+                    punct.span(),
+                    format!(
+                        "unexpected `{}`, wrap expressions in parentheses",
+                        punct.as_char()
+                    ),
+                ))
+            }
+            value => {
+                values.push(value_tokens(value));
+                rest = tail;
+            }
+        }
+    }
+
+    context.coords += 1;
+    let layout = match context.layout {
+        Some(layout) if layout.len() == values.len() => layout,
+        Some(layout) if context.tagged => {
+            return Err(Error::new(
+                span,
+                format!(
+                    "expected {} values for a {} coordinate, found {}",
+                    layout.len(),
+                    context.keyword,
+                    values.len()
+                ),
+            ))
+        }
+        Some(layout) => {
+            return Err(Error::new(
+                span,
+                format!(
+                    "mixed dimension at coordinate {}: expected {} values, found {}",
+                    context.coords,
+                    layout.len(),
+                    values.len()
+                ),
+            ))
+        }
+        // Like the declarative macro, and unlike the parser, don't guess whether a third value is Z
+        // or M.
+        None if values.len() == 2 => Layout { z: false, m: false },
+        None => {
+            return Err(Error::new(
+                span,
+                format!(
+                    "expected 2 values for an untagged coordinate, found {}; tag the geometry with \
+                     Z, M or ZM",
+                    values.len()
+                ),
+            ))
+        }
+    };
+    context.layout = Some(layout);
+    if context.target == Target::GeoTypes && layout.len() > 2 {
+        return Err(Error::new(span, "geo-types coordinates only have x and y"));
+    }
+
+    let mut values = values.into_iter();
+    let x = values.next();
+    let y = values.next();
+    let mut optional = |present: bool| match present {
+        true => {
+            let value = values.next();
+            quote!(::core::option::Option::Some(#value))
+        }
+        false => quote!(::core::option::Option::None),
+    };
+    let z = optional(layout.z);
+    let m = optional(layout.m);
+    let types = context.target.types();
+    Ok(match context.target {
+        Target::Wkt | Target::Borrowed => quote!(#types::Coord { x: #x, y: #y, z: #z, m: #m }),
+        Target::GeoTypes => quote!(#types::Coord { x: #x, y: #y }),
+    })
+}
+
+/// The tokens of one value. The parentheses or braces around an expression are respanned to the
+/// macro call, so they don't trigger `unused_parens` or `unused_braces` in the caller.
+fn value_tokens(value: &TokenTree) -> TokenStream {
+    match value {
+        TokenTree::Group(group) => {
+            let mut respanned = Group::new(group.delimiter(), group.stream());
+            respanned.set_span(Span::call_site());
+            quote!(#respanned)
+        }
+        value => quote!(#value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_str(input: &str) -> String {
+        expand(input.parse().unwrap()).to_string()
+    }
+
+    fn error(input: &str) -> String {
+        let output = expand_str(input);
+        let message = output
+            .strip_prefix(":: core :: compile_error ! (\"")
+            .and_then(|rest| rest.strip_suffix("\")"))
+            .unwrap_or_else(|| panic!("expected an error for {input}, got {output}"));
+        message.to_string()
+    }
+
+    #[test]
+    fn expands_geometries() {
+        assert_eq!(
+            expand_str("POINT(1 -2)"),
+            quote!(::wkt::types::Point(::core::option::Option::Some(
+                ::wkt::types::Coord {
+                    x: 1,
+                    y: -2,
+                    z: ::core::option::Option::None,
+                    m: ::core::option::Option::None
+                }
+            )))
+            .to_string()
+        );
+        assert_eq!(
+            expand_str("GEOMETRY MULTIPOLYGON EMPTY"),
+            quote!(::wkt::Wkt::MultiPolygon(::wkt::types::MultiPolygon(
+                ::std::vec::Vec::new()
+            )))
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            error("CIRCLE(1 2)"),
+            "unknown geometry type `CIRCLE`, expected one of POINT, LINESTRING, LINEARRING, \
+             POLYGON, MULTIPOINT, MULTILINESTRING, MULTIPOLYGON, GEOMETRYCOLLECTION"
+        );
+        assert_eq!(error("POINT"), "expected `(` or EMPTY after POINT");
+        assert_eq!(
+            error("POINT Z(1 2)"),
+            "expected 3 values for a POINT Z coordinate, found 2"
+        );
+        assert_eq!(
+            error("LINESTRING(0 0,1 1,2 2 2)"),
+            "mixed dimension at coordinate 3: expected 2 values, found 3"
+        );
+        assert_eq!(
+            error("POINT(1 2 3)"),
+            "expected 2 values for an untagged coordinate, found 3; tag the geometry with Z, M \
+             or ZM"
+        );
+        assert_eq!(
+            error("LINESTRING(x + 1 0)"),
+            "unexpected `+`, wrap expressions in parentheses"
+        );
+        assert_eq!(
+            error("POINT(1 2) POINT"),
+            "unexpected token after the geometry"
+        );
+        assert_eq!(
+            error("borrowed: GEOMETRYCOLLECTION EMPTY"),
+            "borrowed geometries have no GEOMETRYCOLLECTION"
+        );
+        assert_eq!(
+            error("borrowed: GEOMETRY POINT EMPTY"),
+            "borrowed geometries have no `Wkt` enum"
+        );
+        assert_eq!(error("LINESTRING()"), "expected a value");
+        assert_eq!(error("LINESTRING(0 0,,1 1)"), "expected a value before `,`");
+    }
+
+    #[test]
+    fn geo_types() {
+        assert_eq!(
+            expand_str("geo: GEOMETRY LINEARRING(0 0,1 0,1 1,0 0)"),
+            expand_str("geo: GEOMETRY LINESTRING(0 0,1 0,1 1,0 0)")
+        );
+        assert_eq!(error("geo: POINT EMPTY"), "geo-types has no empty point");
+        assert_eq!(
+            error("geo: LINESTRING Z(0 0 1,1 1 1)"),
+            "geo-types coordinates only have x and y"
+        );
+    }
+
+    #[test]
+    fn borrowed() {
+        assert_eq!(
+            expand_str("borrowed: POLYGON((0 0,1 0,0 0),EMPTY)"),
+            quote!(::wkt::borrowed::Polygon(&[
+                ::wkt::borrowed::LineString(&[
+                    ::wkt::borrowed::Coord {
+                        x: 0,
+                        y: 0,
+                        z: ::core::option::Option::None,
+                        m: ::core::option::Option::None
+                    },
+                    ::wkt::borrowed::Coord {
+                        x: 1,
+                        y: 0,
+                        z: ::core::option::Option::None,
+                        m: ::core::option::Option::None
+                    },
+                    ::wkt::borrowed::Coord {
+                        x: 0,
+                        y: 0,
+                        z: ::core::option::Option::None,
+                        m: ::core::option::Option::None
+                    }
+                ]),
+                ::wkt::borrowed::LineString(&[])
+            ]))
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn trailing_commas() {
+        assert_eq!(
+            expand_str("MULTIPOLYGON(((0 0,1 0,1 1,0 0,),),)"),
+            expand_str("MULTIPOLYGON(((0 0,1 0,1 1,0 0)))")
+        );
+    }
+
+    #[test]
+    fn large_geometries() {
+        let coords: Vec<String> = (0..10_000).map(|i| format!("{i} -{i}")).collect();
+        let output = expand_str(&format!("LINESTRING({})", coords.join(",")));
+        assert!(!output.contains("compile_error"));
+    }
+
+    #[test]
+    fn accepts_what_the_declarative_macro_accepts() {
+        // Neither macro checks that rings are closed.
+        let output = expand_str("POLYGON((0 0,1 0,1 1))");
+        assert!(!output.contains("compile_error"));
+    }
+}